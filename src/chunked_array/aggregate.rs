@@ -1,20 +1,181 @@
-use arrow2::compute::aggregate::max_primitive;
+use arrow2::compute::aggregate::{
+    max_boolean, max_primitive, max_string, min_boolean, min_primitive, min_string,
+};
 
-use super::types::I32Chunked;
+use crate::types::{LittleNumericType, NumericNative};
 
-impl I32Chunked {
-    pub fn max(&self) -> Option<i32> {
-        self.iter_primitive()
-            .filter_map(max_primitive)
-            .fold(None, |acc, item| match acc {
-                Some(max) => {
-                    if max > item {
-                        Some(max)
-                    } else {
-                        Some(item)
-                    }
+use super::{
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+    ChunkedArray,
+};
+
+// Whole-array reductions. Like the group aggregations, they ignore nulls:
+// min, max and mean return None if there are no valid values and sum returns 0.
+macro_rules! impl_numeric_aggregate {
+    ($ca: ty, $native: ty) => {
+        impl $ca {
+            pub fn min(&self) -> Option<$native> {
+                self.iter_primitive()
+                    .filter_map(min_primitive)
+                    .reduce(|acc, v| if v < acc { v } else { acc })
+            }
+
+            pub fn max(&self) -> Option<$native> {
+                self.iter_primitive()
+                    .filter_map(max_primitive)
+                    .reduce(|acc, v| if v > acc { v } else { acc })
+            }
+
+            pub fn sum(&self) -> $native {
+                // The arrow2 sum kernel panics on overflow in debug builds
+                self.iter_primitive()
+                    .flat_map(|arr| arr.iter().flatten().copied())
+                    .fold(<$native>::default(), |acc, v| acc.wrapping_add(v))
+            }
+
+            pub fn mean(&self) -> Option<f64> {
+                let count = self.length - self.null_count();
+                if count == 0 {
+                    None
+                } else {
+                    // Summed as floats, since the integer sum wraps around on overflow
+                    let sum = self
+                        .iter_primitive()
+                        .flat_map(|arr| arr.iter().flatten().map(|v| v.to_f64()))
+                        .sum::<f64>();
+                    Some(sum / count as f64)
                 }
-                None => Some(item),
-            })
+            }
+        }
+    };
+}
+
+impl_numeric_aggregate!(I32Chunked, i32);
+impl_numeric_aggregate!(F64Chunked, f64);
+
+impl BooleanChunked {
+    pub fn min(&self) -> Option<bool> {
+        self.iter_primitive()
+            .filter_map(min_boolean)
+            .reduce(|acc, v| acc && v)
+    }
+
+    pub fn max(&self) -> Option<bool> {
+        self.iter_primitive()
+            .filter_map(max_boolean)
+            .reduce(|acc, v| acc || v)
+    }
+
+    // The sum of a boolean array is the number of true values
+    pub fn sum(&self) -> i32 {
+        self.iter_primitive()
+            .map(|arr| arr.iter().filter(|v| *v == Some(true)).count() as i32)
+            .sum()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let count = self.length - self.null_count();
+        if count == 0 {
+            None
+        } else {
+            Some(self.sum() as f64 / count as f64)
+        }
+    }
+}
+
+impl Utf8Chunked {
+    pub fn min(&self) -> Option<&str> {
+        self.iter_primitive()
+            .filter_map(min_string)
+            .reduce(|acc, v| if v < acc { v } else { acc })
+    }
+
+    pub fn max(&self) -> Option<&str> {
+        self.iter_primitive()
+            .filter_map(max_string)
+            .reduce(|acc, v| if v > acc { v } else { acc })
+    }
+}
+
+// How a quantile is picked when it lies between two values.
+// See: https://pola-rs.github.io/polars/py-polars/html/reference/series/api/polars.Series.quantile.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuantileInterpolOptions {
+    Nearest,
+    Lower,
+    Higher,
+    Midpoint,
+    Linear,
+}
+
+impl<T: LittleNumericType> ChunkedArray<T> {
+    // Non-null values of the array as f64
+    fn non_null_f64(&self) -> Vec<f64> {
+        self.iter_options().flatten().map(|v| v.to_f64()).collect()
+    }
+
+    pub fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Option<f64> {
+        let mut values = self.non_null_f64();
+        quantile_of(&mut values, quantile, interpol)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.quantile(0.5, QuantileInterpolOptions::Linear)
+    }
+
+    pub fn var(&self, ddof: u8) -> Option<f64> {
+        var_of(&self.non_null_f64(), ddof)
+    }
+
+    pub fn std(&self, ddof: u8) -> Option<f64> {
+        self.var(ddof).map(|var| var.sqrt())
+    }
+}
+
+// Computes the quantile of the values. The values are reordered in the process.
+pub fn quantile_of(
+    values: &mut [f64],
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> Option<f64> {
+    values.sort_by(|a, b| a.total_cmp(b));
+    quantile_of_sorted(values, quantile, interpol)
+}
+
+// Like quantile_of, for values that are already sorted
+pub fn quantile_of_sorted(
+    values: &[f64],
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> Option<f64> {
+    if !(0.0..=1.0).contains(&quantile) {
+        panic!("quantile should be between 0.0 and 1.0")
+    }
+    if values.is_empty() {
+        return None;
+    }
+    let float_idx = (values.len() - 1) as f64 * quantile;
+    let lower = values[float_idx.floor() as usize];
+    let upper = values[float_idx.ceil() as usize];
+    let value = match interpol {
+        QuantileInterpolOptions::Nearest => values[float_idx.round() as usize],
+        QuantileInterpolOptions::Lower => lower,
+        QuantileInterpolOptions::Higher => upper,
+        QuantileInterpolOptions::Midpoint => (lower + upper) / 2.0,
+        QuantileInterpolOptions::Linear => {
+            lower + (upper - lower) * (float_idx - float_idx.floor())
+        }
+    };
+    Some(value)
+}
+
+// Variance with `ddof` delta degrees of freedom. Returns None if there are not enough values.
+pub fn var_of(values: &[f64], ddof: u8) -> Option<f64> {
+    let count = values.len();
+    if count <= ddof as usize {
+        return None;
     }
+    let mean = values.iter().sum::<f64>() / count as f64;
+    let squared_diff = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+    Some(squared_diff / (count - ddof as usize) as f64)
 }
@@ -43,3 +43,103 @@ mod aggregate {
         arr.iter().max()
     }
 }
+
+#[cfg(test)]
+mod statistics {
+    use crate::chunked_array::{
+        aggregate::QuantileInterpolOptions, builder::NewFrom, types::I32Chunked, ChunkedArray,
+    };
+
+    #[test]
+    fn quantile() {
+        let chunked =
+            I32Chunked::from_slice_options("a", &vec![Some(4), None, Some(1), Some(3), Some(2)]);
+        assert_eq!(chunked.median(), Some(2.5));
+        assert_eq!(
+            chunked.quantile(0.5, QuantileInterpolOptions::Lower),
+            Some(2.0)
+        );
+        assert_eq!(
+            chunked.quantile(0.5, QuantileInterpolOptions::Higher),
+            Some(3.0)
+        );
+        assert_eq!(
+            chunked.quantile(0.5, QuantileInterpolOptions::Midpoint),
+            Some(2.5)
+        );
+        assert_eq!(
+            chunked.quantile(0.4, QuantileInterpolOptions::Nearest),
+            Some(2.0)
+        );
+        assert_eq!(
+            chunked.quantile(0.25, QuantileInterpolOptions::Linear),
+            Some(1.75)
+        );
+    }
+
+    #[test]
+    fn var_and_std() {
+        let chunked =
+            ChunkedArray::from_lists("a", vec![&[2.0, 4.0, 4.0, 4.0], &[5.0, 5.0, 7.0, 9.0]]);
+        assert_eq!(chunked.var(0), Some(4.0));
+        assert_eq!(chunked.std(0), Some(2.0));
+        assert_eq!(chunked.var(1), Some(32.0 / 7.0));
+
+        let single = ChunkedArray::new("a", &vec![1]);
+        assert_eq!(single.var(1), None);
+    }
+}
+
+#[cfg(test)]
+mod reductions {
+    use crate::chunked_array::{
+        builder::NewFrom,
+        types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+        ChunkedArray,
+    };
+
+    #[test]
+    fn numeric() {
+        let chunked = I32Chunked::from_slice_options("a", &vec![Some(3), None, Some(-1), Some(4)]);
+        assert_eq!(chunked.min(), Some(-1));
+        assert_eq!(chunked.max(), Some(4));
+        assert_eq!(chunked.sum(), 6);
+        assert_eq!(chunked.mean(), Some(2.0));
+
+        let chunked: F64Chunked = ChunkedArray::from_lists("a", vec![&[1.5, 0.5], &[4.0]]);
+        assert_eq!(chunked.min(), Some(0.5));
+        assert_eq!(chunked.max(), Some(4.0));
+        assert_eq!(chunked.sum(), 6.0);
+        assert_eq!(chunked.mean(), Some(2.0));
+
+        let nulls = I32Chunked::full_null("a", 2);
+        assert_eq!(nulls.min(), None);
+        assert_eq!(nulls.sum(), 0);
+        assert_eq!(nulls.mean(), None);
+    }
+
+    #[test]
+    fn sum_wraps_on_overflow() {
+        let chunked: I32Chunked = ChunkedArray::from_lists("a", vec![&[i32::MAX, 1], &[2]]);
+        assert_eq!(chunked.sum(), i32::MIN + 2);
+    }
+
+    #[test]
+    fn mean_does_not_overflow() {
+        let chunked: I32Chunked = ChunkedArray::from_lists("a", vec![&[i32::MAX, 1], &[i32::MAX]]);
+        assert_eq!(chunked.mean(), Some((2.0 * i32::MAX as f64 + 1.0) / 3.0));
+    }
+
+    #[test]
+    fn boolean_and_utf8() {
+        let chunked = BooleanChunked::from_slice_options("a", &vec![Some(true), None, Some(false)]);
+        assert_eq!(chunked.min(), Some(false));
+        assert_eq!(chunked.max(), Some(true));
+        assert_eq!(chunked.sum(), 1);
+        assert_eq!(chunked.mean(), Some(0.5));
+
+        let chunked = Utf8Chunked::from_slice_options("a", &vec![Some("b"), None, Some("a")]);
+        assert_eq!(chunked.min(), Some("a"));
+        assert_eq!(chunked.max(), Some("b"));
+    }
+}
@@ -0,0 +1,40 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use arrow2::compute::arithmetics::basic;
+
+use crate::little_arrow::types::ArrayRef;
+
+use super::{
+    types::{F64Chunked, I32Chunked},
+    utils::align_chunked_arrays,
+    ChunkedArray,
+};
+
+// Element-wise arithmetic between two arrays of the same length. A null on either side gives null.
+macro_rules! impl_arithmetic {
+    ($ca: ty, $trait: ident, $method: ident, $kernel: path) => {
+        impl $trait<&$ca> for &$ca {
+            type Output = $ca;
+
+            fn $method(self, rhs: &$ca) -> $ca {
+                assert_eq!(self.length, rhs.length);
+                let aligned_rhs = align_chunked_arrays(rhs, self);
+                let chunks = self
+                    .iter_primitive()
+                    .zip(aligned_rhs.iter_primitive())
+                    .map(|(a, b)| Box::new($kernel(a, b)) as ArrayRef)
+                    .collect::<Vec<_>>();
+                ChunkedArray::from_chunks(self.name(), chunks)
+            }
+        }
+    };
+}
+
+impl_arithmetic!(I32Chunked, Add, add, basic::add);
+impl_arithmetic!(I32Chunked, Sub, sub, basic::sub);
+impl_arithmetic!(I32Chunked, Mul, mul, basic::mul);
+impl_arithmetic!(I32Chunked, Div, div, basic::div);
+impl_arithmetic!(F64Chunked, Add, add, basic::add);
+impl_arithmetic!(F64Chunked, Sub, sub, basic::sub);
+impl_arithmetic!(F64Chunked, Mul, mul, basic::mul);
+impl_arithmetic!(F64Chunked, Div, div, basic::div);
@@ -0,0 +1,26 @@
+use crate::chunked_array::{
+    builder::NewFrom,
+    types::{F64Chunked, I32Chunked},
+    ChunkedArray,
+};
+
+#[test]
+fn test_i32_arithmetic() {
+    let a = I32Chunked::from_slice_options("a", &vec![Some(6), None, Some(2)]);
+    let b: I32Chunked = ChunkedArray::from_lists("b", vec![&[3, 1], &[-2]]);
+
+    assert_eq!((&a + &b).to_vec_options(), vec![Some(9), None, Some(0)]);
+    assert_eq!((&a - &b).to_vec_options(), vec![Some(3), None, Some(4)]);
+    assert_eq!((&a * &b).to_vec_options(), vec![Some(18), None, Some(-4)]);
+    assert_eq!((&a / &b).to_vec_options(), vec![Some(2), None, Some(-1)]);
+    assert_eq!((&a + &b).name(), "a");
+}
+
+#[test]
+fn test_f64_arithmetic() {
+    let a = F64Chunked::new("a", &vec![1.5, 3.0]);
+    let b = F64Chunked::new("b", &vec![0.5, 2.0]);
+
+    assert_eq!((&a * &b).to_vec_options(), vec![Some(0.75), Some(6.0)]);
+    assert_eq!((&a / &b).to_vec_options(), vec![Some(3.0), Some(1.5)]);
+}
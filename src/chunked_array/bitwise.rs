@@ -0,0 +1,30 @@
+use std::ops::{BitAnd, BitOr};
+
+use arrow2::compute::boolean_kleene;
+
+use crate::little_arrow::types::ArrayRef;
+
+use super::{types::BooleanChunked, utils::align_chunked_arrays, ChunkedArray};
+
+// Logical operations follow Kleene logic, so `false & null` is false and `true | null` is true.
+macro_rules! impl_bitwise {
+    ($trait: ident, $method: ident, $kernel: path) => {
+        impl $trait<&BooleanChunked> for &BooleanChunked {
+            type Output = BooleanChunked;
+
+            fn $method(self, rhs: &BooleanChunked) -> BooleanChunked {
+                assert_eq!(self.length, rhs.length);
+                let aligned_rhs = align_chunked_arrays(rhs, self);
+                let chunks = self
+                    .iter_primitive()
+                    .zip(aligned_rhs.iter_primitive())
+                    .map(|(a, b)| Box::new($kernel(a, b)) as ArrayRef)
+                    .collect::<Vec<_>>();
+                ChunkedArray::from_chunks(self.name(), chunks)
+            }
+        }
+    };
+}
+
+impl_bitwise!(BitAnd, bitand, boolean_kleene::and);
+impl_bitwise!(BitOr, bitor, boolean_kleene::or);
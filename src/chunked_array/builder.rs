@@ -1,13 +1,15 @@
 use std::marker::PhantomData;
 
 use arrow2::array::{
-    BooleanArray, Int32Array, MutableArray, MutableBooleanArray, MutablePrimitiveArray,
-    MutableUtf8Array, Utf8Array,
+    BooleanArray, Float64Array, Int32Array, MutableArray, MutableBooleanArray,
+    MutablePrimitiveArray, MutableUtf8Array, PrimitiveArray, Utf8Array,
 };
 
+use crate::types::LittleNumericType;
+
 use super::{
-    types::{BooleanChunked, I32Chunked, Utf8Chunked},
-    ChunkedArray,
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+    ChunkedArray, IsSorted,
 };
 
 pub trait NewFrom<TItem> {
@@ -29,6 +31,7 @@ impl NewFrom<bool> for BooleanChunked {
             chunks: vec![Box::new(primitive_array)],
             length,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         }
     }
@@ -46,6 +49,7 @@ impl NewFrom<bool> for BooleanChunked {
             chunks: vec![primitive_arr],
             length,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         }
     }
@@ -64,6 +68,7 @@ impl NewFrom<bool> for BooleanChunked {
             chunks: primitive_arrays,
             length: 0,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         };
         arr.compute_len();
@@ -82,6 +87,7 @@ impl NewFrom<i32> for I32Chunked {
             chunks: vec![Box::new(primitive_array)],
             length: length,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         }
     }
@@ -98,6 +104,7 @@ impl NewFrom<i32> for I32Chunked {
             chunks: vec![primitive_arr],
             length,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         }
     }
@@ -114,6 +121,7 @@ impl NewFrom<i32> for I32Chunked {
             chunks: primitive_arrays,
             length: 0,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         };
         arr.compute_len();
@@ -121,7 +129,61 @@ impl NewFrom<i32> for I32Chunked {
     }
 
     fn from_vec(name: &str, v: &[i32]) -> Self {
-        todo!()
+        Self::new(name, v)
+    }
+}
+
+impl NewFrom<f64> for F64Chunked {
+    fn new(name: &str, v: &[f64]) -> Self {
+        let primitive_array = Float64Array::from_iter(v.iter().copied().map(Some));
+        let length = primitive_array.len();
+        ChunkedArray {
+            chunks: vec![Box::new(primitive_array)],
+            length: length,
+            phantom: PhantomData,
+            sorted: IsSorted::Not,
+            name: name.into(),
+        }
+    }
+
+    fn from_slice_options(name: &str, v: &[Option<f64>]) -> Self {
+        let mut arr = MutablePrimitiveArray::new();
+        v.iter().copied().for_each(|a| match a {
+            Some(v) => arr.push(Some(v)),
+            None => arr.push(None),
+        });
+        let primitive_arr = arr.as_box();
+        let length = primitive_arr.len();
+        ChunkedArray {
+            chunks: vec![primitive_arr],
+            length,
+            phantom: PhantomData,
+            sorted: IsSorted::Not,
+            name: name.into(),
+        }
+    }
+
+    #[cfg(test)]
+    fn from_lists(name: &str, lists: Vec<&[f64]>) -> Self {
+        use crate::little_arrow::types::ArrayRef;
+
+        let primitive_arrays = lists
+            .iter()
+            .map(|list| Box::new(Float64Array::from_iter(list.iter().copied().map(Some))) as ArrayRef)
+            .collect::<Vec<_>>();
+        let mut arr = ChunkedArray {
+            chunks: primitive_arrays,
+            length: 0,
+            phantom: PhantomData,
+            sorted: IsSorted::Not,
+            name: name.into(),
+        };
+        arr.compute_len();
+        arr
+    }
+
+    fn from_vec(name: &str, v: &[f64]) -> Self {
+        Self::new(name, v)
     }
 }
 
@@ -133,6 +195,7 @@ impl NewFrom<&str> for Utf8Chunked {
             chunks: vec![Box::new(primitive_array)],
             length,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         }
     }
@@ -149,6 +212,7 @@ impl NewFrom<&str> for Utf8Chunked {
             chunks: vec![primitive_arr],
             length,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         }
     }
@@ -167,6 +231,7 @@ impl NewFrom<&str> for Utf8Chunked {
             chunks: primitive_arrays,
             length: 0,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
             name: name.into(),
         };
         arr.compute_len();
@@ -177,3 +242,41 @@ impl NewFrom<&str> for Utf8Chunked {
         todo!()
     }
 }
+
+impl<T: LittleNumericType> ChunkedArray<T> {
+    pub fn from_iter_options<I>(name: &str, it: I) -> Self
+    where
+        I: Iterator<Item = Option<T::Native>>,
+    {
+        let arr: PrimitiveArray<T::Native> = it.collect();
+        ChunkedArray::from_chunks(name, vec![Box::new(arr)])
+    }
+
+    pub fn from_iter_values<I>(name: &str, it: I) -> Self
+    where
+        I: Iterator<Item = T::Native>,
+    {
+        let arr: PrimitiveArray<T::Native> = it.map(Some).collect();
+        ChunkedArray::from_chunks(name, vec![Box::new(arr)])
+    }
+}
+
+impl BooleanChunked {
+    pub fn from_iter_options<I>(name: &str, it: I) -> Self
+    where
+        I: Iterator<Item = Option<bool>>,
+    {
+        let arr: BooleanArray = it.collect();
+        ChunkedArray::from_chunks(name, vec![Box::new(arr)])
+    }
+}
+
+impl Utf8Chunked {
+    pub fn from_iter_options<'a, I>(name: &str, it: I) -> Self
+    where
+        I: Iterator<Item = Option<&'a str>>,
+    {
+        let arr: Utf8Array<i64> = it.collect();
+        ChunkedArray::from_chunks(name, vec![Box::new(arr)])
+    }
+}
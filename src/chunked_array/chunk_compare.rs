@@ -3,56 +3,71 @@ use arrow2::compute::comparison;
 use crate::little_arrow::types::ArrayRef;
 
 use super::{
-    types::{BooleanChunked, I32Chunked, Utf8Chunked},
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
     utils::align_chunked_arrays,
     ChunkedArray,
 };
 
 pub trait ChunkCompare<Rhs> {
+    // Nulls are equal to each other
     fn equal(&self, rhs: Rhs) -> BooleanChunked;
-}
 
-impl ChunkCompare<&I32Chunked> for I32Chunked {
-    fn equal(&self, rhs: &I32Chunked) -> BooleanChunked {
-        let aligned_rhs = align_chunked_arrays(rhs, self);
-        let chunks = self
-            .iter_primitive()
-            .zip(aligned_rhs.iter_primitive())
-            .map(|(a, b)| {
-                let is_eq = comparison::eq_and_validity(a, b);
-                Box::new(is_eq) as ArrayRef
-            })
-            .collect::<Vec<_>>();
-        ChunkedArray::from_chunks(self.name(), chunks)
-    }
+    fn not_equal(&self, rhs: Rhs) -> BooleanChunked;
+
+    // The order comparisons are null where either side is null
+    fn gt(&self, rhs: Rhs) -> BooleanChunked;
+
+    fn gt_eq(&self, rhs: Rhs) -> BooleanChunked;
+
+    fn lt(&self, rhs: Rhs) -> BooleanChunked;
+
+    fn lt_eq(&self, rhs: Rhs) -> BooleanChunked;
 }
 
-impl ChunkCompare<&BooleanChunked> for BooleanChunked {
-    fn equal(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        let aligned_rhs = align_chunked_arrays(rhs, self);
-        let chunks = self
+// Applies an arrow2 comparison kernel to the aligned chunks of both sides
+macro_rules! compare_chunks {
+    ($lhs: ident, $rhs: ident, $kernel: path) => {{
+        let aligned_rhs = align_chunked_arrays($rhs, $lhs);
+        let chunks = $lhs
             .iter_primitive()
             .zip(aligned_rhs.iter_primitive())
-            .map(|(a, b)| {
-                let is_eq = comparison::eq_and_validity(a, b);
-                Box::new(is_eq) as ArrayRef
-            })
+            .map(|(a, b)| Box::new($kernel(a, b)) as ArrayRef)
             .collect::<Vec<_>>();
-        ChunkedArray::from_chunks(self.name(), chunks)
-    }
+        ChunkedArray::from_chunks($lhs.name(), chunks)
+    }};
 }
 
-impl ChunkCompare<&Utf8Chunked> for Utf8Chunked {
-    fn equal(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        let aligned_rhs = align_chunked_arrays(rhs, self);
-        let chunks = self
-            .iter_primitive()
-            .zip(aligned_rhs.iter_primitive())
-            .map(|(a, b)| {
-                let is_eq = comparison::eq_and_validity(a, b);
-                Box::new(is_eq) as ArrayRef
-            })
-            .collect::<Vec<_>>();
-        ChunkedArray::from_chunks(self.name(), chunks)
-    }
+macro_rules! impl_chunk_compare {
+    ($ca: ty) => {
+        impl ChunkCompare<&$ca> for $ca {
+            fn equal(&self, rhs: &$ca) -> BooleanChunked {
+                compare_chunks!(self, rhs, comparison::eq_and_validity)
+            }
+
+            fn not_equal(&self, rhs: &$ca) -> BooleanChunked {
+                compare_chunks!(self, rhs, comparison::neq_and_validity)
+            }
+
+            fn gt(&self, rhs: &$ca) -> BooleanChunked {
+                compare_chunks!(self, rhs, comparison::gt)
+            }
+
+            fn gt_eq(&self, rhs: &$ca) -> BooleanChunked {
+                compare_chunks!(self, rhs, comparison::gt_eq)
+            }
+
+            fn lt(&self, rhs: &$ca) -> BooleanChunked {
+                compare_chunks!(self, rhs, comparison::lt)
+            }
+
+            fn lt_eq(&self, rhs: &$ca) -> BooleanChunked {
+                compare_chunks!(self, rhs, comparison::lt_eq)
+            }
+        }
+    };
 }
+
+impl_chunk_compare!(I32Chunked);
+impl_chunk_compare!(F64Chunked);
+impl_chunk_compare!(BooleanChunked);
+impl_chunk_compare!(Utf8Chunked);
@@ -20,3 +20,29 @@ fn test_compare_utf8() {
     let is_equal = c1.equal(&c2);
     assert_eq!(is_equal.to_vec(), vec![true, true, false]);
 }
+
+#[test]
+fn test_order_comparisons() {
+    let c1 = ChunkedArray::from_slice_options("", &vec![Some(1), Some(2), None, Some(4)]);
+    let c2 = ChunkedArray::from_slice_options("", &vec![Some(2), Some(2), Some(3), Some(3)]);
+    assert_eq!(
+        c1.lt(&c2).into_iter().collect::<Vec<_>>(),
+        vec![Some(true), Some(false), None, Some(false)]
+    );
+    assert_eq!(
+        c1.lt_eq(&c2).into_iter().collect::<Vec<_>>(),
+        vec![Some(true), Some(true), None, Some(false)]
+    );
+    assert_eq!(
+        c1.gt(&c2).into_iter().collect::<Vec<_>>(),
+        vec![Some(false), Some(false), None, Some(true)]
+    );
+    assert_eq!(
+        c1.gt_eq(&c2).into_iter().collect::<Vec<_>>(),
+        vec![Some(false), Some(true), None, Some(true)]
+    );
+    assert_eq!(
+        c1.not_equal(&c2).into_iter().collect::<Vec<_>>(),
+        vec![Some(true), Some(false), Some(true), Some(true)]
+    );
+}
@@ -1,12 +1,12 @@
 use arrow2::array::Utf8Array;
 
 use crate::{
-    chunked_array::{builder::NewFrom, chunk_get::ChunkGet, ChunkedArray},
+    chunked_array::{builder::NewFrom, chunk_get::ChunkGet, types::AnyValue, ChunkedArray},
     series::Series,
     types::DataType,
 };
 
-use super::types::{BooleanChunked, I32Chunked, Utf8Chunked};
+use super::types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked};
 
 pub trait ChunkEqualElement {
     unsafe fn equal_element(&self, idx_self: usize, _other: &Series, idx_other: usize) -> bool;
@@ -30,6 +30,18 @@ impl ChunkEqualElement for I32Chunked {
     }
 }
 
+impl ChunkEqualElement for F64Chunked {
+    unsafe fn equal_element(&self, idx_self: usize, other: &Series, idx_other: usize) -> bool {
+        assert!(other.dtype() == DataType::Float64);
+        // Compares the bits like the hashes do, so that NaN rows fall in the same group
+        let bits = |value| match value {
+            Some(AnyValue::Float64(value)) => Some(f64::to_bits(value)),
+            _ => None,
+        };
+        bits(self.get_value(idx_self)) == bits(other.get(idx_other))
+    }
+}
+
 impl ChunkEqualElement for Utf8Chunked {
     unsafe fn equal_element(&self, idx_self: usize, other: &Series, idx_other: usize) -> bool {
         assert!(other.dtype() == DataType::Utf8);
@@ -1,6 +1,6 @@
 use super::{
     builder::NewFrom,
-    types::{BooleanChunked, I32Chunked, Utf8Chunked},
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
     ChunkedArray,
 };
 
@@ -15,6 +15,13 @@ impl ChunkFull<i32> for I32Chunked {
     }
 }
 
+impl ChunkFull<f64> for F64Chunked {
+    fn full(name: &str, value: f64, length: usize) -> Self {
+        let value = vec![value; length];
+        ChunkedArray::new(name, &value)
+    }
+}
+
 impl<'a> ChunkFull<&'a str> for Utf8Chunked {
     fn full(name: &str, value: &'a str, length: usize) -> Self {
         let value = vec![value; length];
@@ -1,10 +1,10 @@
-use arrow2::array::{Array, BooleanArray, Int32Array, Utf8Array};
+use arrow2::array::{Array, BooleanArray, Float64Array, Int32Array, Utf8Array};
 
 use crate::little_arrow::types::ArrayRef;
 
 use super::{
     builder::NewFrom,
-    types::{AnyValue, BooleanChunked, I32Chunked, Utf8Chunked},
+    types::{AnyValue, BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
     ChunkedArray,
 };
 
@@ -42,6 +42,16 @@ impl ChunkGet for I32Chunked {
     }
 }
 
+impl ChunkGet for F64Chunked {
+    fn get_value(&self, index: usize) -> Option<AnyValue> {
+        let (chunk_idx, idx) = get_chunk_idx(&self.chunks, index);
+        let chunk = &self.chunks[chunk_idx];
+        let arr = &**chunk;
+        let chunk = unsafe { &*(arr as *const dyn Array as *const Float64Array) };
+        chunk.get(idx).map(|any_value| AnyValue::Float64(any_value))
+    }
+}
+
 // Returns (chunk_idx, idx)
 fn get_chunk_idx(chunks: &Vec<ArrayRef>, idx: usize) -> (usize, usize) {
     let mut remaining_idx = idx;
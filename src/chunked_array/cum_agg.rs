@@ -0,0 +1,71 @@
+use crate::types::{LittleDataType, NumericNative};
+
+use super::{
+    types::{F64Chunked, I32Chunked},
+    ChunkedArray,
+};
+
+// Running aggregation over the non-null values. Nulls stay null and don't reset the state.
+fn cum_fold<T, F>(values: impl Iterator<Item = Option<T>>, f: F) -> impl Iterator<Item = Option<T>>
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut state = None;
+    values.map(move |value| {
+        let value = value?;
+        let next = match state {
+            Some(state) => f(state, value),
+            None => value,
+        };
+        state = Some(next);
+        Some(next)
+    })
+}
+
+impl<T: LittleDataType> ChunkedArray<T> {
+    // Number of non-null values up to and including every position
+    pub fn cum_count(&self) -> I32Chunked {
+        let mut count = 0;
+        let counts = self.chunks.iter().flat_map(|chunk| {
+            (0..chunk.len())
+                .map(|idx| {
+                    if chunk.is_valid(idx) {
+                        count += 1;
+                    }
+                    count
+                })
+                .collect::<Vec<i32>>()
+        });
+        I32Chunked::from_iter_values(self.name(), counts)
+    }
+}
+
+macro_rules! impl_cum_agg {
+    ($ca: ty, $native: ty) => {
+        impl $ca {
+            pub fn cum_sum(&self) -> Self {
+                let values = cum_fold(self.into_iter(), |a: $native, b| a.wrapping_add(b));
+                Self::from_iter_options(self.name(), values)
+            }
+
+            pub fn cum_prod(&self) -> Self {
+                let values = cum_fold(self.into_iter(), |a: $native, b| a.wrapping_mul(b));
+                Self::from_iter_options(self.name(), values)
+            }
+
+            pub fn cum_min(&self) -> Self {
+                let values = cum_fold(self.into_iter(), |a: $native, b| if b < a { b } else { a });
+                Self::from_iter_options(self.name(), values)
+            }
+
+            pub fn cum_max(&self) -> Self {
+                let values = cum_fold(self.into_iter(), |a: $native, b| if b > a { b } else { a });
+                Self::from_iter_options(self.name(), values)
+            }
+        }
+    };
+}
+
+impl_cum_agg!(I32Chunked, i32);
+impl_cum_agg!(F64Chunked, f64);
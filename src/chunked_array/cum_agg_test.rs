@@ -0,0 +1,78 @@
+use crate::chunked_array::{
+    builder::NewFrom,
+    types::{F64Chunked, I32Chunked, Utf8Chunked},
+    ChunkedArray,
+};
+
+#[test]
+fn test_cum_agg_with_nulls() {
+    let ca = I32Chunked::from_slice_options("a", &vec![Some(2), None, Some(-1), Some(3)]);
+    assert_eq!(
+        ca.cum_sum().into_iter().collect::<Vec<_>>(),
+        vec![Some(2), None, Some(1), Some(4)]
+    );
+    assert_eq!(
+        ca.cum_prod().into_iter().collect::<Vec<_>>(),
+        vec![Some(2), None, Some(-2), Some(-6)]
+    );
+    assert_eq!(
+        ca.cum_min().into_iter().collect::<Vec<_>>(),
+        vec![Some(2), None, Some(-1), Some(-1)]
+    );
+    assert_eq!(
+        ca.cum_max().into_iter().collect::<Vec<_>>(),
+        vec![Some(2), None, Some(2), Some(3)]
+    );
+    assert_eq!(
+        ca.cum_count().into_iter().collect::<Vec<_>>(),
+        vec![Some(1), Some(1), Some(2), Some(3)]
+    );
+}
+
+#[test]
+fn test_cum_agg_across_chunks() {
+    let ca: F64Chunked = ChunkedArray::from_lists("a", vec![&[1.5, 2.0], &[0.5], &[3.0]]);
+    assert_eq!(
+        ca.cum_sum().into_iter().collect::<Vec<_>>(),
+        vec![Some(1.5), Some(3.5), Some(4.0), Some(7.0)]
+    );
+    assert_eq!(
+        ca.cum_min().into_iter().collect::<Vec<_>>(),
+        vec![Some(1.5), Some(1.5), Some(0.5), Some(0.5)]
+    );
+    assert_eq!(
+        ca.cum_count().into_iter().collect::<Vec<_>>(),
+        vec![Some(1), Some(2), Some(3), Some(4)]
+    );
+}
+
+#[test]
+fn test_cum_count_utf8() {
+    let ca = Utf8Chunked::from_slice_options("a", &vec![None, Some("x"), None, Some("y")]);
+    assert_eq!(
+        ca.cum_count().into_iter().collect::<Vec<_>>(),
+        vec![Some(0), Some(1), Some(1), Some(2)]
+    );
+}
+
+#[test]
+fn test_cum_sum_and_prod_wrap_on_overflow() {
+    let ca = I32Chunked::from_slice_options("a", &vec![Some(i32::MAX), None, Some(2)]);
+    assert_eq!(
+        ca.cum_sum().into_iter().collect::<Vec<_>>(),
+        vec![Some(i32::MAX), None, Some(i32::MIN + 1)]
+    );
+    assert_eq!(
+        ca.cum_prod().into_iter().collect::<Vec<_>>(),
+        vec![Some(i32::MAX), None, Some(-2)]
+    );
+}
+
+#[test]
+fn test_diff_wraps_on_overflow() {
+    let ca = I32Chunked::new("a", &vec![i32::MIN, 1]);
+    assert_eq!(
+        ca.diff(-1).into_iter().collect::<Vec<_>>(),
+        vec![Some(i32::MAX), None]
+    );
+}
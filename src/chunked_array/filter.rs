@@ -4,7 +4,10 @@ use arrow2::compute::filter::filter as arrow_filter;
 use crate::types::LittleDataType;
 
 use super::{
-    types::{BooleanChunked, BooleanType, I32Chunked, I32Type, Utf8Chunked, Utf8Type},
+    types::{
+        BooleanChunked, BooleanType, F64Chunked, Float64Type, I32Chunked, I32Type, Utf8Chunked,
+        Utf8Type,
+    },
     utils::align_chunked_arrays,
     ChunkedArray,
 };
@@ -28,6 +31,18 @@ impl ChunkedArrayFilter<I32Type> for I32Chunked {
     }
 }
 
+impl ChunkedArrayFilter<Float64Type> for F64Chunked {
+    fn filter(&self, mask: &BooleanChunked) -> Self {
+        let aligned_mask = align_chunked_arrays(mask, self);
+        let chunks = self
+            .iter_primitive()
+            .zip(aligned_mask.iter_primitive())
+            .map(|(primitive_arr, mask)| arrow_filter(primitive_arr, mask).unwrap())
+            .collect();
+        F64Chunked::from_chunks(&self.name, chunks)
+    }
+}
+
 impl ChunkedArrayFilter<BooleanType> for BooleanChunked {
     fn filter(&self, mask: &BooleanChunked) -> Self {
         let aligned_mask = align_chunked_arrays(mask, self);
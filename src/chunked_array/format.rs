@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use super::types::{BooleanChunked, I32Chunked, Utf8Chunked};
+use super::types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked};
 
 macro_rules! format_chunked_array {
     ($f:ident, $chunked_array:expr) => {{
@@ -24,6 +24,12 @@ impl Debug for I32Chunked {
     }
 }
 
+impl Debug for F64Chunked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        format_chunked_array!(f, &self)
+    }
+}
+
 impl Debug for Utf8Chunked {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         format_chunked_array!(f, &self)
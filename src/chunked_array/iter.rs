@@ -1,7 +1,12 @@
 use arrow2::array::BooleanArray;
 use arrow2::array::*;
 
-use super::types::{BooleanChunked, I32Chunked, Utf8Chunked};
+use crate::types::LittleNumericType;
+
+use super::{
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+    ChunkedArray,
+};
 
 impl BooleanChunked {
     pub fn iter_primitive(&self) -> impl Iterator<Item = &BooleanArray> {
@@ -21,6 +26,28 @@ impl I32Chunked {
     }
 }
 
+impl F64Chunked {
+    pub fn iter_primitive(&self) -> impl Iterator<Item = &Float64Array> {
+        self.chunks.iter().map(|chunk| {
+            let arr = &**chunk;
+            unsafe { &*(arr as *const dyn Array as *const Float64Array) }
+        })
+    }
+}
+
+impl<T: LittleNumericType> ChunkedArray<T> {
+    pub fn downcast_iter(&self) -> impl Iterator<Item = &PrimitiveArray<T::Native>> {
+        self.chunks.iter().map(|chunk| {
+            let arr = &**chunk;
+            unsafe { &*(arr as *const dyn Array as *const PrimitiveArray<T::Native>) }
+        })
+    }
+
+    pub fn iter_options(&self) -> impl Iterator<Item = Option<T::Native>> + '_ {
+        self.downcast_iter().flatten().map(|e| e.copied())
+    }
+}
+
 impl Utf8Chunked {
     pub fn iter_primitive(&self) -> impl Iterator<Item = &Utf8Array<i64>> {
         self.chunks.iter().map(|chunk| {
@@ -55,6 +82,21 @@ impl<'a> IntoIterator for &'a I32Chunked {
     }
 }
 
+impl<'a> IntoIterator for &'a F64Chunked {
+    type Item = Option<f64>;
+
+    type IntoIter = Box<dyn Iterator<Item = Self::Item> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(
+            self.iter_primitive()
+                .flatten()
+                .map(|e| e.copied())
+                .into_iter(),
+        )
+    }
+}
+
 impl<'a> IntoIterator for &'a Utf8Chunked {
     type Item = Option<&'a str>;
 
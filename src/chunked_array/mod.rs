@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 use std::slice::Iter;
 use std::usize::MIN;
 
-use arrow2::array::{Array, BooleanArray, Int32Array, PrimitiveArray, Utf8Array};
+use arrow2::array::{new_null_array, Array, BooleanArray, Int32Array, PrimitiveArray, Utf8Array};
 use arrow2::buffer::Buffer;
 use arrow2::datatypes::DataType as ArrowDataType;
 
@@ -13,25 +13,41 @@ use crate::little_arrow::types::ArrayRef;
 use crate::types::{DataType, LittleDataType};
 
 use self::types::{AnyValue, I32Chunked, Utf8Chunked};
+use self::utils::convert_to_single_chunk;
 
 pub mod aggregate;
 mod aggregate_test;
+pub mod arithmetic;
+mod arithmetic_test;
+pub mod bitwise;
 pub mod builder;
 pub mod chunk_compare;
 mod chunk_compare_test;
 pub mod chunk_equal;
 pub mod chunk_full;
 pub mod chunk_get;
+pub mod cum_agg;
+mod cum_agg_test;
 pub mod filter;
 mod filter_test;
 pub mod format;
 mod iter;
 mod iter_test;
 mod mod_test;
+pub mod nulls;
+mod nulls_test;
+pub mod rank;
+mod rank_test;
+pub mod rolling_window;
+mod rolling_window_test;
+pub mod shift;
+mod shift_test;
 pub mod sort;
 mod sort_test;
 pub mod test_utils;
 pub mod to_vec;
+pub mod top_k;
+mod top_k_test;
 pub mod types;
 pub mod utils;
 mod utils_test;
@@ -42,10 +58,32 @@ pub struct ChunkedArray<T: LittleDataType> {
     // TODO: Finalize if this is number of chunks or total number of elements
     pub length: usize,
     phantom: PhantomData<T>,
+    sorted: IsSorted,
 }
 
 unsafe impl<T> Send for ChunkedArray<T> where T: LittleDataType {}
 
+// Sortedness of a ChunkedArray. Sorted arrays are expected to have their
+// nulls placed at the start, which is what ChunkedSort produces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsSorted {
+    Ascending,
+    Descending,
+    Not,
+}
+
+impl<T: LittleDataType> Clone for ChunkedArray<T> {
+    fn clone(&self) -> Self {
+        ChunkedArray {
+            name: self.name.clone(),
+            chunks: self.chunks.clone(),
+            length: self.length,
+            phantom: PhantomData,
+            sorted: self.sorted,
+        }
+    }
+}
+
 pub type ChunkLenIter<'a> = std::iter::Map<std::slice::Iter<'a, ArrayRef>, fn(&ArrayRef) -> usize>;
 
 impl<T> ChunkedArray<T>
@@ -66,11 +104,34 @@ where
             chunks,
             length: 0,
             phantom: PhantomData,
+            sorted: IsSorted::Not,
         };
         arr.compute_len();
         arr
     }
 
+    pub fn full_null(name: &str, length: usize) -> Self {
+        let arr = new_null_array(T::get_dtype().to_array_type(), length);
+        Self::from_chunks(name, vec![arr])
+    }
+
+    // Returns the ChunkedArray as a single chunk. Only copies the data if there are multiple chunks.
+    pub fn rechunk(&self) -> Self {
+        if self.chunks.len() <= 1 {
+            self.clone()
+        } else {
+            convert_to_single_chunk(self)
+        }
+    }
+
+    pub fn is_sorted_flag(&self) -> IsSorted {
+        self.sorted
+    }
+
+    pub fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        self.sorted = sorted;
+    }
+
     pub fn compute_len(&mut self) {
         let length = self.chunks.iter().fold(0, |acc, arr| acc + arr.len());
         self.length = length;
@@ -109,7 +170,10 @@ where
                 break;
             }
         }
-        Self::from_chunks(&self.name, output_chunks)
+        // A contiguous slice of a sorted array is still sorted
+        let mut out = Self::from_chunks(&self.name, output_chunks);
+        out.set_sorted_flag(self.sorted);
+        out
     }
 }
 
@@ -0,0 +1,30 @@
+use arrow2::{
+    array::{Array, BooleanArray},
+    compute::boolean,
+};
+
+use crate::{little_arrow::types::ArrayRef, types::LittleDataType};
+
+use super::{types::BooleanChunked, ChunkedArray};
+
+impl<T: LittleDataType> ChunkedArray<T> {
+    pub fn is_null(&self) -> BooleanChunked {
+        null_mask(self.name(), &self.chunks, boolean::is_null)
+    }
+
+    pub fn is_not_null(&self) -> BooleanChunked {
+        null_mask(self.name(), &self.chunks, boolean::is_not_null)
+    }
+}
+
+// Applies the kernel to every chunk, so the mask has the same chunks as the values
+pub(crate) fn null_mask<F>(name: &str, chunks: &[ArrayRef], kernel: F) -> BooleanChunked
+where
+    F: Fn(&dyn Array) -> BooleanArray,
+{
+    let chunks = chunks
+        .iter()
+        .map(|chunk| Box::new(kernel(chunk.as_ref())) as ArrayRef)
+        .collect();
+    BooleanChunked::from_chunks(name, chunks)
+}
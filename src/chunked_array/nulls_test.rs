@@ -0,0 +1,20 @@
+use crate::chunked_array::{
+    builder::NewFrom,
+    types::{I32Chunked, Utf8Chunked},
+};
+
+#[test]
+fn test_is_null() {
+    let ca = I32Chunked::from_slice_options("a", &vec![Some(1), None, Some(3)]);
+    assert_eq!(
+        ca.is_null().into_iter().collect::<Vec<_>>(),
+        vec![Some(false), Some(true), Some(false)]
+    );
+    assert_eq!(ca.is_null().name(), "a");
+
+    let ca = Utf8Chunked::from_slice_options("a", &vec![None, Some("x")]);
+    assert_eq!(
+        ca.is_not_null().into_iter().collect::<Vec<_>>(),
+        vec![Some(false), Some(true)]
+    );
+}
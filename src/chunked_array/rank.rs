@@ -0,0 +1,144 @@
+use crate::series::{constructor::IntoSeries, Series};
+
+use super::{
+    sort::TotalOrd,
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+};
+
+// How equal values are ranked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RankMethod {
+    // The mean of the positions of the ties
+    #[default]
+    Average,
+    // The lowest position of the ties
+    Min,
+    // The highest position of the ties
+    Max,
+    // Like Min, without gaps after the ties
+    Dense,
+    // Every value has its own rank, ties are ordered by their position
+    Ordinal,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RankOptions {
+    pub method: RankMethod,
+    pub descending: bool,
+}
+
+// Positions of the non-null values, ordered by value. Ties keep their order.
+fn arg_sort_non_null<T: TotalOrd>(values: &[Option<T>], descending: bool) -> Vec<usize> {
+    let mut order = (0..values.len())
+        .filter(|idx| values[*idx].is_some())
+        .collect::<Vec<usize>>();
+    order.sort_by(|a, b| {
+        let ordering = values[*a].tot_cmp(&values[*b]);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    order
+}
+
+// Ranks starting at 1. Nulls have no rank, and NaN ranks above every other float.
+fn rank_values<T: TotalOrd>(values: &[Option<T>], options: RankOptions) -> Vec<Option<f64>> {
+    let order = arg_sort_non_null(values, options.descending);
+    let mut ranks = vec![None; values.len()];
+    let mut dense_rank = 0;
+    let mut start = 0;
+    while start < order.len() {
+        let mut stop = start + 1;
+        while stop < order.len() && values[order[stop]].tot_cmp(&values[order[start]]).is_eq() {
+            stop += 1;
+        }
+        dense_rank += 1;
+        for (offset, idx) in order[start..stop].iter().enumerate() {
+            let rank = match options.method {
+                RankMethod::Average => (start + stop + 1) as f64 / 2.0,
+                RankMethod::Min => (start + 1) as f64,
+                RankMethod::Max => stop as f64,
+                RankMethod::Dense => dense_rank as f64,
+                RankMethod::Ordinal => (start + offset + 1) as f64,
+            };
+            ranks[*idx] = Some(rank);
+        }
+        start = stop;
+    }
+    ranks
+}
+
+fn ranks_to_series(name: &str, ranks: Vec<Option<f64>>, method: RankMethod) -> Series {
+    match method {
+        RankMethod::Average => F64Chunked::from_iter_options(name, ranks.into_iter()).into_series(),
+        _ => I32Chunked::from_iter_options(
+            name,
+            ranks.into_iter().map(|rank| rank.map(|r| r as i32)),
+        )
+        .into_series(),
+    }
+}
+
+macro_rules! impl_rank {
+    ($ca: ty) => {
+        impl $ca {
+            pub fn rank(&self, options: RankOptions) -> Series {
+                let values = self.into_iter().collect::<Vec<_>>();
+                ranks_to_series(self.name(), rank_values(&values, options), options.method)
+            }
+
+            // (min rank - 1) / (number of non-null values - 1), between 0 and 1
+            pub fn percent_rank(&self, descending: bool) -> Series {
+                let values = self.into_iter().collect::<Vec<_>>();
+                let options = RankOptions {
+                    method: RankMethod::Min,
+                    descending,
+                };
+                let count = values.iter().filter(|value| value.is_some()).count();
+                let ranks = rank_values(&values, options).into_iter().map(|rank| {
+                    rank.map(|rank| {
+                        if count > 1 {
+                            (rank - 1.0) / (count - 1) as f64
+                        } else {
+                            0.0
+                        }
+                    })
+                });
+                F64Chunked::from_iter_options(self.name(), ranks).into_series()
+            }
+
+            // Splits the values in order into `n` buckets numbered from 1, whose sizes differ
+            // by at most one. The first buckets are the larger ones.
+            pub fn ntile(&self, n: u32, descending: bool) -> Series {
+                assert!(n > 0, "ntile needs at least one bucket");
+                let values = self.into_iter().collect::<Vec<_>>();
+                let options = RankOptions {
+                    method: RankMethod::Ordinal,
+                    descending,
+                };
+                let count = values.iter().filter(|value| value.is_some()).count();
+                let n = n as usize;
+                let (size, remainder) = (count / n, count % n);
+                let buckets = rank_values(&values, options).into_iter().map(|rank| {
+                    rank.map(|rank| {
+                        let idx = rank as usize - 1;
+                        let bucket = if idx < remainder * (size + 1) {
+                            idx / (size + 1)
+                        } else {
+                            remainder + (idx - remainder * (size + 1)) / size
+                        };
+                        bucket as i32 + 1
+                    })
+                });
+                I32Chunked::from_iter_options(self.name(), buckets).into_series()
+            }
+        }
+    };
+}
+
+impl_rank!(I32Chunked);
+impl_rank!(F64Chunked);
+impl_rank!(Utf8Chunked);
+impl_rank!(BooleanChunked);
@@ -0,0 +1,116 @@
+use crate::{
+    chunked_array::{
+        builder::NewFrom,
+        rank::{RankMethod, RankOptions},
+        types::{F64Chunked, I32Chunked, Utf8Chunked},
+    },
+    series::Series,
+};
+
+fn rank(ca: &I32Chunked, method: RankMethod, descending: bool) -> Series {
+    ca.rank(RankOptions { method, descending })
+}
+
+#[test]
+fn test_rank_methods() {
+    let ca = I32Chunked::from_slice_options(
+        "a",
+        &vec![Some(3), Some(1), None, Some(3), Some(2), Some(3)],
+    );
+
+    assert_eq!(
+        rank(&ca, RankMethod::Average, false),
+        Series::from_slice_options(
+            "a",
+            &vec![Some(4.0), Some(1.0), None, Some(4.0), Some(2.0), Some(4.0)]
+        )
+    );
+    assert_eq!(
+        rank(&ca, RankMethod::Min, false),
+        Series::from_slice_options(
+            "a",
+            &vec![Some(3), Some(1), None, Some(3), Some(2), Some(3)]
+        )
+    );
+    assert_eq!(
+        rank(&ca, RankMethod::Max, false),
+        Series::from_slice_options(
+            "a",
+            &vec![Some(5), Some(1), None, Some(5), Some(2), Some(5)]
+        )
+    );
+    assert_eq!(
+        rank(&ca, RankMethod::Dense, false),
+        Series::from_slice_options(
+            "a",
+            &vec![Some(3), Some(1), None, Some(3), Some(2), Some(3)]
+        )
+    );
+    assert_eq!(
+        rank(&ca, RankMethod::Ordinal, false),
+        Series::from_slice_options(
+            "a",
+            &vec![Some(3), Some(1), None, Some(4), Some(2), Some(5)]
+        )
+    );
+    assert_eq!(
+        rank(&ca, RankMethod::Dense, true),
+        Series::from_slice_options(
+            "a",
+            &vec![Some(1), Some(3), None, Some(1), Some(2), Some(1)]
+        )
+    );
+}
+
+#[test]
+fn test_rank_utf8() {
+    let ca = Utf8Chunked::new("a", &vec!["b", "a", "c", "a"]);
+    let options = RankOptions {
+        method: RankMethod::Min,
+        descending: false,
+    };
+    assert_eq!(ca.rank(options), Series::from_vec("a", &vec![3, 1, 4, 1]));
+}
+
+#[test]
+fn test_rank_with_nan() {
+    // NaN ranks above every other float, and NaNs tie with each other
+    let ca = F64Chunked::from_slice_options(
+        "a",
+        &vec![Some(f64::NAN), Some(2.0), None, Some(f64::NAN), Some(1.0)],
+    );
+    let options = RankOptions {
+        method: RankMethod::Min,
+        descending: false,
+    };
+    assert_eq!(
+        ca.rank(options),
+        Series::from_slice_options("a", &vec![Some(3), Some(2), None, Some(3), Some(1)])
+    );
+}
+
+#[test]
+fn test_percent_rank() {
+    let ca = I32Chunked::new("a", &vec![10, 30, 20, 30, 40]);
+    assert_eq!(
+        ca.percent_rank(false),
+        Series::from_vec("a", &vec![0.0, 0.5, 0.25, 0.5, 1.0])
+    );
+    assert_eq!(
+        I32Chunked::new("a", &vec![7]).percent_rank(false),
+        Series::from_vec("a", &vec![0.0])
+    );
+}
+
+#[test]
+fn test_ntile() {
+    let ca = I32Chunked::new("a", &vec![5, 1, 4, 2, 3, 6, 7]);
+    assert_eq!(
+        ca.ntile(3, false),
+        Series::from_vec("a", &vec![2, 1, 2, 1, 1, 3, 3])
+    );
+    assert_eq!(
+        ca.ntile(10, false),
+        Series::from_vec("a", &vec![5, 1, 4, 2, 3, 6, 7])
+    );
+}
@@ -0,0 +1,407 @@
+use std::collections::VecDeque;
+
+use crate::types::{LittleNumericType, NumericNative};
+
+use super::{
+    aggregate::{quantile_of_sorted, QuantileInterpolOptions},
+    types::F64Chunked,
+    ChunkedArray,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingOptions {
+    pub window_size: usize,
+    // Minimum number of non-null values in a window to compute a result, otherwise it is null
+    pub min_periods: usize,
+    // Multiplied with the values at the same position of the window. The mean and the std use
+    // them as relative weights instead.
+    pub weights: Option<Vec<f64>>,
+    // Centers the window on the row instead of ending it there
+    pub center: bool,
+}
+
+impl RollingOptions {
+    pub fn new(window_size: usize) -> Self {
+        RollingOptions {
+            window_size,
+            min_periods: window_size,
+            weights: None,
+            center: false,
+        }
+    }
+
+    pub fn with_min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    pub fn with_center(mut self, center: bool) -> Self {
+        self.center = center;
+        self
+    }
+
+    fn validate(&self) {
+        if self.window_size == 0 {
+            panic!("window_size must be positive");
+        }
+        if self.min_periods > self.window_size {
+            panic!("min_periods cannot be larger than window_size");
+        }
+        if let Some(weights) = &self.weights {
+            if weights.len() != self.window_size {
+                panic!("there must be one weight for every position of the window");
+            }
+        }
+    }
+
+    // The nominal start of the window of a row, which can be before the first row, and the
+    // [start, stop) range of rows in it
+    fn window_bounds(&self, idx: usize, len: usize) -> (i64, usize, usize) {
+        let after = if self.center {
+            self.window_size.div_ceil(2)
+        } else {
+            1
+        };
+        let nominal_start = idx as i64 + after as i64 - self.window_size as i64;
+        (
+            nominal_start,
+            nominal_start.max(0) as usize,
+            (idx + after).min(len),
+        )
+    }
+
+    fn min_count(&self) -> usize {
+        self.min_periods.max(1)
+    }
+}
+
+// State of the aggregation of the non-null values in a window. The windows only move forward,
+// so every value is pushed and popped once.
+trait WindowState {
+    fn push(&mut self, idx: usize, value: f64);
+
+    fn pop(&mut self, idx: usize, value: f64);
+
+    fn value(&self, count: usize) -> Option<f64>;
+}
+
+// The non-finite values are counted apart, so that the sum recovers once they leave the window
+#[derive(Default)]
+struct SumState {
+    sum: f64,
+    nan_count: usize,
+    pos_inf_count: usize,
+    neg_inf_count: usize,
+}
+
+impl SumState {
+    fn count_of(&mut self, value: f64) -> Option<&mut usize> {
+        if value.is_nan() {
+            Some(&mut self.nan_count)
+        } else if value == f64::INFINITY {
+            Some(&mut self.pos_inf_count)
+        } else if value == f64::NEG_INFINITY {
+            Some(&mut self.neg_inf_count)
+        } else {
+            None
+        }
+    }
+
+    fn sum(&self) -> f64 {
+        match (self.nan_count, self.pos_inf_count, self.neg_inf_count) {
+            (0, 0, 0) => self.sum,
+            (0, _, 0) => f64::INFINITY,
+            (0, 0, _) => f64::NEG_INFINITY,
+            _ => f64::NAN,
+        }
+    }
+}
+
+impl WindowState for SumState {
+    fn push(&mut self, _idx: usize, value: f64) {
+        match self.count_of(value) {
+            Some(count) => *count += 1,
+            None => self.sum += value,
+        }
+    }
+
+    fn pop(&mut self, _idx: usize, value: f64) {
+        match self.count_of(value) {
+            Some(count) => *count -= 1,
+            None => self.sum -= value,
+        }
+    }
+
+    fn value(&self, _count: usize) -> Option<f64> {
+        Some(self.sum())
+    }
+}
+
+#[derive(Default)]
+struct MeanState(SumState);
+
+impl WindowState for MeanState {
+    fn push(&mut self, idx: usize, value: f64) {
+        self.0.push(idx, value);
+    }
+
+    fn pop(&mut self, idx: usize, value: f64) {
+        self.0.pop(idx, value);
+    }
+
+    fn value(&self, count: usize) -> Option<f64> {
+        Some(self.0.sum() / count as f64)
+    }
+}
+
+// Welford's algorithm, which stays accurate when values leave the window
+#[derive(Default)]
+struct VarState {
+    count: usize,
+    mean: f64,
+    squared_diff: f64,
+}
+
+impl WindowState for VarState {
+    fn push(&mut self, _idx: usize, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.squared_diff += delta * (value - self.mean);
+    }
+
+    fn pop(&mut self, _idx: usize, value: f64) {
+        self.count -= 1;
+        if self.count == 0 {
+            *self = VarState::default();
+            return;
+        }
+        let delta = value - self.mean;
+        self.mean -= delta / self.count as f64;
+        self.squared_diff -= delta * (value - self.mean);
+    }
+
+    fn value(&self, count: usize) -> Option<f64> {
+        (count > 1).then(|| (self.squared_diff.max(0.0) / (count - 1) as f64).sqrt())
+    }
+}
+
+// Monotonic deque of the candidates for the extreme value, in the order of the rows
+struct ExtremeState {
+    candidates: VecDeque<(usize, f64)>,
+    is_max: bool,
+}
+
+impl ExtremeState {
+    fn new(is_max: bool) -> Self {
+        ExtremeState {
+            candidates: VecDeque::new(),
+            is_max,
+        }
+    }
+}
+
+impl WindowState for ExtremeState {
+    fn push(&mut self, idx: usize, value: f64) {
+        while let Some((_, last)) = self.candidates.back() {
+            let dominated = if self.is_max {
+                *last <= value
+            } else {
+                *last >= value
+            };
+            if !dominated {
+                break;
+            }
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back((idx, value));
+    }
+
+    fn pop(&mut self, idx: usize, _value: f64) {
+        if matches!(self.candidates.front(), Some((first, _)) if *first == idx) {
+            self.candidates.pop_front();
+        }
+    }
+
+    fn value(&self, _count: usize) -> Option<f64> {
+        self.candidates.front().map(|(_, value)| *value)
+    }
+}
+
+// The values of the window kept sorted, which takes O(window_size) per row instead of O(1)
+struct QuantileState {
+    sorted: Vec<f64>,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+}
+
+impl WindowState for QuantileState {
+    fn push(&mut self, _idx: usize, value: f64) {
+        let position = self.sorted.partition_point(|v| v.total_cmp(&value).is_lt());
+        self.sorted.insert(position, value);
+    }
+
+    fn pop(&mut self, _idx: usize, value: f64) {
+        let position = self.sorted.partition_point(|v| v.total_cmp(&value).is_lt());
+        self.sorted.remove(position);
+    }
+
+    fn value(&self, _count: usize) -> Option<f64> {
+        quantile_of_sorted(&self.sorted, self.quantile, self.interpol)
+    }
+}
+
+fn rolling_state<S: WindowState>(
+    values: &[Option<f64>],
+    options: &RollingOptions,
+    mut state: S,
+) -> Vec<Option<f64>> {
+    let (mut start, mut stop, mut count) = (0, 0, 0);
+    (0..values.len())
+        .map(|idx| {
+            let (_, lower, upper) = options.window_bounds(idx, values.len());
+            while stop < upper {
+                if let Some(value) = values[stop] {
+                    state.push(stop, value);
+                    count += 1;
+                }
+                stop += 1;
+            }
+            while start < lower {
+                if let Some(value) = values[start] {
+                    state.pop(start, value);
+                    count -= 1;
+                }
+                start += 1;
+            }
+            if count >= options.min_count() {
+                state.value(count)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Calls `f` with the (weight, value) pairs of the non-null values of every window. This
+// takes O(window_size) per row, since every value has a different weight in every window.
+fn rolling_weighted<F>(
+    values: &[Option<f64>],
+    options: &RollingOptions,
+    weights: &[f64],
+    f: F,
+) -> Vec<Option<f64>>
+where
+    F: Fn(&[(f64, f64)]) -> Option<f64>,
+{
+    (0..values.len())
+        .map(|idx| {
+            let (nominal_start, lower, upper) = options.window_bounds(idx, values.len());
+            let pairs = (lower..upper)
+                .filter_map(|row| {
+                    let weight = weights[(row as i64 - nominal_start) as usize];
+                    values[row].map(|value| (weight, value))
+                })
+                .collect::<Vec<(f64, f64)>>();
+            if pairs.len() >= options.min_count() {
+                f(&pairs)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn weighted_products(pairs: &[(f64, f64)]) -> impl Iterator<Item = f64> + '_ {
+    pairs.iter().map(|(weight, value)| weight * value)
+}
+
+fn weighted_mean(pairs: &[(f64, f64)]) -> Option<f64> {
+    let total = pairs.iter().map(|(weight, _)| weight).sum::<f64>();
+    (total != 0.0).then(|| weighted_products(pairs).sum::<f64>() / total)
+}
+
+impl<T: LittleNumericType> ChunkedArray<T> {
+    fn rolling(
+        &self,
+        options: &RollingOptions,
+        state: impl WindowState,
+        weighted: impl Fn(&[(f64, f64)]) -> Option<f64>,
+    ) -> F64Chunked {
+        options.validate();
+        let values = self
+            .iter_options()
+            .map(|value| value.map(|v| v.to_f64()))
+            .collect::<Vec<Option<f64>>>();
+        let output = match &options.weights {
+            Some(weights) => rolling_weighted(&values, options, weights, weighted),
+            None => rolling_state(&values, options, state),
+        };
+        F64Chunked::from_iter_options(self.name(), output.into_iter())
+    }
+
+    pub fn rolling_sum(&self, options: &RollingOptions) -> F64Chunked {
+        self.rolling(options, SumState::default(), |pairs| {
+            Some(weighted_products(pairs).sum())
+        })
+    }
+
+    pub fn rolling_mean(&self, options: &RollingOptions) -> F64Chunked {
+        self.rolling(options, MeanState::default(), weighted_mean)
+    }
+
+    pub fn rolling_min(&self, options: &RollingOptions) -> F64Chunked {
+        self.rolling(options, ExtremeState::new(false), |pairs| {
+            weighted_products(pairs).min_by(|a, b| a.total_cmp(b))
+        })
+    }
+
+    pub fn rolling_max(&self, options: &RollingOptions) -> F64Chunked {
+        self.rolling(options, ExtremeState::new(true), |pairs| {
+            weighted_products(pairs).max_by(|a, b| a.total_cmp(b))
+        })
+    }
+
+    // Sample standard deviation. With weights, the squared differences from the weighted
+    // mean are weighted too.
+    pub fn rolling_std(&self, options: &RollingOptions) -> F64Chunked {
+        self.rolling(options, VarState::default(), |pairs| {
+            if pairs.len() < 2 {
+                return None;
+            }
+            let mean = weighted_mean(pairs)?;
+            let total = pairs.iter().map(|(weight, _)| weight).sum::<f64>();
+            let var = pairs
+                .iter()
+                .map(|(weight, value)| weight * (value - mean) * (value - mean))
+                .sum::<f64>()
+                / total;
+            // Same correction as the unweighted sample std, which it equals with equal weights
+            let count = pairs.len() as f64;
+            Some((var * count / (count - 1.0)).sqrt())
+        })
+    }
+
+    pub fn rolling_quantile(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+        options: &RollingOptions,
+    ) -> F64Chunked {
+        let state = QuantileState {
+            sorted: Vec::with_capacity(options.window_size),
+            quantile,
+            interpol,
+        };
+        self.rolling(options, state, |pairs| {
+            let mut products = weighted_products(pairs).collect::<Vec<f64>>();
+            products.sort_by(|a, b| a.total_cmp(b));
+            quantile_of_sorted(&products, quantile, interpol)
+        })
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    chunked_array::{
+        aggregate::QuantileInterpolOptions,
+        builder::NewFrom,
+        rolling_window::RollingOptions,
+        types::{F64Chunked, I32Chunked},
+        ChunkedArray,
+    },
+    series::Series,
+    types::DataType,
+};
+
+#[cfg(test)]
+fn values(ca: &F64Chunked) -> Vec<Option<f64>> {
+    ca.into_iter().collect()
+}
+
+#[cfg(test)]
+fn assert_approx_eq(computed: &F64Chunked, expected: &[Option<f64>]) {
+    let computed = values(computed);
+    assert_eq!(computed.len(), expected.len());
+    for (computed, expected) in computed.iter().zip(expected) {
+        match (computed, expected) {
+            (Some(computed), Some(expected)) => assert!((computed - expected).abs() < 1e-9),
+            _ => assert_eq!(computed, expected),
+        }
+    }
+}
+
+#[test]
+fn test_rolling_sum_and_mean() {
+    let ca: I32Chunked = ChunkedArray::from_lists("a", vec![&[1, 2], &[3, 4, 5]]);
+    assert_eq!(
+        values(&ca.rolling_sum(&RollingOptions::new(3))),
+        vec![None, None, Some(6.0), Some(9.0), Some(12.0)]
+    );
+    assert_eq!(
+        values(&ca.rolling_sum(&RollingOptions::new(3).with_min_periods(1))),
+        vec![Some(1.0), Some(3.0), Some(6.0), Some(9.0), Some(12.0)]
+    );
+    assert_eq!(
+        values(&ca.rolling_mean(&RollingOptions::new(2))),
+        vec![None, Some(1.5), Some(2.5), Some(3.5), Some(4.5)]
+    );
+}
+
+#[test]
+fn test_rolling_sum_recovers_after_non_finite_values() {
+    let ca = F64Chunked::new("a", &vec![1.0, f64::NAN, 1.0, 1.0, 1.0]);
+    let sums = values(&ca.rolling_sum(&RollingOptions::new(2)));
+    assert!(sums[1].unwrap().is_nan() && sums[2].unwrap().is_nan());
+    assert_eq!(sums[3..], [Some(2.0), Some(2.0)]);
+
+    let ca = F64Chunked::new("a", &vec![1.0, f64::INFINITY, f64::NEG_INFINITY, 1.0, 3.0]);
+    let means = values(&ca.rolling_mean(&RollingOptions::new(2)));
+    assert_eq!(means[..2], [None, Some(f64::INFINITY)]);
+    assert!(means[2].unwrap().is_nan());
+    assert_eq!(means[3..], [Some(f64::NEG_INFINITY), Some(2.0)]);
+}
+
+#[test]
+fn test_rolling_center() {
+    let ca = I32Chunked::new("a", &vec![1, 2, 3, 4, 5]);
+    let options = RollingOptions::new(3).with_min_periods(1).with_center(true);
+    assert_eq!(
+        values(&ca.rolling_sum(&options)),
+        vec![Some(3.0), Some(6.0), Some(9.0), Some(12.0), Some(9.0)]
+    );
+    let options = RollingOptions::new(4).with_min_periods(1).with_center(true);
+    assert_eq!(
+        values(&ca.rolling_sum(&options)),
+        vec![Some(3.0), Some(6.0), Some(10.0), Some(14.0), Some(12.0)]
+    );
+}
+
+#[test]
+fn test_rolling_min_max() {
+    let ca = I32Chunked::new("a", &vec![3, 1, 4, 1, 5, 9, 2]);
+    let options = RollingOptions::new(3);
+    assert_eq!(
+        values(&ca.rolling_min(&options)),
+        vec![
+            None,
+            None,
+            Some(1.0),
+            Some(1.0),
+            Some(1.0),
+            Some(1.0),
+            Some(2.0)
+        ]
+    );
+    assert_eq!(
+        values(&ca.rolling_max(&options)),
+        vec![
+            None,
+            None,
+            Some(4.0),
+            Some(4.0),
+            Some(5.0),
+            Some(9.0),
+            Some(9.0)
+        ]
+    );
+}
+
+#[test]
+fn test_rolling_with_nulls() {
+    let ca = I32Chunked::from_slice_options("a", &vec![Some(1), None, Some(3), Some(4)]);
+    assert_eq!(
+        values(&ca.rolling_sum(&RollingOptions::new(2).with_min_periods(1))),
+        vec![Some(1.0), Some(1.0), Some(3.0), Some(7.0)]
+    );
+    assert_eq!(
+        values(&ca.rolling_sum(&RollingOptions::new(2))),
+        vec![None, None, None, Some(7.0)]
+    );
+    assert_eq!(
+        values(&ca.rolling_max(&RollingOptions::new(2).with_min_periods(1))),
+        vec![Some(1.0), Some(1.0), Some(3.0), Some(4.0)]
+    );
+}
+
+#[test]
+fn test_rolling_std_and_quantile() {
+    let ca: F64Chunked = ChunkedArray::from_lists("a", vec![&[1.0, 2.0, 3.0], &[4.0, 5.0]]);
+    assert_approx_eq(
+        &ca.rolling_std(&RollingOptions::new(3)),
+        &[None, None, Some(1.0), Some(1.0), Some(1.0)],
+    );
+    assert_approx_eq(
+        &ca.rolling_std(&RollingOptions::new(3).with_weights(vec![1.0, 1.0, 1.0])),
+        &[None, None, Some(1.0), Some(1.0), Some(1.0)],
+    );
+
+    let ca = I32Chunked::new("a", &vec![3, 1, 4, 1, 5, 9, 2]);
+    assert_eq!(
+        values(&ca.rolling_quantile(
+            0.5,
+            QuantileInterpolOptions::Linear,
+            &RollingOptions::new(3)
+        )),
+        vec![
+            None,
+            None,
+            Some(3.0),
+            Some(1.0),
+            Some(4.0),
+            Some(5.0),
+            Some(5.0)
+        ]
+    );
+}
+
+#[test]
+fn test_rolling_weights() {
+    let ca = I32Chunked::new("a", &vec![1, 2, 3, 4]);
+    assert_eq!(
+        values(&ca.rolling_sum(&RollingOptions::new(2).with_weights(vec![0.5, 1.0]))),
+        vec![None, Some(2.5), Some(4.0), Some(5.5)]
+    );
+    assert_eq!(
+        values(&ca.rolling_mean(&RollingOptions::new(2).with_weights(vec![1.0, 3.0]))),
+        vec![None, Some(1.75), Some(2.75), Some(3.75)]
+    );
+}
+
+#[test]
+fn test_rolling_series_dtypes() {
+    let series = Series::new("a", &vec![1, 2, 3]);
+    let options = RollingOptions::new(2).with_min_periods(1);
+    assert_eq!(
+        series.rolling_sum(&options),
+        Series::new("a", &vec![1, 3, 5])
+    );
+    assert_eq!(series.rolling_mean(&options).dtype(), DataType::Float64);
+    assert_eq!(
+        series
+            .rolling_max(&options.with_weights(vec![1.0, 1.0]))
+            .dtype(),
+        DataType::Float64
+    );
+}
@@ -0,0 +1,54 @@
+use crate::types::NumericNative;
+
+use super::types::{F64Chunked, I32Chunked};
+
+// For every position, the position `periods` rows before it, if there is one. A negative
+// `periods` looks ahead instead.
+pub(crate) fn shifted_indices(len: usize, periods: i64) -> impl Iterator<Item = Option<usize>> {
+    (0..len as i64).map(move |idx| {
+        let source = idx - periods;
+        (source >= 0 && source < len as i64).then_some(source as usize)
+    })
+}
+
+macro_rules! impl_shift {
+    ($ca: ty, $native: ty) => {
+        impl $ca {
+            // Moves the values down by `periods` rows, or up if it is negative. The emptied
+            // rows take `fill_value`, or null.
+            pub fn shift(&self, periods: i64, fill_value: Option<$native>) -> Self {
+                let values = self.into_iter().collect::<Vec<Option<$native>>>();
+                let shifted = shifted_indices(values.len(), periods).map(|source| match source {
+                    Some(source) => values[source],
+                    None => fill_value,
+                });
+                Self::from_iter_options(self.name(), shifted)
+            }
+
+            // Difference with the value `periods` rows before
+            pub fn diff(&self, periods: i64) -> Self {
+                let values = self.into_iter().collect::<Vec<Option<$native>>>();
+                let diffs = shifted_indices(values.len(), periods)
+                    .zip(&values)
+                    .map(|(source, value)| Some(value.as_ref()?.wrapping_sub(values[source?]?)));
+                Self::from_iter_options(self.name(), diffs)
+            }
+
+            // Relative change from the value `periods` rows before
+            pub fn pct_change(&self, periods: i64) -> F64Chunked {
+                let values = self.into_iter().collect::<Vec<Option<$native>>>();
+                let changes =
+                    shifted_indices(values.len(), periods)
+                        .zip(&values)
+                        .map(|(source, value)| {
+                            let previous = values[source?]? as f64;
+                            Some((*value.as_ref()? as f64 - previous) / previous)
+                        });
+                F64Chunked::from_iter_options(self.name(), changes)
+            }
+        }
+    };
+}
+
+impl_shift!(I32Chunked, i32);
+impl_shift!(F64Chunked, f64);
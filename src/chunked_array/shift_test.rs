@@ -0,0 +1,52 @@
+use crate::chunked_array::{
+    builder::NewFrom,
+    types::{F64Chunked, I32Chunked},
+    ChunkedArray,
+};
+
+#[test]
+fn test_shift() {
+    let ca: I32Chunked = ChunkedArray::from_lists("a", vec![&[1, 2], &[3, 4]]);
+    assert_eq!(
+        ca.shift(1, None).into_iter().collect::<Vec<_>>(),
+        vec![None, Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        ca.shift(-2, Some(0)).into_iter().collect::<Vec<_>>(),
+        vec![Some(3), Some(4), Some(0), Some(0)]
+    );
+    assert_eq!(
+        ca.shift(5, None).into_iter().collect::<Vec<_>>(),
+        vec![None, None, None, None]
+    );
+    assert_eq!(
+        ca.shift(0, None).into_iter().collect::<Vec<_>>(),
+        vec![Some(1), Some(2), Some(3), Some(4)]
+    );
+}
+
+#[test]
+fn test_diff() {
+    let ca = I32Chunked::from_slice_options("a", &vec![Some(1), Some(4), None, Some(10), Some(8)]);
+    assert_eq!(
+        ca.diff(1).into_iter().collect::<Vec<_>>(),
+        vec![None, Some(3), None, None, Some(-2)]
+    );
+    assert_eq!(
+        ca.diff(2).into_iter().collect::<Vec<_>>(),
+        vec![None, None, None, Some(6), None]
+    );
+    assert_eq!(
+        ca.diff(-1).into_iter().collect::<Vec<_>>(),
+        vec![Some(-3), None, None, Some(2), None]
+    );
+}
+
+#[test]
+fn test_pct_change() {
+    let ca: F64Chunked = ChunkedArray::from_lists("a", vec![&[2.0, 3.0], &[1.5]]);
+    assert_eq!(
+        ca.pct_change(1).into_iter().collect::<Vec<_>>(),
+        vec![None, Some(0.5), Some(-0.5)]
+    );
+}
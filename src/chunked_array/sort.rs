@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, iter::repeat, ptr::null};
+use std::{cmp::Ordering, iter::repeat_n, ptr::null};
 
 use crate::core::POOL;
 use arrow2::{
@@ -7,10 +7,75 @@ use arrow2::{
 };
 use rayon::prelude::*;
 
-use super::{builder::NewFrom, iter, types::I32Chunked};
+use super::{
+    builder::NewFrom,
+    iter,
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+    IsSorted,
+};
+
+pub trait ChunkedSort: Sized {
+    // Sorts the values with the nulls first
+    fn sort(&self, descending: bool) -> Self {
+        self.sort_with(SortOptions {
+            descending,
+            nulls_last: false,
+        })
+    }
+
+    fn sort_with(&self, options: SortOptions) -> Self;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortOptions {
+    pub descending: bool,
+    // Nulls go first by default, whatever the direction
+    pub nulls_last: bool,
+}
+
+// A total order over values, where NaN is above every other float and equal to itself, like
+// in `arg_sort`
+pub trait TotalOrd {
+    fn tot_cmp(&self, other: &Self) -> Ordering;
+}
 
-pub trait ChunkedSort {
-    fn sort(&self, descending: bool) -> Self;
+impl TotalOrd for i32 {
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl TotalOrd for f64 {
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+impl TotalOrd for &str {
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl TotalOrd for bool {
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+// Nulls come first
+impl<T: TotalOrd> TotalOrd for Option<T> {
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.tot_cmp(b),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        }
+    }
+}
+
+pub trait ChunkedArgSort {
+    // Returns the positions of the values in sorted order. Equal values keep their order.
+    fn arg_sort(&self, options: SortOptions) -> Vec<usize>;
 }
 
 pub fn sort_list<T, Fc, Fa>(
@@ -32,39 +97,146 @@ where
 }
 
 impl ChunkedSort for I32Chunked {
-    fn sort(&self, descending: bool) -> Self {
+    fn sort_with(&self, options: SortOptions) -> Self {
+        let descending = options.descending;
         if self.null_count() == 0 {
             let mut list = self.to_vec();
             sort_list(&mut list, descending, |a, b| b.cmp(a), |a, b| a.cmp(b));
-            println!("List: {:?}", &list);
-            I32Chunked::new(&self.name, &list)
+            let mut out = I32Chunked::new(&self.name, &list);
+            out.set_sorted_flag(sorted_flag(descending));
+            out
         } else {
             let length = self.length;
             let mut list = Vec::with_capacity(self.length);
             let null_count = self.null_count();
 
-            // Place all the nulls at the start
-            list.extend(repeat(i32::default()).take(null_count));
-
+            // Place all the nulls at the start, or at the end with nulls_last
+            if !options.nulls_last {
+                list.extend(repeat_n(i32::default(), null_count));
+            }
             self.iter_primitive().for_each(|primitive_arr| {
                 let iter = primitive_arr.iter().filter_map(|a| a.copied());
                 list.extend(iter);
             });
+            let values = if options.nulls_last {
+                0..length - null_count
+            } else {
+                null_count..length
+            };
             sort_list(
-                &mut list[null_count..],
+                &mut list[values],
                 descending,
                 |a, b| b.cmp(a),
                 |a, b| a.cmp(b),
             );
             let mut validity = MutableBitmap::with_capacity(length);
-            validity.extend_constant(null_count, false);
-            validity.extend_constant(length - null_count, true);
+            if options.nulls_last {
+                list.extend(repeat_n(i32::default(), null_count));
+                validity.extend_constant(length - null_count, true);
+                validity.extend_constant(null_count, false);
+            } else {
+                validity.extend_constant(null_count, false);
+                validity.extend_constant(length - null_count, true);
+            }
             let primitive_arr = PrimitiveArray::new(
                 arrow2::datatypes::DataType::Int32,
                 list.into(),
                 validity.into(),
             );
-            I32Chunked::from_chunks(&self.name, vec![Box::new(primitive_arr)])
+            let mut out = I32Chunked::from_chunks(&self.name, vec![Box::new(primitive_arr)]);
+            out.set_sorted_flag(output_sorted_flag(options, null_count));
+            out
         }
     }
 }
+
+macro_rules! impl_sort {
+    ($ca: ty, $compare: expr) => {
+        impl ChunkedSort for $ca {
+            fn sort_with(&self, options: SortOptions) -> Self {
+                let null_count = self.null_count();
+                let mut list = self.into_iter().flatten().collect::<Vec<_>>();
+                sort_list(
+                    &mut list,
+                    options.descending,
+                    |a, b| ($compare)(b, a),
+                    $compare,
+                );
+                let nulls = repeat_n(None, null_count);
+                let values = list.into_iter().map(Some);
+                let mut out = if options.nulls_last {
+                    Self::from_iter_options(&self.name, values.chain(nulls))
+                } else {
+                    Self::from_iter_options(&self.name, nulls.chain(values))
+                };
+                out.set_sorted_flag(output_sorted_flag(options, null_count));
+                out
+            }
+        }
+    };
+}
+
+impl_sort!(Utf8Chunked, |a: &&str, b: &&str| a.cmp(b));
+impl_sort!(BooleanChunked, |a: &bool, b: &bool| a.cmp(b));
+
+// Sorted arrays are expected to have their nulls at the start, so nulls placed last leave
+// the output unflagged
+fn output_sorted_flag(options: SortOptions, null_count: usize) -> IsSorted {
+    if options.nulls_last && null_count > 0 {
+        IsSorted::Not
+    } else {
+        sorted_flag(options.descending)
+    }
+}
+
+pub fn sorted_flag(descending: bool) -> IsSorted {
+    if descending {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    }
+}
+
+// Sorts the positions of the non-null values in parallel, then places the nulls
+pub fn arg_sort_values<T, F>(values: Vec<Option<T>>, options: SortOptions, compare: F) -> Vec<usize>
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Send + Sync,
+{
+    let mut nulls = vec![];
+    let mut non_null = Vec::with_capacity(values.len());
+    for (idx, value) in values.into_iter().enumerate() {
+        match value {
+            Some(value) => non_null.push((idx, value)),
+            None => nulls.push(idx),
+        }
+    }
+    // Ties are broken by position since the parallel sort is unstable
+    sort_list(
+        &mut non_null,
+        options.descending,
+        |(idx_a, a), (idx_b, b)| compare(b, a).then(idx_a.cmp(idx_b)),
+        |(idx_a, a), (idx_b, b)| compare(a, b).then(idx_a.cmp(idx_b)),
+    );
+    let sorted = non_null.into_iter().map(|(idx, _)| idx);
+    if options.nulls_last {
+        sorted.chain(nulls).collect()
+    } else {
+        nulls.into_iter().chain(sorted).collect()
+    }
+}
+
+macro_rules! impl_arg_sort {
+    ($ca: ty, $compare: expr) => {
+        impl ChunkedArgSort for $ca {
+            fn arg_sort(&self, options: SortOptions) -> Vec<usize> {
+                arg_sort_values(self.into_iter().collect(), options, $compare)
+            }
+        }
+    };
+}
+
+impl_arg_sort!(I32Chunked, |a: &i32, b: &i32| a.cmp(b));
+impl_arg_sort!(F64Chunked, |a: &f64, b: &f64| a.total_cmp(b));
+impl_arg_sort!(Utf8Chunked, |a: &&str, b: &&str| a.cmp(b));
+impl_arg_sort!(BooleanChunked, |a: &bool, b: &bool| a.cmp(b));
@@ -72,3 +72,98 @@ mod sort_i32 {
         assert_eq!(&second, &vec![3, 5, 8, 19, 28, 30]);
     }
 }
+
+mod arg_sort {
+    use crate::chunked_array::{
+        builder::NewFrom,
+        sort::{ChunkedArgSort, SortOptions},
+        types::{F64Chunked, Utf8Chunked},
+    };
+
+    #[test]
+    fn arg_sort_nulls() {
+        let ca = F64Chunked::from_slice_options("", &vec![Some(2.5), None, Some(-1.0), Some(2.5)]);
+        let options = SortOptions {
+            descending: false,
+            nulls_last: false,
+        };
+        assert_eq!(ca.arg_sort(options), vec![1, 2, 0, 3]);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_last: true,
+        };
+        assert_eq!(ca.arg_sort(options), vec![0, 3, 2, 1]);
+    }
+
+    #[test]
+    fn arg_sort_utf8() {
+        let ca = Utf8Chunked::from_slice_options("", &vec![Some("b"), Some("a"), None, Some("c")]);
+        assert_eq!(ca.arg_sort(SortOptions::default()), vec![2, 1, 0, 3]);
+    }
+}
+
+mod sort_utf8_boolean {
+    use crate::chunked_array::{
+        builder::NewFrom,
+        sort::{ChunkedSort, SortOptions},
+        types::{BooleanChunked, Utf8Chunked},
+        ChunkedArray, IsSorted,
+    };
+
+    #[test]
+    fn sort_utf8() {
+        let ca: Utf8Chunked = ChunkedArray::from_lists("s", vec![&["pear", "apple"], &["fig"]]);
+        let sorted = ca.sort(false);
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![Some("apple"), Some("fig"), Some("pear")]
+        );
+        assert_eq!(sorted.is_sorted_flag(), IsSorted::Ascending);
+    }
+
+    #[test]
+    fn sort_utf8_nulls() {
+        let ca = Utf8Chunked::from_slice_options("s", &vec![Some("b"), None, Some("c"), Some("a")]);
+        let sorted = ca.sort(true);
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![None, Some("c"), Some("b"), Some("a")]
+        );
+        assert_eq!(sorted.is_sorted_flag(), IsSorted::Descending);
+
+        let sorted = ca.sort_with(SortOptions {
+            descending: false,
+            nulls_last: true,
+        });
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), Some("c"), None]
+        );
+        assert_eq!(sorted.is_sorted_flag(), IsSorted::Not);
+    }
+
+    #[test]
+    fn sort_boolean() {
+        let ca = BooleanChunked::from_slice_options(
+            "b",
+            &vec![Some(true), None, Some(false), Some(true)],
+        );
+        let sorted = ca.sort(false);
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![None, Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(sorted.is_sorted_flag(), IsSorted::Ascending);
+    }
+
+    #[test]
+    fn sort_i32_nulls_last() {
+        let ca = ChunkedArray::from_slice_options("", &vec![None, Some(12), None, Some(5)]);
+        let sorted = ca.sort_with(SortOptions {
+            descending: true,
+            nulls_last: true,
+        });
+        assert_eq!(sorted.to_vec_options(), vec![Some(12), Some(5), None, None]);
+    }
+}
@@ -1,6 +1,6 @@
 use arrow2::bitmap::Bitmap;
 
-use super::types::{BooleanChunked, I32Chunked};
+use super::types::{BooleanChunked, F64Chunked, I32Chunked};
 
 impl I32Chunked {
     pub fn to_vec(&self) -> Vec<i32> {
@@ -19,6 +19,21 @@ impl I32Chunked {
     }
 }
 
+impl F64Chunked {
+    pub fn to_vec(&self) -> Vec<f64> {
+        let mut list = Vec::with_capacity(self.length);
+        self.iter_primitive().for_each(|primitive_arr| {
+            list.extend_from_slice(primitive_arr.values());
+        });
+        list
+    }
+
+    pub fn to_vec_options(&self) -> Vec<Option<f64>> {
+        let it = self.into_iter();
+        it.collect()
+    }
+}
+
 impl BooleanChunked {
     pub fn to_vec(&self) -> Vec<bool> {
         let mut list = Vec::with_capacity(self.length);
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+use crate::core::POOL;
+
+use super::{
+    sort::SortOptions,
+    types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+};
+
+pub trait ChunkedArgTopK {
+    // Returns the first `k` positions of `arg_sort(options)` without sorting all the values
+    fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize>;
+}
+
+// Keeps the `k` first candidates in the order of `compare`, in no particular order
+pub fn select_first_k<T, F>(mut candidates: Vec<T>, k: usize, compare: &F) -> Vec<T>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    if k == 0 {
+        return vec![];
+    }
+    if candidates.len() > k {
+        candidates.select_nth_unstable_by(k - 1, compare);
+        candidates.truncate(k);
+    }
+    candidates
+}
+
+// Selects the first `k` values of every chunk in parallel, then the first `k` of those. The
+// chunks hold (position, value) pairs.
+pub fn arg_top_k_values<T, F>(
+    chunks: Vec<Vec<(usize, Option<T>)>>,
+    k: usize,
+    options: SortOptions,
+    compare: F,
+) -> Vec<usize>
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Send + Sync,
+{
+    // Ties are broken by position, like in arg_sort
+    let compare_pairs = |(idx_a, a): &(usize, T), (idx_b, b): &(usize, T)| {
+        let ordering = if options.descending {
+            compare(b, a)
+        } else {
+            compare(a, b)
+        };
+        ordering.then(idx_a.cmp(idx_b))
+    };
+    let (nulls, candidates): (Vec<_>, Vec<_>) = POOL.install(|| {
+        chunks
+            .into_par_iter()
+            .map(|chunk| {
+                let mut nulls = vec![];
+                let mut values = vec![];
+                for (idx, value) in chunk {
+                    match value {
+                        Some(value) => values.push((idx, value)),
+                        None => nulls.push(idx),
+                    }
+                }
+                (nulls, select_first_k(values, k, &compare_pairs))
+            })
+            .unzip()
+    });
+    let nulls = nulls.into_iter().flatten();
+    let mut values = select_first_k(
+        candidates.into_iter().flatten().collect(),
+        k,
+        &compare_pairs,
+    );
+    values.sort_unstable_by(compare_pairs);
+    let values = values.into_iter().map(|(idx, _)| idx);
+    if options.nulls_last {
+        values.chain(nulls).take(k).collect()
+    } else {
+        nulls.chain(values).take(k).collect()
+    }
+}
+
+macro_rules! impl_arg_top_k {
+    ($ca: ty, $compare: expr) => {
+        impl ChunkedArgTopK for $ca {
+            fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize> {
+                let mut values = self.into_iter().enumerate();
+                let chunks = self
+                    .chunk_length_it()
+                    .map(|len| values.by_ref().take(len).collect())
+                    .collect();
+                arg_top_k_values(chunks, k, options, $compare)
+            }
+        }
+    };
+}
+
+impl_arg_top_k!(I32Chunked, |a: &i32, b: &i32| a.cmp(b));
+impl_arg_top_k!(F64Chunked, |a: &f64, b: &f64| a.total_cmp(b));
+impl_arg_top_k!(Utf8Chunked, |a: &&str, b: &&str| a.cmp(b));
+impl_arg_top_k!(BooleanChunked, |a: &bool, b: &bool| a.cmp(b));
@@ -0,0 +1,66 @@
+use crate::{
+    chunked_array::{
+        builder::NewFrom,
+        sort::{ChunkedArgSort, SortOptions},
+        top_k::ChunkedArgTopK,
+        types::{F64Chunked, I32Chunked, Utf8Chunked},
+        ChunkedArray, IsSorted,
+    },
+    series::Series,
+};
+
+#[test]
+fn arg_top_k_nulls() {
+    let ca = F64Chunked::from_slice_options("", &vec![Some(2.5), None, Some(-1.0), Some(2.5)]);
+    let options = SortOptions {
+        descending: true,
+        nulls_last: true,
+    };
+    assert_eq!(ca.arg_top_k(2, options), vec![0, 3]);
+    assert_eq!(ca.arg_top_k(10, options), vec![0, 3, 2, 1]);
+
+    let options = SortOptions {
+        descending: false,
+        nulls_last: false,
+    };
+    assert_eq!(ca.arg_top_k(2, options), vec![1, 2]);
+}
+
+#[test]
+fn arg_top_k_multiple_chunks() {
+    let ca: I32Chunked = ChunkedArray::from_lists("", vec![&[5, 1, 9], &[7, 3], &[9, 0, 4]]);
+    for k in 0..=ca.length {
+        for descending in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_last: false,
+            };
+            assert_eq!(ca.arg_top_k(k, options), ca.arg_sort(options)[..k].to_vec());
+        }
+    }
+}
+
+#[test]
+fn arg_top_k_utf8() {
+    let ca = Utf8Chunked::from_slice_options("", &vec![Some("b"), Some("a"), None, Some("c")]);
+    assert_eq!(ca.arg_top_k(3, SortOptions::default()), vec![2, 1, 0]);
+}
+
+#[test]
+fn series_top_k() {
+    let series = Series::from_slice_options("a", &vec![Some(4), None, Some(8), Some(1)]);
+
+    let top = series.top_k(2, true);
+    assert_eq!(top, Series::from_vec("a", &vec![8, 4]));
+    assert_eq!(top.is_sorted_flag(), IsSorted::Descending);
+
+    let bottom = series.top_k(2, false);
+    assert_eq!(bottom, Series::from_vec("a", &vec![1, 4]));
+    assert_eq!(bottom.is_sorted_flag(), IsSorted::Ascending);
+
+    let all = series.top_k(5, true);
+    assert_eq!(
+        all,
+        Series::from_slice_options("a", &vec![Some(8), Some(4), Some(1), None])
+    );
+}
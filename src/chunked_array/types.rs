@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::types::{DataType, LittleDataType};
+use crate::types::{DataType, LittleDataType, LittleNumericType};
 
 use super::ChunkedArray;
 
@@ -24,6 +24,24 @@ impl LittleDataType for I32Type {
 
 pub type I32Chunked = ChunkedArray<I32Type>;
 
+impl LittleNumericType for I32Type {
+    type Native = i32;
+}
+
+pub struct Float64Type {}
+
+impl LittleDataType for Float64Type {
+    fn get_dtype() -> DataType {
+        DataType::Float64
+    }
+}
+
+pub type F64Chunked = ChunkedArray<Float64Type>;
+
+impl LittleNumericType for Float64Type {
+    type Native = f64;
+}
+
 pub struct Utf8Type {}
 
 impl LittleDataType for Utf8Type {
@@ -34,11 +52,12 @@ impl LittleDataType for Utf8Type {
 
 pub type Utf8Chunked = ChunkedArray<Utf8Type>;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum AnyValue<'a> {
     Boolean(bool),
     Utf8(&'a str),
     Int32(i32),
+    Float64(f64),
 }
 
 impl Display for AnyValue<'_> {
@@ -47,6 +66,7 @@ impl Display for AnyValue<'_> {
             AnyValue::Boolean(v) => write!(f, "{}", *v),
             AnyValue::Utf8(v) => write!(f, "{}", format_args!("\"{v}\"")),
             AnyValue::Int32(v) => write!(f, "{v}"),
+            AnyValue::Float64(v) => write!(f, "{v}"),
         }
     }
 }
@@ -49,6 +49,7 @@ where
         chunks: vec![chunk],
         length: 0,
         phantom: PhantomData,
+        sorted: chunked_array.sorted,
     };
     arr.compute_len();
     arr
@@ -0,0 +1,141 @@
+use crate::{core::schema::Schema, series::Series};
+
+use super::{utils::accumulate_dataframes_vertical, DataFrame};
+
+// How `concat` combines the frames
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConcatHow {
+    // Stacks the rows. The frames must have the same columns in the same order.
+    #[default]
+    Vertical,
+    // Stacks the rows of the union of the columns, filling the missing ones with nulls
+    Diagonal,
+    // Puts the columns side by side, filling the shorter frames with nulls
+    Horizontal,
+}
+
+impl DataFrame {
+    // Appends the rows of `other` as new chunks, so no data is copied
+    pub fn vstack(&self, other: &DataFrame) -> DataFrame {
+        let mut df = self.clone();
+        df.vstack_mut(other);
+        df
+    }
+
+    pub fn vstack_mut(&mut self, other: &DataFrame) {
+        check_same_schema(self, other);
+        *self = accumulate_dataframes_vertical(vec![self.clone(), other.clone()]);
+    }
+
+    // Appends the rows of `other` and merges the chunks of every column, which copies the
+    // data once instead of slowing down later operations on many chunks
+    pub fn extend(&mut self, other: &DataFrame) {
+        self.vstack_mut(other);
+        self.columns = self.columns.iter().map(|series| series.rechunk()).collect();
+    }
+}
+
+fn check_same_schema(df: &DataFrame, other: &DataFrame) {
+    if df.columns_count() != other.columns_count() {
+        panic!(
+            "cannot stack a frame of {} columns on a frame of {} columns",
+            other.columns_count(),
+            df.columns_count()
+        );
+    }
+    for (series, other) in df.columns.iter().zip(&other.columns) {
+        if series.name() != other.name() || series.dtype() != other.dtype() {
+            panic!(
+                "cannot stack column {}: {:?} on column {}: {:?}",
+                other.name(),
+                other.dtype(),
+                series.name(),
+                series.dtype()
+            );
+        }
+    }
+}
+
+pub fn concat(frames: &[DataFrame], how: ConcatHow) -> DataFrame {
+    if frames.is_empty() {
+        panic!("cannot concat an empty list of frames");
+    }
+    match how {
+        ConcatHow::Vertical => {
+            frames[1..]
+                .iter()
+                .for_each(|df| check_same_schema(&frames[0], df));
+            accumulate_dataframes_vertical(frames.to_vec())
+        }
+        ConcatHow::Diagonal => {
+            let schema = concat_schema(frames.iter().map(|df| df.schema()), how);
+            let frames = frames
+                .iter()
+                .map(|df| {
+                    let columns = schema
+                        .iter()
+                        .map(|(name, dtype)| {
+                            match df.columns.iter().find(|series| series.name() == name) {
+                                Some(series) => series.clone(),
+                                None => Series::full_null(name, df.rows_count(), dtype),
+                            }
+                        })
+                        .collect();
+                    DataFrame::new_no_checks(columns)
+                })
+                .collect();
+            accumulate_dataframes_vertical(frames)
+        }
+        ConcatHow::Horizontal => {
+            // Checks the names
+            concat_schema(frames.iter().map(|df| df.schema()), how);
+            let height = frames.iter().map(|df| df.rows_count()).max().unwrap();
+            let columns = frames
+                .iter()
+                .flat_map(|df| {
+                    let missing = height - df.rows_count();
+                    df.columns.iter().map(move |series| {
+                        if missing == 0 {
+                            return series.clone();
+                        }
+                        let nulls = Series::full_null(series.name(), missing, &series.dtype());
+                        let mut chunks = series.chunks().clone();
+                        chunks.extend(nulls.chunks().iter().cloned());
+                        Series::from_chunks_and_dtype(series.name(), chunks, &series.dtype())
+                    })
+                })
+                .collect();
+            DataFrame::new_no_checks(columns)
+        }
+    }
+}
+
+// The schema of the output of `concat`
+pub fn concat_schema<I>(schemas: I, how: ConcatHow) -> Schema
+where
+    I: IntoIterator<Item = Schema>,
+{
+    let mut schemas = schemas.into_iter();
+    let mut output = schemas
+        .next()
+        .expect("cannot concat an empty list of frames");
+    if how == ConcatHow::Vertical {
+        return output;
+    }
+    for schema in schemas {
+        for (name, dtype) in schema.iter() {
+            match output.get_field(name) {
+                None => output.with_column(name.clone(), dtype.clone()),
+                Some(_) if how == ConcatHow::Horizontal => {
+                    panic!("column {name} appears in more than one frame")
+                }
+                Some(field) if &field.dtype != dtype => panic!(
+                    "column {name} is {:?} in one frame and {dtype:?} in another",
+                    field.dtype
+                ),
+                Some(_) => {}
+            }
+        }
+    }
+    output
+}
@@ -0,0 +1,74 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::{
+        concat::{concat, ConcatHow},
+        DataFrame,
+    },
+    series::Series,
+};
+
+#[test]
+fn test_vstack_and_extend() {
+    let top = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("points", &vec![1, 2]),
+    ]);
+    let bottom = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["c"]),
+        Series::from_vec("points", &vec![3]),
+    ]);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_vec("points", &vec![1, 2, 3]),
+    ]);
+
+    let stacked = top.vstack(&bottom);
+    assert_eq!(stacked, expected);
+    assert_eq!(stacked.column("points").chunks().len(), 2);
+
+    let mut extended = top.clone();
+    extended.extend(&bottom);
+    assert_eq!(extended, expected);
+    assert_eq!(extended.column("points").chunks().len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_vstack_different_dtypes() {
+    let top = DataFrame::new(vec![Series::from_vec("points", &vec![1, 2])]);
+    let bottom = DataFrame::new(vec![Series::from_vec("points", &vec![3.0])]);
+    top.vstack(&bottom);
+}
+
+#[test]
+fn test_concat_diagonal() {
+    let first = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("points", &vec![1, 2]),
+    ]);
+    let second = DataFrame::new(vec![
+        Series::from_vec("score", &vec![0.5]),
+        Series::from_vec("name", &vec!["c"]),
+    ]);
+
+    let computed = concat(&[first, second], ConcatHow::Diagonal);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_slice_options("points", &vec![Some(1), Some(2), None]),
+        Series::from_slice_options("score", &vec![None, None, Some(0.5)]),
+    ]);
+    assert_eq!(computed, expected);
+}
+
+#[test]
+fn test_concat_horizontal() {
+    let left = DataFrame::new(vec![Series::from_vec("name", &vec!["a", "b", "c"])]);
+    let right = DataFrame::new(vec![Series::from_vec("points", &vec![1, 2])]);
+
+    let computed = concat(&[left, right], ConcatHow::Horizontal);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_slice_options("points", &vec![Some(1), Some(2), None]),
+    ]);
+    assert_eq!(computed, expected);
+}
@@ -7,4 +7,21 @@ impl DataFrame {
         let columns = self.apply_columns_par(&|series| series.filter(mask));
         DataFrame::new(columns)
     }
+
+    // Removes the rows with a null in any of the `subset` columns, or in any column
+    pub fn drop_nulls(&self, subset: Option<&[&str]>) -> DataFrame {
+        let by = match subset {
+            Some(subset) => self.select_series(subset),
+            None => self.columns.clone(),
+        };
+        if by.iter().all(|series| series.null_count() == 0) {
+            return self.clone();
+        }
+        let mask = by
+            .iter()
+            .map(|series| series.is_not_null())
+            .reduce(|left, right| &left & &right)
+            .unwrap();
+        self.filter(&mask)
+    }
 }
@@ -0,0 +1,21 @@
+use crate::{chunked_array::builder::NewFrom, dataframe::DataFrame, series::Series};
+
+#[test]
+fn test_drop_nulls() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![Some(1), None, Some(3), Some(4)]),
+        Series::from_slice_options("b", &vec![Some("x"), Some("y"), None, Some("z")]),
+    ]);
+
+    let expected = DataFrame::new(vec![
+        Series::from_vec("a", &vec![1, 4]),
+        Series::from_vec("b", &vec!["x", "z"]),
+    ]);
+    assert_eq!(df.drop_nulls(None), expected);
+
+    let expected = DataFrame::new(vec![
+        Series::from_vec("a", &vec![1, 3, 4]),
+        Series::from_slice_options("b", &vec![Some("x"), None, Some("z")]),
+    ]);
+    assert_eq!(df.drop_nulls(Some(&["a"])), expected);
+}
@@ -0,0 +1,275 @@
+use std::cmp::Ordering;
+
+use rayon::prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    chunked_array::{
+        aggregate::{quantile_of, var_of, QuantileInterpolOptions},
+        sort::TotalOrd,
+        types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+        ChunkedArray,
+    },
+    core::POOL,
+    series::{constructor::IntoSeries, Series},
+    types::{LittleDataType, LittleNumericType, NumericNative},
+};
+
+use super::GroupsProxy;
+
+// Group aggregations follow the null semantics of Polars:
+// - min, max and mean ignore nulls and return null if a group only has nulls.
+// - sum ignores nulls and returns 0 if a group only has nulls.
+// - count is the number of non-null values.
+// - n_unique counts null as a distinct value.
+
+// Runs `f` on the indices of every group in parallel.
+pub fn agg_helper_idx<O, F>(all: &[Vec<u32>], f: F) -> Vec<O>
+where
+    F: Fn(&[u32]) -> O + Send + Sync,
+    O: Send,
+{
+    POOL.install(|| all.par_iter().map(|idx| f(idx)).collect())
+}
+
+// Runs `f` on the offset and length of every group in parallel.
+pub fn agg_helper_slice<O, F>(offset: &[u32], len: &[u32], f: F) -> Vec<O>
+where
+    F: Fn(usize, usize) -> O + Send + Sync,
+    O: Send,
+{
+    POOL.install(|| {
+        offset
+            .par_iter()
+            .zip(len.par_iter())
+            .map(|(offset, len)| f(*offset as usize, *len as usize))
+            .collect()
+    })
+}
+
+// Evaluates `$body` for every group with `$rows` bound to an iterator over the row indices
+// of the group. Slice groups iterate over a range of the array, so nothing is copied.
+macro_rules! agg_over_rows {
+    ($groups: expr, |$rows: ident| $body: expr) => {
+        match $groups {
+            GroupsProxy::Idx { all, .. } => agg_helper_idx(all, |idx| {
+                let $rows = idx.iter().map(|i| *i as usize);
+                $body
+            }),
+            GroupsProxy::Slice { offset, len } => agg_helper_slice(offset, len, |offset, len| {
+                let $rows = offset..offset + len;
+                $body
+            }),
+        }
+    };
+}
+
+fn min_of<T: PartialOrd>(it: impl Iterator<Item = T>) -> Option<T> {
+    it.reduce(|acc, v| if v < acc { v } else { acc })
+}
+
+fn max_of<T: PartialOrd>(it: impl Iterator<Item = T>) -> Option<T> {
+    it.reduce(|acc, v| if v > acc { v } else { acc })
+}
+
+fn mean_of(it: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = it.fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    if count == 0 {
+        None
+    } else {
+        Some(sum / count as f64)
+    }
+}
+
+// Number of distinct values. Nulls are counted as a value, and so is NaN.
+fn n_unique_of<T: TotalOrd>(mut values: Vec<Option<T>>) -> i32 {
+    values.sort_by(|a, b| a.tot_cmp(b));
+    values.dedup_by(|a, b| a.tot_cmp(b).is_eq());
+    values.len() as i32
+}
+
+impl<T: LittleDataType> ChunkedArray<T> {
+    pub fn agg_count(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = &ca.chunks[0];
+        let counts = agg_over_rows!(groups, |rows| {
+            rows.filter(|i| arr.is_valid(*i)).count() as i32
+        });
+        I32Chunked::from_iter_values(self.name(), counts.into_iter()).into_series()
+    }
+}
+
+impl<T: LittleNumericType> ChunkedArray<T>
+where
+    ChunkedArray<T>: IntoSeries,
+{
+    pub fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| min_of(rows.filter_map(|i| arr.get(i))));
+        ChunkedArray::<T>::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| max_of(rows.filter_map(|i| arr.get(i))));
+        ChunkedArray::<T>::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            rows.filter_map(|i| arr.get(i))
+                .fold(T::Native::default(), |acc, v| acc.wrapping_add(v))
+        });
+        ChunkedArray::<T>::from_iter_values(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            mean_of(rows.filter_map(|i| arr.get(i)).map(|v| v.to_f64()))
+        });
+        F64Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            n_unique_of(rows.map(|i| arr.get(i)).collect())
+        });
+        I32Chunked::from_iter_values(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_quantile(
+        &self,
+        groups: &GroupsProxy,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            let mut values = rows
+                .filter_map(|i| arr.get(i))
+                .map(|v| v.to_f64())
+                .collect::<Vec<f64>>();
+            quantile_of(&mut values, quantile, interpol)
+        });
+        F64Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_median(&self, groups: &GroupsProxy) -> Series {
+        self.agg_quantile(groups, 0.5, QuantileInterpolOptions::Linear)
+    }
+
+    pub fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            let values = rows
+                .filter_map(|i| arr.get(i))
+                .map(|v| v.to_f64())
+                .collect::<Vec<f64>>();
+            var_of(&values, ddof)
+        });
+        F64Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            let values = rows
+                .filter_map(|i| arr.get(i))
+                .map(|v| v.to_f64())
+                .collect::<Vec<f64>>();
+            var_of(&values, ddof).map(|var| var.sqrt())
+        });
+        F64Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+}
+
+impl BooleanChunked {
+    pub fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| min_of(rows.filter_map(|i| arr.get(i))));
+        BooleanChunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| max_of(rows.filter_map(|i| arr.get(i))));
+        BooleanChunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    // The sum of a boolean column is the number of true values
+    pub fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            rows.filter(|i| arr.get(*i) == Some(true)).count() as i32
+        });
+        I32Chunked::from_iter_values(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            mean_of(
+                rows.filter_map(|i| arr.get(i))
+                    .map(|v| if v { 1.0 } else { 0.0 }),
+            )
+        });
+        F64Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            n_unique_of(rows.map(|i| arr.get(i)).collect())
+        });
+        I32Chunked::from_iter_values(self.name(), out.into_iter()).into_series()
+    }
+}
+
+impl Utf8Chunked {
+    pub fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| min_of(rows.filter_map(|i| arr.get(i))));
+        Utf8Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    pub fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| max_of(rows.filter_map(|i| arr.get(i))));
+        Utf8Chunked::from_iter_options(self.name(), out.into_iter()).into_series()
+    }
+
+    // Strings can't be summed or averaged, so like Polars we return nulls.
+    pub fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        Utf8Chunked::full_null(self.name(), groups.len()).into_series()
+    }
+
+    pub fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        F64Chunked::full_null(self.name(), groups.len()).into_series()
+    }
+
+    pub fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.rechunk();
+        let arr = ca.iter_primitive().next().unwrap();
+        let out = agg_over_rows!(groups, |rows| {
+            n_unique_of(rows.map(|i| arr.get(i)).collect())
+        });
+        I32Chunked::from_iter_values(self.name(), out.into_iter()).into_series()
+    }
+}
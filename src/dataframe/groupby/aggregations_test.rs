@@ -0,0 +1,179 @@
+use crate::{
+    chunked_array::{aggregate::QuantileInterpolOptions, builder::NewFrom},
+    dataframe::groupby::GroupsProxy,
+    series::Series,
+};
+
+fn groups() -> GroupsProxy {
+    GroupsProxy::Idx {
+        first: vec![0, 1, 4],
+        all: vec![vec![0, 2, 3], vec![1, 5], vec![4]],
+    }
+}
+
+#[test]
+fn test_agg_i32() {
+    let series = Series::from_slice_options(
+        "points",
+        &vec![Some(3), Some(5), None, Some(1), None, Some(5)],
+    );
+    let groups = groups();
+
+    assert_eq!(
+        series.agg_min(&groups),
+        Series::from_slice_options("points", &vec![Some(1), Some(5), None])
+    );
+    assert_eq!(
+        series.agg_max(&groups),
+        Series::from_slice_options("points", &vec![Some(3), Some(5), None])
+    );
+    assert_eq!(
+        series.agg_sum(&groups),
+        Series::from_vec("points", &vec![4, 10, 0])
+    );
+    assert_eq!(
+        series.agg_mean(&groups),
+        Series::from_slice_options("points", &vec![Some(2.0), Some(5.0), None])
+    );
+    assert_eq!(
+        series.agg_count(&groups),
+        Series::from_vec("points", &vec![2, 2, 0])
+    );
+    assert_eq!(
+        series.agg_n_unique(&groups),
+        Series::from_vec("points", &vec![3, 1, 1])
+    );
+    assert_eq!(
+        series.agg_first(&groups),
+        Series::from_slice_options("points", &vec![Some(3), Some(5), None])
+    );
+    assert_eq!(
+        series.agg_last(&groups),
+        Series::from_slice_options("points", &vec![Some(1), Some(5), None])
+    );
+}
+
+#[test]
+fn test_agg_sum_wraps_on_overflow() {
+    let series = Series::from_vec("points", &vec![i32::MAX, 1, 2, 3, 4, 5]);
+    assert_eq!(
+        series.agg_sum(&groups()),
+        Series::from_vec("points", &vec![i32::MIN + 4, 6, 4])
+    );
+}
+
+#[test]
+fn test_agg_n_unique_with_nan() {
+    let series = Series::from_slice_options(
+        "x",
+        &vec![
+            Some(f64::NAN),
+            Some(1.0),
+            Some(2.0),
+            Some(f64::NAN),
+            None,
+            Some(1.0),
+        ],
+    );
+    let groups = GroupsProxy::Slice {
+        offset: vec![0],
+        len: vec![6],
+    };
+    // Every NaN is the same value, like in the hash groups of `Series::n_unique`
+    assert_eq!(
+        series.agg_n_unique(&groups),
+        Series::from_vec("x", &vec![4])
+    );
+    assert_eq!(series.n_unique(), 4);
+}
+
+#[test]
+fn test_agg_multiple_chunks() {
+    let series = Series::from_lists("points", vec![&[1.5, 2.5, 3.0], &[4.0, 0.5, 1.0]]);
+    let groups = groups();
+
+    assert_eq!(
+        series.agg_min(&groups),
+        Series::from_vec("points", &vec![1.5, 1.0, 0.5])
+    );
+    assert_eq!(
+        series.agg_sum(&groups),
+        Series::from_vec("points", &vec![8.5, 3.5, 0.5])
+    );
+}
+
+#[test]
+fn test_agg_utf8_and_bool() {
+    let names = Series::from_slice_options(
+        "name",
+        &vec![Some("b"), Some("x"), Some("a"), None, None, Some("y")],
+    );
+    let flags = Series::from_vec("flag", &vec![true, false, false, true, true, false]);
+    let groups = groups();
+
+    assert_eq!(
+        names.agg_min(&groups),
+        Series::from_slice_options("name", &vec![Some("a"), Some("x"), None])
+    );
+    assert_eq!(
+        names.agg_max(&groups),
+        Series::from_slice_options("name", &vec![Some("b"), Some("y"), None])
+    );
+    assert_eq!(
+        names.agg_sum(&groups),
+        Series::from_slice_options("name", &vec![None::<&str>, None, None])
+    );
+    assert_eq!(
+        flags.agg_sum(&groups),
+        Series::from_vec("flag", &vec![2, 0, 1])
+    );
+    assert_eq!(
+        flags.agg_min(&groups),
+        Series::from_vec("flag", &vec![false, false, true])
+    );
+}
+
+#[test]
+fn test_agg_slice_groups_match_idx_groups() {
+    let series = Series::from_slice_options(
+        "points",
+        &vec![Some(3), None, Some(1), Some(5), Some(5), None],
+    );
+    let slice_groups = GroupsProxy::Slice {
+        offset: vec![0, 3, 5, 6],
+        len: vec![3, 2, 1, 0],
+    };
+    let idx_groups = slice_groups.to_idx().into_owned();
+    assert_eq!(
+        idx_groups,
+        GroupsProxy::Idx {
+            first: vec![0, 3, 5, 6],
+            all: vec![vec![0, 1, 2], vec![3, 4], vec![5], vec![]],
+        }
+    );
+
+    let aggregations: Vec<fn(&Series, &GroupsProxy) -> Series> = vec![
+        |s, g| s.agg_min(g),
+        |s, g| s.agg_max(g),
+        |s, g| s.agg_sum(g),
+        |s, g| s.agg_mean(g),
+        |s, g| s.agg_count(g),
+        |s, g| s.agg_n_unique(g),
+        |s, g| s.agg_first(g),
+        |s, g| s.agg_last(g),
+        |s, g| s.agg_median(g),
+        |s, g| s.agg_quantile(g, 0.5, QuantileInterpolOptions::Lower),
+        |s, g| s.agg_var(g, 0),
+    ];
+    for agg in aggregations {
+        assert_eq!(agg(&series, &slice_groups), agg(&series, &idx_groups));
+    }
+    assert_eq!(
+        series.agg_sum(&slice_groups),
+        Series::from_vec("points", &vec![4, 10, 0, 0])
+    );
+    assert_eq!(
+        series.agg_first(&slice_groups),
+        Series::from_slice_options("points", &vec![Some(3), Some(5), None, None])
+    );
+}
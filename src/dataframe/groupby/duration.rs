@@ -0,0 +1,74 @@
+// A number of days like "7d", or a number of index units like "3i". Index columns are Int32
+// and hold plain integers, which take index units, or dates as days since the epoch (like
+// arrow's Date32), which take days. There is no datetime type, so units shorter than a day
+// are not supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Duration {
+    // Days, or index units if `is_index` is set
+    value: i64,
+    is_index: bool,
+}
+
+impl Duration {
+    // Parses a string of <number><unit> parts, optionally preceded by "-". The units are
+    // d (days) and i (index units), which can't be mixed.
+    pub fn parse(duration: &str) -> Self {
+        let (negative, parts) = match duration.strip_prefix('-') {
+            Some(parts) => (true, parts),
+            None => (false, duration),
+        };
+        if parts.is_empty() {
+            panic!("invalid duration: {duration:?}");
+        }
+
+        let mut value = 0i64;
+        let mut is_index = None;
+        let mut chars = parts.chars().peekable();
+        while chars.peek().is_some() {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                number.push(c);
+            }
+            let mut unit = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                unit.push(c);
+            }
+            let number = number
+                .parse::<i64>()
+                .unwrap_or_else(|_| panic!("invalid duration: {duration:?}"));
+            let index_unit = match unit.as_str() {
+                "d" => false,
+                "i" => true,
+                _ => panic!(
+                    "invalid unit {unit:?} in duration {duration:?}, expected \"d\" (days) or \
+                     \"i\" (index units)"
+                ),
+            };
+            if *is_index.get_or_insert(index_unit) != index_unit {
+                panic!("cannot mix index and time units in duration: {duration:?}");
+            }
+            value += number;
+        }
+
+        Duration {
+            value: if negative { -value } else { value },
+            is_index: is_index.unwrap(),
+        }
+    }
+
+    pub fn is_index(&self) -> bool {
+        self.is_index
+    }
+
+    pub fn negate(&self) -> Self {
+        Duration {
+            value: -self.value,
+            is_index: self.is_index,
+        }
+    }
+
+    // The duration in the units of an Int32 index column
+    pub fn in_i32_units(&self) -> i64 {
+        self.value
+    }
+}
@@ -0,0 +1,23 @@
+use super::duration::Duration;
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(Duration::parse("7d").in_i32_units(), 7);
+    assert_eq!(Duration::parse("1d2d").in_i32_units(), 3);
+    assert_eq!(Duration::parse("-2d").in_i32_units(), -2);
+    assert_eq!(Duration::parse("3i").in_i32_units(), 3);
+    assert!(Duration::parse("3i").is_index());
+    assert!(!Duration::parse("3d").is_index());
+}
+
+#[test]
+#[should_panic]
+fn test_parse_duration_mixed_units() {
+    Duration::parse("1d2i");
+}
+
+#[test]
+#[should_panic(expected = "invalid unit \"h\"")]
+fn test_parse_sub_day_duration() {
+    Duration::parse("36h");
+}
@@ -0,0 +1,159 @@
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+use crate::{chunked_array::builder::NewFrom, core::POOL, dataframe::DataFrame, series::Series};
+
+use super::{
+    duration::Duration,
+    rolling::{find_windows, index_values, ClosedWindow},
+    GroupsProxy,
+};
+
+#[derive(Clone, Debug)]
+pub struct DynamicGroupOptions {
+    // An Int32 column, sorted ascending within every `by` group
+    pub index_column: String,
+    // Interval between the starts of the windows
+    pub every: Duration,
+    // Length of every window. Windows overlap if it is longer than `every`.
+    pub period: Duration,
+    // Shift of the window starts from the multiples of `every`
+    pub offset: Duration,
+    pub closed_window: ClosedWindow,
+}
+
+impl DynamicGroupOptions {
+    pub fn new(index_column: &str, every: &str) -> Self {
+        let every = Duration::parse(every);
+        DynamicGroupOptions {
+            index_column: index_column.into(),
+            every,
+            period: every,
+            offset: Duration::parse("0d"),
+            closed_window: ClosedWindow::Left,
+        }
+    }
+
+    pub fn with_period(mut self, period: &str) -> Self {
+        self.period = Duration::parse(period);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: &str) -> Self {
+        self.offset = Duration::parse(offset);
+        self
+    }
+
+    pub fn with_closed_window(mut self, closed_window: ClosedWindow) -> Self {
+        self.closed_window = closed_window;
+        self
+    }
+}
+
+impl DataFrame {
+    // Creates a group for every window of every `by` group that has rows. Windows start at
+    // the multiples of `every` shifted by `offset` and are `period` long, so a row can be in
+    // several windows. Returns the key columns, which are the `by` columns followed by the
+    // start and the stop of the windows, and the groups.
+    pub fn groupby_dynamic(
+        &self,
+        by: Vec<Series>,
+        options: &DynamicGroupOptions,
+    ) -> (Vec<Series>, GroupsProxy) {
+        let index = self.column(&options.index_column);
+        let values = index_values(&index);
+        let every = options.every.in_i32_units();
+        let period = options.period.in_i32_units();
+        let offset = options.offset.in_i32_units();
+        if every <= 0 || period <= 0 {
+            panic!("every and period must be positive");
+        }
+        let closed_window = options.closed_window;
+
+        let (mut keys, lower_bounds, groups) = if by.is_empty() {
+            let windows = dynamic_windows(&values, every, period, offset, closed_window);
+            let lower_bounds = windows.iter().map(|(lower, ..)| *lower).collect();
+            let (offset, len) = windows
+                .iter()
+                .map(|(_, start, stop)| (*start as u32, (stop - start) as u32))
+                .unzip();
+            (vec![], lower_bounds, GroupsProxy::Slice { offset, len })
+        } else {
+            let key_groups = self.compute_group_proxy(by.clone(), true);
+            let windows = POOL.install(|| {
+                key_groups
+                    .all_indices()
+                    .to_vec()
+                    .into_par_iter()
+                    .map(|rows| {
+                        let values = rows
+                            .iter()
+                            .map(|row| values[*row as usize])
+                            .collect::<Vec<i64>>();
+                        dynamic_windows(&values, every, period, offset, closed_window)
+                            .into_iter()
+                            .map(|(lower, start, stop)| {
+                                (rows[0], lower, rows[start..stop].to_vec())
+                            })
+                            .collect::<Vec<(u32, i64, Vec<u32>)>>()
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let mut key_rows = vec![];
+            let mut lower_bounds = vec![];
+            let mut first = vec![];
+            let mut all = vec![];
+            for (key_row, lower, rows) in windows.into_iter().flatten() {
+                key_rows.push(key_row as usize);
+                lower_bounds.push(lower);
+                first.push(rows[0]);
+                all.push(rows);
+            }
+            let keys = by.iter().map(|key| key.take_indices(&key_rows)).collect();
+            (keys, lower_bounds, GroupsProxy::Idx { first, all })
+        };
+
+        let lower = lower_bounds
+            .iter()
+            .map(|lower| *lower as i32)
+            .collect::<Vec<i32>>();
+        let upper = lower_bounds
+            .iter()
+            .map(|lower| (lower + period) as i32)
+            .collect::<Vec<i32>>();
+        keys.push(Series::new("_lower_boundary", &lower));
+        keys.push(Series::new("_upper_boundary", &upper));
+        (keys, groups)
+    }
+}
+
+// Returns the lower bound and the [start, stop) range of rows of every window that has rows
+fn dynamic_windows(
+    values: &[i64],
+    every: i64,
+    period: i64,
+    offset: i64,
+    closed_window: ClosedWindow,
+) -> Vec<(i64, usize, usize)> {
+    let (Some(first), Some(last)) = (values.first(), values.last()) else {
+        return vec![];
+    };
+    // Earlier windows can still hold the first value when they overlap
+    let mut lower = first.div_euclid(every) * every + offset;
+    while lower > *first {
+        lower -= every;
+    }
+    while lower - every + period >= *first {
+        lower -= every;
+    }
+    let mut lower_bounds = vec![];
+    while lower <= *last {
+        lower_bounds.push(lower);
+        lower += every;
+    }
+    find_windows(values, &lower_bounds, period, closed_window)
+        .into_iter()
+        .zip(lower_bounds)
+        .filter(|((start, stop), _)| start < stop)
+        .map(|((start, stop), lower)| (lower, start, stop))
+        .collect()
+}
@@ -0,0 +1,74 @@
+use crate::{chunked_array::builder::NewFrom, dataframe::DataFrame, series::Series};
+
+use super::{dynamic::DynamicGroupOptions, rolling::ClosedWindow, GroupsProxy};
+
+#[test]
+fn test_groupby_dynamic_tumbling() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("idx", &vec![0, 1, 3, 4, 5, 11]),
+        Series::from_vec("value", &vec![1, 2, 3, 4, 5, 6]),
+    ]);
+    let options = DynamicGroupOptions::new("idx", "5i");
+
+    let (keys, groups) = df.groupby_dynamic(vec![], &options);
+    assert_eq!(
+        keys,
+        vec![
+            Series::from_vec("_lower_boundary", &vec![0, 5, 10]),
+            Series::from_vec("_upper_boundary", &vec![5, 10, 15]),
+        ]
+    );
+    assert_eq!(
+        groups,
+        GroupsProxy::Slice {
+            offset: vec![0, 4, 5],
+            len: vec![4, 1, 1],
+        }
+    );
+}
+
+#[test]
+fn test_groupby_dynamic_hopping() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("idx", &vec![2, 3, 6, 7]),
+        Series::from_vec("value", &vec![1, 2, 3, 4]),
+    ]);
+    let options = DynamicGroupOptions::new("idx", "2i")
+        .with_period("4i")
+        .with_closed_window(ClosedWindow::Both);
+
+    let (keys, groups) = df.groupby_dynamic(vec![], &options);
+    assert_eq!(
+        keys[0],
+        Series::from_vec("_lower_boundary", &vec![-2, 0, 2, 4, 6])
+    );
+    assert_eq!(
+        df.column("value").agg_sum(&groups),
+        Series::from_vec("value", &vec![1, 3, 6, 7, 7])
+    );
+}
+
+#[test]
+fn test_groupby_dynamic_by_key() {
+    // Dates as days since the epoch
+    let df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "b", "a", "a", "b"]),
+        Series::from_vec("date", &vec![19000, 19001, 19002, 19008, 19009]),
+        Series::from_vec("amount", &vec![10, 20, 30, 40, 50]),
+    ]);
+    let options = DynamicGroupOptions::new("date", "7d");
+
+    let (keys, groups) = df.groupby_dynamic(vec![df.column("user")], &options);
+    assert_eq!(
+        keys,
+        vec![
+            Series::from_vec("user", &vec!["a", "a", "b", "b"]),
+            Series::from_vec("_lower_boundary", &vec![18998, 19005, 18998, 19005]),
+            Series::from_vec("_upper_boundary", &vec![19005, 19012, 19005, 19012]),
+        ]
+    );
+    assert_eq!(
+        df.column("amount").agg_sum(&groups),
+        Series::from_vec("amount", &vec![40, 40, 20, 50])
+    );
+}
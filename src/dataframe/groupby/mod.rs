@@ -1,34 +1,275 @@
-use std::collections::hash_map::RandomState;
+use std::{borrow::Cow, cmp::Ordering, collections::hash_map::RandomState};
 
 use hashbrown::{hash_map::RawEntryMut, HashMap};
-use rayon::prelude::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::prelude::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+};
 
 use crate::{
+    chunked_array::{sort::sort_list, IsSorted},
     core::{sync_ptr::SyncPtr, POOL},
     dataframe::{
         join::{compare_df_row, IdxHash},
-        utils::split_df,
+        utils::{accumulate_dataframes_vertical, split_df},
     },
     hashing::{
         hash_dataframes,
         partition::{_set_partition_size, this_partition},
+        IdBuildHasher,
     },
     series::Series,
 };
 
+pub mod aggregations;
+mod aggregations_test;
+pub mod duration;
+mod duration_test;
+pub mod dynamic;
+mod dynamic_test;
 mod mod_test;
+pub mod rolling;
+mod rolling_test;
 use super::DataFrame;
 
-#[derive(Debug)]
-pub struct GroupsProxy {
-    pub first: Vec<u32>,
-    pub all: Vec<Vec<u32>>,
+#[derive(Clone, Debug, PartialEq)]
+pub enum GroupsProxy {
+    // The row indices of every group, and the first row index of every group
+    Idx { first: Vec<u32>, all: Vec<Vec<u32>> },
+    // Groups of consecutive rows. Produced when the keys are sorted, so the groups don't
+    // need to allocate their indices.
+    Slice { offset: Vec<u32>, len: Vec<u32> },
+}
+
+impl GroupsProxy {
+    // Number of groups
+    pub fn len(&self) -> usize {
+        match self {
+            GroupsProxy::Idx { first, .. } => first.len(),
+            GroupsProxy::Slice { offset, .. } => offset.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Number of rows of every group
+    pub fn group_lengths(&self) -> Vec<u32> {
+        match self {
+            GroupsProxy::Idx { all, .. } => all.iter().map(|idx| idx.len() as u32).collect(),
+            GroupsProxy::Slice { len, .. } => len.clone(),
+        }
+    }
+
+    // Index of the first row of every group. None if the group is empty.
+    pub fn first_indices(&self) -> Vec<Option<usize>> {
+        match self {
+            GroupsProxy::Idx { all, .. } => all
+                .iter()
+                .map(|idx| idx.first().map(|idx| *idx as usize))
+                .collect(),
+            GroupsProxy::Slice { offset, len } => offset
+                .iter()
+                .zip(len)
+                .map(|(offset, len)| (*len > 0).then_some(*offset as usize))
+                .collect(),
+        }
+    }
+
+    // Index of the last row of every group. None if the group is empty.
+    pub fn last_indices(&self) -> Vec<Option<usize>> {
+        match self {
+            GroupsProxy::Idx { all, .. } => all
+                .iter()
+                .map(|idx| idx.last().map(|idx| *idx as usize))
+                .collect(),
+            GroupsProxy::Slice { offset, len } => offset
+                .iter()
+                .zip(len)
+                .map(|(offset, len)| (*len > 0).then(|| (offset + len - 1) as usize))
+                .collect(),
+        }
+    }
+
+    // Reorders the groups so that they are in the order of their first row
+    pub fn sort_by_first(&mut self) {
+        if let GroupsProxy::Idx { first, all } = self {
+            let mut groups = std::mem::take(first)
+                .into_iter()
+                .zip(std::mem::take(all))
+                .collect::<Vec<(u32, Vec<u32>)>>();
+            sort_list(
+                &mut groups,
+                false,
+                |a, b| b.0.cmp(&a.0),
+                |a, b| a.0.cmp(&b.0),
+            );
+            (*first, *all) = groups.into_iter().unzip();
+        }
+        // Slice groups are always ordered by their first row
+    }
+
+    // Reorders the groups by the value of their keys. Null keys come first.
+    pub fn sort_by_keys(&mut self, by: &[Series]) {
+        let first = self.first_indices();
+        let mut order = (0..self.len()).collect::<Vec<usize>>();
+        let compare = |a: &usize, b: &usize| compare_rows(by, first[*a], first[*b]);
+        sort_list(&mut order, false, |a, b| compare(b, a), compare);
+        *self = self.take_groups(&order);
+    }
+
+    // Orders the rows within every group by the values of `by`. Nulls come first and equal
+    // values keep their order.
+    pub fn sort_rows_by(&self, by: &Series) -> GroupsProxy {
+        let by = std::slice::from_ref(by);
+        let all = POOL.install(|| {
+            self.all_indices()
+                .par_iter()
+                .map(|idx| {
+                    let mut idx = idx.clone();
+                    idx.sort_by(|a, b| compare_rows(by, Some(*a as usize), Some(*b as usize)));
+                    idx
+                })
+                .collect::<Vec<Vec<u32>>>()
+        });
+        let first = all
+            .iter()
+            .map(|idx| idx.first().copied().unwrap_or(0))
+            .collect();
+        GroupsProxy::Idx { first, all }
+    }
+
+    // Returns the groups at the given positions
+    pub fn take_groups(&self, order: &[usize]) -> GroupsProxy {
+        match self {
+            GroupsProxy::Idx { first, all } => GroupsProxy::Idx {
+                first: order.iter().map(|i| first[*i]).collect(),
+                all: order.iter().map(|i| all[*i].clone()).collect(),
+            },
+            GroupsProxy::Slice { offset, len } => GroupsProxy::Slice {
+                offset: order.iter().map(|i| offset[*i]).collect(),
+                len: order.iter().map(|i| len[*i]).collect(),
+            },
+        }
+    }
+
+    // Converts the groups to the Idx representation
+    pub fn to_idx(&self) -> Cow<'_, GroupsProxy> {
+        match self {
+            GroupsProxy::Idx { .. } => Cow::Borrowed(self),
+            GroupsProxy::Slice { offset, len } => Cow::Owned(GroupsProxy::Idx {
+                first: offset.clone(),
+                all: offset
+                    .iter()
+                    .zip(len)
+                    .map(|(offset, len)| (*offset..offset + len).collect())
+                    .collect(),
+            }),
+        }
+    }
+
+    // The row indices of every group
+    pub fn all_indices(&self) -> Cow<'_, [Vec<u32>]> {
+        match self {
+            GroupsProxy::Idx { all, .. } => Cow::Borrowed(all),
+            GroupsProxy::Slice { .. } => match self.to_idx() {
+                Cow::Owned(GroupsProxy::Idx { all, .. }) => Cow::Owned(all),
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+fn compare_rows(by: &[Series], a: Option<usize>, b: Option<usize>) -> Ordering {
+    by.iter()
+        .map(|key| {
+            let a = a.and_then(|idx| key.get(idx));
+            let b = b.and_then(|idx| key.get(idx));
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        })
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+pub struct GroupBy<'df> {
+    df: &'df DataFrame,
+    by: Vec<Series>,
+    groups: GroupsProxy,
+}
+
+impl<'df> GroupBy<'df> {
+    pub fn keys(&self) -> &[Series] {
+        &self.by
+    }
+
+    pub fn groups(&self) -> &GroupsProxy {
+        &self.groups
+    }
+
+    // Calls `f` with the sub-frame of every group and stacks the outputs vertically
+    pub fn apply<F>(&self, f: F) -> DataFrame
+    where
+        F: Fn(DataFrame) -> DataFrame + Send + Sync,
+    {
+        self.df.apply_groups(&self.groups, &f)
+    }
 }
 
 impl DataFrame {
-    pub fn compute_group_proxy(&self, by: Vec<Series>) -> GroupsProxy {
+    // Groups the rows by the given key columns. Groups are in the order of their first row.
+    pub fn groupby<I, S>(&self, by: I) -> GroupBy<'_>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let by = self.select_series(by);
+        let groups = self.compute_group_proxy(by.clone(), true);
+        GroupBy {
+            df: self,
+            by,
+            groups,
+        }
+    }
+
+    // Materializes the sub-frame of every group, calls `f` on them in parallel and stacks
+    // the outputs in the order of the groups. Without groups, `f` is called on an empty
+    // frame so the output still has its columns.
+    pub fn apply_groups(
+        &self,
+        groups: &GroupsProxy,
+        f: &(dyn Fn(DataFrame) -> DataFrame + Send + Sync),
+    ) -> DataFrame {
+        if groups.is_empty() {
+            return f(self.slice(0, 0));
+        }
+        let all = groups.all_indices();
+        let dfs = POOL.install(|| {
+            all.par_iter()
+                .map(|idx| {
+                    let idx = idx.iter().map(|idx| *idx as usize).collect::<Vec<usize>>();
+                    f(self.create_df_from_slice(&idx))
+                })
+                .collect::<Vec<DataFrame>>()
+        });
+        accumulate_dataframes_vertical(dfs)
+    }
+
+    // Groups are in an arbitrary order unless `maintain_order` is set, in which case they
+    // are ordered by their first row.
+    pub fn compute_group_proxy(&self, by: Vec<Series>, maintain_order: bool) -> GroupsProxy {
         assert_ne!(by.len(), 0);
         assert_eq!(self.rows_count(), by[0].len());
+        if by.len() == 1 && by[0].is_sorted_flag() != IsSorted::Not {
+            return compute_sorted_group_proxy(&by[0]);
+        }
+        let mut groups = self.compute_hashed_group_proxy(by);
+        if maintain_order {
+            groups.sort_by_first();
+        }
+        groups
+    }
+
+    fn compute_hashed_group_proxy(&self, by: Vec<Series>) -> GroupsProxy {
         let key_df = DataFrame::new(by);
         let n_threads = _set_partition_size();
         let hasher = RandomState::default();
@@ -41,7 +282,7 @@ impl DataFrame {
                     let mut first_indices = Vec::<u32>::new();
                     let mut grouped_indices = Vec::<Vec<u32>>::new();
                     // map from idx to the index inside first_vec.
-                    let mut hashmap = HashMap::<IdxHash, usize>::new(); // TODO: Capacity
+                    let mut hashmap = HashMap::<IdxHash, usize, IdBuildHasher>::default(); // TODO: Capacity
                     let mut offset: usize = 0;
                     for hashes in &hashes {
                         hashes.iter().enumerate().for_each(|(idx, hash)| {
@@ -81,13 +322,29 @@ impl DataFrame {
             })
             .collect();
         let (first_indices, grouped_indices) = join_group_indices(tuples);
-        GroupsProxy {
+        GroupsProxy::Idx {
             first: first_indices,
             all: grouped_indices,
         }
     }
 }
 
+// Equal values of a sorted key are next to each other, so every group is a run of rows.
+fn compute_sorted_group_proxy(key: &Series) -> GroupsProxy {
+    let key = key.rechunk();
+    let mut offset = Vec::new();
+    let mut len = Vec::new();
+    let mut start = 0;
+    for idx in 1..=key.len() {
+        if idx == key.len() || unsafe { !key.equal_element(start, &key, idx) } {
+            offset.push(start as u32);
+            len.push((idx - start) as u32);
+            start = idx;
+        }
+    }
+    GroupsProxy::Slice { offset, len }
+}
+
 fn join_group_indices(arr: Vec<(Vec<u32>, Vec<Vec<u32>>)>) -> (Vec<u32>, Vec<Vec<u32>>) {
     let output_len = arr.iter().map(|v| v.0.len()).sum::<usize>();
     let mut first_vec: Vec<u32> = Vec::with_capacity(output_len);
@@ -1,6 +1,10 @@
-use crate::{chunked_array::builder::NewFrom, dataframe::DataFrame, series::Series};
+use crate::{
+    chunked_array::{builder::NewFrom, IsSorted},
+    dataframe::DataFrame,
+    series::Series,
+};
 
-use super::join_group_indices;
+use super::{join_group_indices, GroupsProxy};
 
 #[test]
 fn join_group_indices_test() {
@@ -32,9 +36,102 @@ fn test_compute_group_proxy() {
         Series::from_vec("name", &vec!["a", "b", "a", "b", "c"]),
         Series::from_vec("points", &vec![1, 2, 1, 3, 3]),
     ]);
-    let group_proxy = df.compute_group_proxy(vec![Series::from_vec(
-        "name",
-        &vec!["a", "b", "a", "b", "c"],
-    )]);
+    let group_proxy = df.compute_group_proxy(
+        vec![Series::from_vec("name", &vec!["a", "b", "a", "b", "c"])],
+        false,
+    );
     println!("Proxy: {:?}", group_proxy);
 }
+
+#[test]
+fn test_compute_group_proxy_sorted_key() {
+    let mut key = Series::from_slice_options(
+        "name",
+        &vec![None, Some("a"), Some("a"), Some("b"), Some("c"), Some("c")],
+    );
+    key.set_sorted_flag(IsSorted::Ascending);
+    let df = DataFrame::new(vec![key.clone()]);
+
+    let group_proxy = df.compute_group_proxy(vec![key], false);
+    assert_eq!(
+        group_proxy,
+        GroupsProxy::Slice {
+            offset: vec![0, 1, 3, 4],
+            len: vec![1, 2, 1, 2],
+        }
+    );
+}
+
+#[test]
+fn test_compute_group_proxy_maintain_order() {
+    let keys = (0..100).map(|i| (i * 7) % 13).collect::<Vec<i32>>();
+    let df = DataFrame::new(vec![Series::from_vec("key", &keys)]);
+
+    let group_proxy = df.compute_group_proxy(vec![df.column("key")], true);
+    let first = group_proxy
+        .first_indices()
+        .into_iter()
+        .map(|idx| idx.unwrap())
+        .collect::<Vec<usize>>();
+    assert_eq!(first, (0..13).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_sort_groups_by_keys() {
+    let key = Series::from_slice_options("key", &vec![Some(3), None, Some(1), Some(3)]);
+    let mut group_proxy = GroupsProxy::Idx {
+        first: vec![0, 1, 2],
+        all: vec![vec![0, 3], vec![1], vec![2]],
+    };
+    group_proxy.sort_by_keys(&[key]);
+    assert_eq!(
+        group_proxy,
+        GroupsProxy::Idx {
+            first: vec![1, 2, 0],
+            all: vec![vec![1], vec![2], vec![0, 3]],
+        }
+    );
+}
+
+#[test]
+fn test_groupby_apply() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "a", "b", "a", "c"]),
+        Series::from_vec("points", &vec![1, 2, 3, 4, 5]),
+    ]);
+
+    // Keeps the last row of every group and adds the size of the group
+    let computed_df = df.groupby(["name"]).apply(|group_df| {
+        let size = group_df.rows_count() as i32;
+        let mut last = group_df.slice(group_df.rows_count() - 1, 1);
+        last.columns.push(Series::from_vec("size", &vec![size]));
+        last
+    });
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "a", "c"]),
+        Series::from_vec("points", &vec![3, 4, 5]),
+        Series::from_vec("size", &vec![2, 2, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_apply_sorted_keys() {
+    let mut key = Series::from_vec("key", &vec![1, 1, 2, 3, 3, 3]);
+    key.set_sorted_flag(IsSorted::Ascending);
+    let df = DataFrame::new(vec![
+        key,
+        Series::from_vec("value", &vec![1, 2, 3, 4, 5, 6]),
+    ]);
+
+    let groupby = df.groupby(["key"]);
+    assert!(matches!(groupby.groups(), GroupsProxy::Slice { .. }));
+    let computed_df = groupby.apply(|group_df| group_df.slice(0, 1));
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("key", &vec![1, 2, 3]),
+        Series::from_vec("value", &vec![1, 3, 4]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
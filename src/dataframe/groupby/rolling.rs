@@ -0,0 +1,171 @@
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+use crate::{core::POOL, dataframe::DataFrame, series::Series, types::DataType};
+
+use super::{duration::Duration, GroupsProxy};
+
+// Which bounds of a window are part of it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClosedWindow {
+    Left,
+    #[default]
+    Right,
+    Both,
+    None,
+}
+
+impl ClosedWindow {
+    fn includes_start(&self) -> bool {
+        matches!(self, ClosedWindow::Left | ClosedWindow::Both)
+    }
+
+    fn includes_stop(&self) -> bool {
+        matches!(self, ClosedWindow::Right | ClosedWindow::Both)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RollingGroupOptions {
+    // An Int32 column, sorted ascending within every `by` group
+    pub index_column: String,
+    pub period: Duration,
+    // Start of the window relative to the value of the row. Defaults to -period, so the
+    // window covers the period before every row.
+    pub offset: Duration,
+    pub closed_window: ClosedWindow,
+}
+
+impl RollingGroupOptions {
+    pub fn new(index_column: &str, period: &str) -> Self {
+        let period = Duration::parse(period);
+        RollingGroupOptions {
+            index_column: index_column.into(),
+            period,
+            offset: period.negate(),
+            closed_window: ClosedWindow::default(),
+        }
+    }
+
+    pub fn with_offset(mut self, offset: &str) -> Self {
+        self.offset = Duration::parse(offset);
+        self
+    }
+
+    pub fn with_closed_window(mut self, closed_window: ClosedWindow) -> Self {
+        self.closed_window = closed_window;
+        self
+    }
+}
+
+impl DataFrame {
+    // Creates a group for every row, holding the rows of the same `by` keys whose index
+    // falls in the window from value + offset to value + offset + period. Returns the index
+    // column, the `by` columns and the groups, which are in the order of the rows.
+    pub fn groupby_rolling(
+        &self,
+        by: Vec<Series>,
+        options: &RollingGroupOptions,
+    ) -> (Series, Vec<Series>, GroupsProxy) {
+        let index = self.column(&options.index_column);
+        let values = index_values(&index);
+        let offset = options.offset.in_i32_units();
+        let period = options.period.in_i32_units();
+        let closed_window = options.closed_window;
+
+        let groups = if by.is_empty() {
+            let (offset, len) = rolling_windows(&values, offset, period, closed_window)
+                .into_iter()
+                .map(|(start, stop)| (start as u32, (stop - start) as u32))
+                .unzip();
+            GroupsProxy::Slice { offset, len }
+        } else {
+            let key_groups = self.compute_group_proxy(by.clone(), true);
+            let windows = POOL.install(|| {
+                key_groups
+                    .all_indices()
+                    .to_vec()
+                    .into_par_iter()
+                    .map(|rows| {
+                        let values = rows
+                            .iter()
+                            .map(|row| values[*row as usize])
+                            .collect::<Vec<i64>>();
+                        rolling_windows(&values, offset, period, closed_window)
+                            .into_iter()
+                            .zip(&rows)
+                            .map(|((start, stop), row)| (*row, rows[start..stop].to_vec()))
+                            .collect::<Vec<(u32, Vec<u32>)>>()
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let mut first = vec![0; self.rows_count()];
+            let mut all = vec![vec![]; self.rows_count()];
+            for (row, window) in windows.into_iter().flatten() {
+                first[row as usize] = window.first().copied().unwrap_or(row);
+                all[row as usize] = window;
+            }
+            GroupsProxy::Idx { first, all }
+        };
+        (index, by, groups)
+    }
+}
+
+pub(super) fn index_values(index: &Series) -> Vec<i64> {
+    if index.dtype() != DataType::Int32 {
+        panic!("the index column must be Int32, got {:?}", index.dtype());
+    }
+    index
+        .i32()
+        .into_iter()
+        .map(|value| value.expect("the index column cannot contain nulls") as i64)
+        .collect()
+}
+
+// Returns the [start, stop) range of rows in the window of every row
+fn rolling_windows(
+    values: &[i64],
+    offset: i64,
+    period: i64,
+    closed_window: ClosedWindow,
+) -> Vec<(usize, usize)> {
+    let lower_bounds = values
+        .iter()
+        .map(|value| value + offset)
+        .collect::<Vec<i64>>();
+    find_windows(values, &lower_bounds, period, closed_window)
+}
+
+// Returns the [start, stop) range of rows in the window starting at every lower bound. Since
+// the values and the bounds are sorted, the ranges only move forward.
+pub(super) fn find_windows(
+    values: &[i64],
+    lower_bounds: &[i64],
+    period: i64,
+    closed_window: ClosedWindow,
+) -> Vec<(usize, usize)> {
+    if values.windows(2).any(|pair| pair[0] > pair[1]) {
+        panic!("the index column must be sorted ascending");
+    }
+    let mut start = 0;
+    let mut stop = 0;
+    lower_bounds
+        .iter()
+        .map(|lower| {
+            let upper = lower + period;
+            while start < values.len()
+                && (values[start] < *lower
+                    || (values[start] == *lower && !closed_window.includes_start()))
+            {
+                start += 1;
+            }
+            stop = stop.max(start);
+            while stop < values.len()
+                && (values[stop] < upper
+                    || (values[stop] == upper && closed_window.includes_stop()))
+            {
+                stop += 1;
+            }
+            (start, stop)
+        })
+        .collect()
+}
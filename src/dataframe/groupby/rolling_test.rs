@@ -0,0 +1,64 @@
+use crate::{chunked_array::builder::NewFrom, dataframe::DataFrame, series::Series};
+
+use super::{
+    rolling::{ClosedWindow, RollingGroupOptions},
+    GroupsProxy,
+};
+
+#[test]
+fn test_groupby_rolling_index() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("idx", &vec![1, 2, 3, 5, 6, 10]),
+        Series::from_vec("value", &vec![1, 2, 3, 4, 5, 6]),
+    ]);
+    let options = RollingGroupOptions::new("idx", "3i");
+
+    let (index, by, groups) = df.groupby_rolling(vec![], &options);
+    assert_eq!(index, df.column("idx"));
+    assert!(by.is_empty());
+    assert_eq!(
+        groups,
+        GroupsProxy::Slice {
+            offset: vec![0, 0, 0, 2, 3, 5],
+            len: vec![1, 2, 3, 2, 2, 1],
+        }
+    );
+    assert_eq!(
+        df.column("value").agg_sum(&groups),
+        Series::from_vec("value", &vec![1, 3, 6, 7, 9, 6])
+    );
+
+    let options = options
+        .with_offset("0i")
+        .with_closed_window(ClosedWindow::Left);
+    let (_, _, groups) = df.groupby_rolling(vec![], &options);
+    assert_eq!(
+        df.column("value").agg_sum(&groups),
+        Series::from_vec("value", &vec![6, 5, 7, 9, 5, 6])
+    );
+}
+
+#[test]
+fn test_groupby_rolling_dates_by_key() {
+    // Dates as days since the epoch
+    let df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "b", "a", "b", "a", "a"]),
+        Series::from_vec("date", &vec![19000, 19001, 19003, 19010, 19007, 19010]),
+        Series::from_vec("amount", &vec![10, 20, 30, 40, 50, 60]),
+    ]);
+    let options = RollingGroupOptions::new("date", "7d");
+
+    let (_, by, groups) = df.groupby_rolling(vec![df.column("user")], &options);
+    assert_eq!(by, vec![df.column("user")]);
+    assert_eq!(
+        df.column("amount").agg_sum(&groups),
+        Series::from_vec("amount", &vec![10, 20, 40, 40, 80, 110])
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_groupby_rolling_unsorted_index() {
+    let df = DataFrame::new(vec![Series::from_vec("idx", &vec![2, 1])]);
+    df.groupby_rolling(vec![], &RollingGroupOptions::new("idx", "1i"));
+}
@@ -15,13 +15,19 @@ use crate::{
     core::POOL,
     dataframe::utils::split_df,
     hashing::{
-        hash_dataframes,
+        hash_dataframes, IdBuildHasher,
         partition::{_set_partition_size, this_partition},
     },
     series::{self, Series},
 };
 
-use super::DataFrame;
+use super::{
+    merge_join::{
+        can_merge_join, compute_merge_join_indices_inner, compute_merge_join_indices_left,
+        compute_merge_join_indices_outer,
+    },
+    DataFrame,
+};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum JoinType {
@@ -52,13 +58,40 @@ impl DataFrame {
         let df1_selected = DataFrame::new_no_checks(df1_by);
         let df2_selected = DataFrame::new_no_checks(df2_by);
         match join_type {
-            JoinType::Left => todo!(),
+            JoinType::Left => {
+                let (df1_indices, df2_indices) =
+                    if can_merge_join(&df1_selected.columns, &df2_selected.columns) {
+                        compute_merge_join_indices_left(
+                            &df1_selected.columns[0],
+                            &df2_selected.columns[0],
+                        )
+                    } else {
+                        compute_left_join_indices(&df1_selected, &df2_selected)
+                    };
+                let df1 = self.create_df_from_slice(&df1_indices);
+                let df2 = df2
+                    .remove_columns(
+                        &df2_selected
+                            .columns
+                            .iter()
+                            .map(|series| series.name())
+                            .collect::<Vec<&str>>(),
+                    )
+                    .create_df_from_opt_slice(&df2_indices);
+                combine_dataframes(&df1, &df2)
+            }
             JoinType::Inner => {
                 let (df1_indices, df2_indices) =
-                    compute_inner_join_indices(&df1_selected, &df2_selected);
+                    if can_merge_join(&df1_selected.columns, &df2_selected.columns) {
+                        compute_merge_join_indices_inner(
+                            &df1_selected.columns[0],
+                            &df2_selected.columns[0],
+                        )
+                    } else {
+                        compute_inner_join_indices(&df1_selected, &df2_selected)
+                    };
 
                 let df1 = self.create_df_from_slice(&df1_indices);
-                println!("Df1: {:?}", &df1);
                 let df2 = df2.remove_columns(
                     &df2_selected
                         .columns
@@ -69,10 +102,66 @@ impl DataFrame {
                 let df2 = df2.create_df_from_slice(&df2_indices);
                 combine_dataframes(&df1, &df2)
             }
-            JoinType::Outer => todo!(),
+            JoinType::Outer => {
+                let (df1_indices, df2_indices) =
+                    if can_merge_join(&df1_selected.columns, &df2_selected.columns) {
+                        compute_merge_join_indices_outer(
+                            &df1_selected.columns[0],
+                            &df2_selected.columns[0],
+                        )
+                    } else {
+                        compute_outer_join_indices(&df1_selected, &df2_selected)
+                    };
+                // Rows that only exist in df2 take their keys from df2
+                let keys = df1_selected
+                    .columns
+                    .iter()
+                    .zip(&df2_selected.columns)
+                    .map(|(key1, key2)| {
+                        series::nulls::coalesce(&[
+                            key1.take_opt_indices(&df1_indices),
+                            key2.take_opt_indices(&df2_indices),
+                        ])
+                    })
+                    .collect::<Vec<Series>>();
+                let df1 = DataFrame::new_no_checks(
+                    self.columns
+                        .iter()
+                        .map(|series| {
+                            let key = keys.iter().find(|key| key.name() == series.name());
+                            match key {
+                                Some(key) => key.clone(),
+                                None => series.take_opt_indices(&df1_indices),
+                            }
+                        })
+                        .collect(),
+                );
+                let df2 = df2
+                    .remove_columns(
+                        &df2_selected
+                            .columns
+                            .iter()
+                            .map(|series| series.name())
+                            .collect::<Vec<&str>>(),
+                    )
+                    .create_df_from_opt_slice(&df2_indices);
+                combine_dataframes(&df1, &df2)
+            }
         }
     }
 
+    fn create_df_from_opt_slice(&self, indices: &[Option<usize>]) -> Self {
+        let columns = &self.columns;
+        let series = POOL
+            .install(|| {
+                columns
+                    .par_iter()
+                    .map(|series| series.take_opt_indices(indices))
+            })
+            .collect();
+        Self::new_no_checks(series)
+    }
+
     pub fn create_df_from_slice(&self, indices: &[usize]) -> Self {
         // TODO: Perform optimizations around taking indices like Polars:
         // https://github.com/pola-rs/polars/blob/f566963f526a11585805088c96e579045a0a2b79/polars/polars-core/src/frame/hash_join/mod.rs#L344
@@ -118,13 +207,13 @@ impl Hash for IdxHash {
 pub fn build_probe_table(
     hashes: &Vec<Vec<u64>>,
     dataframe: &DataFrame,
-) -> Vec<HashMap<IdxHash, Vec<usize>>> {
+) -> Vec<HashMap<IdxHash, Vec<usize>, IdBuildHasher>> {
     let num_threads = _set_partition_size() as u64;
 
     POOL.install(|| {
         (0..num_threads).into_par_iter().map(|thread_no| {
             let mut offset = 0;
-            let mut hashmap = HashMap::<IdxHash, Vec<usize>>::new(); // TODO: What capacity should I use?
+            let mut hashmap = HashMap::<IdxHash, Vec<usize>, IdBuildHasher>::default(); // TODO: What capacity should I use?
 
             for hashes in hashes {
                 hashes.iter().enumerate().for_each(|(idx, hash)| {
@@ -211,8 +300,6 @@ pub fn compute_inner_join_indices(df1: &DataFrame, df2: &DataFrame) -> (Vec<Idx>
     let hasher = RandomState::default();
     let df1_hashes = hash_dataframes(&df1_split, &hasher);
     let df2_hashes = hash_dataframes(&df2_split, &hasher);
-    println!("df1 hash: {:?}", &df1_hashes);
-    println!("df2 hash: {:?}", &df2_hashes);
     let hash_tables = build_probe_table(&df1_hashes, df1);
     let offsets = compute_offsets(&df1_hashes);
     POOL.install(|| {
@@ -244,6 +331,48 @@ pub fn compute_inner_join_indices(df1: &DataFrame, df2: &DataFrame) -> (Vec<Idx>
     .unzip()
 }
 
+// The inner join indices, followed by the df1 rows without a match. Rows are in df1 order,
+// and the matches of a row in df2 order.
+fn compute_left_join_indices(df1: &DataFrame, df2: &DataFrame) -> (Vec<Idx>, Vec<Option<Idx>>) {
+    let (inner1, inner2) = compute_inner_join_indices(df1, df2);
+    let mut matches = vec![vec![]; df1.rows_count()];
+    for (idx1, idx2) in inner1.into_iter().zip(inner2) {
+        matches[idx1].push(idx2);
+    }
+    let mut df1_indices = Vec::with_capacity(matches.len());
+    let mut df2_indices = Vec::with_capacity(matches.len());
+    for (idx1, mut matches) in matches.into_iter().enumerate() {
+        if matches.is_empty() {
+            df1_indices.push(idx1);
+            df2_indices.push(None);
+        } else {
+            matches.sort_unstable();
+            df1_indices.extend(std::iter::repeat_n(idx1, matches.len()));
+            df2_indices.extend(matches.into_iter().map(Some));
+        }
+    }
+    (df1_indices, df2_indices)
+}
+
+// The left join indices, followed by the df2 rows without a match
+fn compute_outer_join_indices(
+    df1: &DataFrame,
+    df2: &DataFrame,
+) -> (Vec<Option<Idx>>, Vec<Option<Idx>>) {
+    let (left1, left2) = compute_left_join_indices(df1, df2);
+    let mut matched = vec![false; df2.rows_count()];
+    for idx2 in left2.iter().flatten() {
+        matched[*idx2] = true;
+    }
+    let mut df1_indices = left1.into_iter().map(Some).collect::<Vec<_>>();
+    let mut df2_indices = left2;
+    for idx2 in (0..matched.len()).filter(|idx2| !matched[*idx2]) {
+        df1_indices.push(None);
+        df2_indices.push(Some(idx2));
+    }
+    (df1_indices, df2_indices)
+}
+
 fn compute_offsets<T>(lists: &Vec<Vec<T>>) -> Vec<usize> {
     let mut offset = 0;
     let mut output = Vec::with_capacity(lists.len());
@@ -0,0 +1,170 @@
+use std::cmp::Ordering;
+
+use crate::{chunked_array::IsSorted, series::Series, types::DataType};
+
+use super::join::Idx;
+
+// Sort-merge join for join keys that are already sorted in ascending order.
+// Nulls are expected at the start of the keys (which is how ChunkedSort places them)
+// and are matched with each other, just like the hash join does.
+
+// Returns true if the join can be performed by merging the keys instead of hashing them.
+pub fn can_merge_join(left_on: &[Series], right_on: &[Series]) -> bool {
+    left_on.len() == 1
+        && right_on.len() == 1
+        && left_on[0].dtype() == right_on[0].dtype()
+        && left_on[0].dtype() != DataType::Float64
+        && left_on[0].is_sorted_flag() == IsSorted::Ascending
+        && right_on[0].is_sorted_flag() == IsSorted::Ascending
+}
+
+macro_rules! dispatch_merge_join {
+    ($left: expr, $right: expr, $join_fn: ident) => {{
+        assert_eq!($left.dtype(), $right.dtype());
+        match $left.dtype() {
+            DataType::Int32 => {
+                let left = $left.i32().to_vec_options();
+                let right = $right.i32().to_vec_options();
+                $join_fn(&left, &right)
+            }
+            DataType::Utf8 => {
+                let left = $left.utf8().into_iter().collect::<Vec<_>>();
+                let right = $right.utf8().into_iter().collect::<Vec<_>>();
+                $join_fn(&left, &right)
+            }
+            DataType::Boolean => {
+                let left = $left.bool().to_vec_options();
+                let right = $right.bool().to_vec_options();
+                $join_fn(&left, &right)
+            }
+            DataType::Float64 => panic!("merge join is not supported on float keys"),
+        }
+    }};
+}
+
+pub fn compute_merge_join_indices_inner(left: &Series, right: &Series) -> (Vec<Idx>, Vec<Idx>) {
+    dispatch_merge_join!(left, right, merge_join_inner)
+}
+
+pub fn compute_merge_join_indices_left(
+    left: &Series,
+    right: &Series,
+) -> (Vec<Idx>, Vec<Option<Idx>>) {
+    dispatch_merge_join!(left, right, merge_join_left)
+}
+
+pub fn compute_merge_join_indices_outer(
+    left: &Series,
+    right: &Series,
+) -> (Vec<Option<Idx>>, Vec<Option<Idx>>) {
+    dispatch_merge_join!(left, right, merge_join_outer)
+}
+
+// Returns the end (exclusive) of the run of values equal to list[start]
+fn run_end<T: Ord>(list: &[T], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < list.len() && list[end] == list[start] {
+        end += 1;
+    }
+    end
+}
+
+pub fn merge_join_inner<T: Ord>(left: &[T], right: &[T]) -> (Vec<Idx>, Vec<Idx>) {
+    let mut left_indices = Vec::new();
+    let mut right_indices = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                let left_end = run_end(left, i);
+                let right_end = run_end(right, j);
+                for left_idx in i..left_end {
+                    for right_idx in j..right_end {
+                        left_indices.push(left_idx);
+                        right_indices.push(right_idx);
+                    }
+                }
+                i = left_end;
+                j = right_end;
+            }
+        }
+    }
+    (left_indices, right_indices)
+}
+
+pub fn merge_join_left<T: Ord>(left: &[T], right: &[T]) -> (Vec<Idx>, Vec<Option<Idx>>) {
+    let mut left_indices = Vec::with_capacity(left.len());
+    let mut right_indices = Vec::with_capacity(left.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() {
+        if j == right.len() {
+            left_indices.push(i);
+            right_indices.push(None);
+            i += 1;
+            continue;
+        }
+        match left[i].cmp(&right[j]) {
+            Ordering::Less => {
+                left_indices.push(i);
+                right_indices.push(None);
+                i += 1;
+            }
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                let left_end = run_end(left, i);
+                let right_end = run_end(right, j);
+                for left_idx in i..left_end {
+                    for right_idx in j..right_end {
+                        left_indices.push(left_idx);
+                        right_indices.push(Some(right_idx));
+                    }
+                }
+                i = left_end;
+                j = right_end;
+            }
+        }
+    }
+    (left_indices, right_indices)
+}
+
+pub fn merge_join_outer<T: Ord>(left: &[T], right: &[T]) -> (Vec<Option<Idx>>, Vec<Option<Idx>>) {
+    let mut left_indices = Vec::with_capacity(std::cmp::max(left.len(), right.len()));
+    let mut right_indices = Vec::with_capacity(std::cmp::max(left.len(), right.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        let ordering = if i == left.len() {
+            Ordering::Greater
+        } else if j == right.len() {
+            Ordering::Less
+        } else {
+            left[i].cmp(&right[j])
+        };
+        match ordering {
+            Ordering::Less => {
+                left_indices.push(Some(i));
+                right_indices.push(None);
+                i += 1;
+            }
+            Ordering::Greater => {
+                left_indices.push(None);
+                right_indices.push(Some(j));
+                j += 1;
+            }
+            Ordering::Equal => {
+                let left_end = run_end(left, i);
+                let right_end = run_end(right, j);
+                for left_idx in i..left_end {
+                    for right_idx in j..right_end {
+                        left_indices.push(Some(left_idx));
+                        right_indices.push(Some(right_idx));
+                    }
+                }
+                i = left_end;
+                j = right_end;
+            }
+        }
+    }
+    (left_indices, right_indices)
+}
@@ -0,0 +1,162 @@
+use crate::{
+    chunked_array::{builder::NewFrom, sort::ChunkedSort, types::I32Chunked, IsSorted},
+    dataframe::{
+        join::{compute_inner_join_indices, JoinType},
+        merge_join::{
+            can_merge_join, compute_merge_join_indices_inner, merge_join_left, merge_join_outer,
+        },
+        DataFrame,
+    },
+    series::{constructor::IntoSeries, Series},
+};
+
+fn sorted_pairs(left: Vec<usize>, right: Vec<usize>) -> Vec<(usize, usize)> {
+    let mut pairs = left.into_iter().zip(right).collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn test_merge_join_inner_matches_hash_join() {
+    let left = I32Chunked::from_slice_options(
+        "a",
+        &vec![None, Some(1), Some(1), Some(2), Some(4), Some(5)],
+    )
+    .sort(false)
+    .into_series();
+    let right = I32Chunked::from_slice_options(
+        "b",
+        &vec![None, Some(1), Some(3), Some(4), Some(4), Some(6)],
+    )
+    .sort(false)
+    .into_series();
+    assert!(can_merge_join(&[left.clone()], &[right.clone()]));
+
+    let (merge_left, merge_right) = compute_merge_join_indices_inner(&left, &right);
+    let (hash_left, hash_right) = compute_inner_join_indices(
+        &DataFrame::new(vec![left]),
+        &DataFrame::new(vec![right]),
+    );
+    assert_eq!(
+        sorted_pairs(merge_left, merge_right),
+        sorted_pairs(hash_left, hash_right)
+    );
+}
+
+#[test]
+fn test_merge_join_left() {
+    let left = vec![1, 2, 2, 5];
+    let right = vec![2, 3, 5, 5];
+    let (left_indices, right_indices) = merge_join_left(&left, &right);
+    assert_eq!(left_indices, vec![0, 1, 2, 3, 3]);
+    assert_eq!(right_indices, vec![None, Some(0), Some(0), Some(2), Some(3)]);
+}
+
+#[test]
+fn test_merge_join_outer() {
+    let left = vec!["a", "c", "d"];
+    let right = vec!["b", "c", "e"];
+    let (left_indices, right_indices) = merge_join_outer(&left, &right);
+    assert_eq!(left_indices, vec![Some(0), None, Some(1), Some(2), None]);
+    assert_eq!(right_indices, vec![None, Some(0), Some(1), None, Some(2)]);
+}
+
+#[test]
+fn test_join_uses_sorted_flag() {
+    let mut name1 = Series::from_vec("name", &vec!["bar", "baz", "foo"]);
+    name1.set_sorted_flag(IsSorted::Ascending);
+    let mut name2 = Series::from_vec("name", &vec!["baz", "foo"]);
+    name2.set_sorted_flag(IsSorted::Ascending);
+    assert!(can_merge_join(&[name1.clone()], &[name2.clone()]));
+
+    let df1 = DataFrame::new(vec![name1, Series::from_vec("points", &vec![10, 20, 0])]);
+    let df2 = DataFrame::new(vec![name2, Series::from_vec("blocks", &vec![2, 0])]);
+
+    let joined = df1.inner_join(vec!["name"], &df2, vec!["name"]);
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["baz", "foo"]),
+        Series::from_vec("points", &vec![20, 0]),
+        Series::from_vec("blocks", &vec![2, 0]),
+    ]);
+    assert_eq!(&joined, &expected_df);
+}
+
+#[cfg(test)]
+fn sorted_join_inputs() -> (DataFrame, DataFrame) {
+    let mut name1 = Series::from_vec("name", &vec!["bar", "baz", "foo"]);
+    name1.set_sorted_flag(IsSorted::Ascending);
+    let mut name2 = Series::from_vec("name", &vec!["baz", "foo", "qux"]);
+    name2.set_sorted_flag(IsSorted::Ascending);
+    (
+        DataFrame::new(vec![name1, Series::from_vec("points", &vec![10, 20, 0])]),
+        DataFrame::new(vec![name2, Series::from_vec("blocks", &vec![2, 0, 5])]),
+    )
+}
+
+#[test]
+fn test_left_join_with_sorted_keys() {
+    let (df1, df2) = sorted_join_inputs();
+    let joined = df1.join(
+        df1.select_series(vec!["name"]),
+        &df2,
+        df2.select_series(vec!["name"]),
+        JoinType::Left,
+    );
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["bar", "baz", "foo"]),
+        Series::from_vec("points", &vec![10, 20, 0]),
+        Series::from_slice_options("blocks", &vec![None, Some(2), Some(0)]),
+    ]);
+    assert_eq!(&joined, &expected_df);
+}
+
+#[test]
+fn test_outer_join_with_sorted_keys() {
+    let (df1, df2) = sorted_join_inputs();
+    let joined = df1.join(
+        df1.select_series(vec!["name"]),
+        &df2,
+        df2.select_series(vec!["name"]),
+        JoinType::Outer,
+    );
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["bar", "baz", "foo", "qux"]),
+        Series::from_slice_options("points", &vec![Some(10), Some(20), Some(0), None]),
+        Series::from_slice_options("blocks", &vec![None, Some(2), Some(0), Some(5)]),
+    ]);
+    assert_eq!(&joined, &expected_df);
+}
+
+#[test]
+fn test_left_and_outer_join_with_unsorted_keys() {
+    let df1 = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["foo", "bar", "baz"]),
+        Series::from_vec("points", &vec![0, 10, 20]),
+    ]);
+    let df2 = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["qux", "foo", "baz", "foo"]),
+        Series::from_vec("blocks", &vec![5, 0, 2, 7]),
+    ]);
+    let join = |join_type| {
+        df1.join(
+            df1.select_series(vec!["name"]),
+            &df2,
+            df2.select_series(vec!["name"]),
+            join_type,
+        )
+    };
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["foo", "foo", "bar", "baz"]),
+        Series::from_vec("points", &vec![0, 0, 10, 20]),
+        Series::from_slice_options("blocks", &vec![Some(0), Some(7), None, Some(2)]),
+    ]);
+    assert_eq!(&join(JoinType::Left), &expected_df);
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["foo", "foo", "bar", "baz", "qux"]),
+        Series::from_slice_options("points", &vec![Some(0), Some(0), Some(10), Some(20), None]),
+        Series::from_slice_options("blocks", &vec![Some(0), Some(7), None, Some(2), Some(5)]),
+    ]);
+    assert_eq!(&join(JoinType::Outer), &expected_df);
+}
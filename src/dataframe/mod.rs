@@ -7,10 +7,25 @@ use crate::{
     series::{constructor::IntoSeries, Series},
 };
 
+pub mod concat;
+mod concat_test;
 pub mod filter;
+mod filter_test;
 pub mod groupby;
 pub mod join;
 mod join_test;
+pub mod merge_join;
+mod merge_join_test;
+pub mod reshape;
+mod reshape_test;
+pub mod row;
+mod row_test;
+pub mod sort;
+mod sort_test;
+pub mod top_k;
+mod top_k_test;
+pub mod unique;
+mod unique_test;
 pub mod utils;
 mod utils_test;
 
@@ -135,6 +150,31 @@ impl DataFrame {
         DataFrame::new_no_checks(columns)
     }
 
+    // Like `slice`, but a negative offset counts from the end and the rows past the end are
+    // left out
+    pub fn slice_clamped(&self, offset: i64, length: usize) -> DataFrame {
+        let height = self.rows_count();
+        let offset = if offset < 0 {
+            height.saturating_sub(offset.unsigned_abs() as usize)
+        } else {
+            (offset as usize).min(height)
+        };
+        let length = length.min(height - offset);
+        if length == 0 {
+            // Slicing at the end of the columns isn't allowed
+            return DataFrame::new_no_checks(self.apply_columns_par(&|s| s.take_indices(&[])));
+        }
+        self.slice(offset, length)
+    }
+
+    pub fn head(&self, n: usize) -> DataFrame {
+        self.slice_clamped(0, n)
+    }
+
+    pub fn tail(&self, n: usize) -> DataFrame {
+        self.slice_clamped(-(n as i64), n)
+    }
+
     // Returns (row_count, col_count)
     pub fn dimensions(&self) -> (usize, usize) {
         if self.columns.len() == 0 {
@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+
+use ahash::{HashSet, HashSetExt};
+
+use crate::{
+    chunked_array::types::{AnyValue, Utf8Chunked},
+    series::{cast::get_supertype, constructor::IntoSeries, Series},
+};
+
+use super::{groupby::GroupsProxy, utils::accumulate_series_vertical, DataFrame};
+
+// How `pivot` aggregates the values that fall in the same cell
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PivotAgg {
+    First,
+    Last,
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Median,
+    // Number of non-null values
+    Count,
+}
+
+impl DataFrame {
+    // Unpivots the `value_vars` columns, or all the columns that aren't in `id_vars`, into a
+    // "variable" column with their names and a "value" column with their values. The
+    // `id_vars` columns are repeated for every value column.
+    pub fn melt(&self, id_vars: &[&str], value_vars: &[&str]) -> DataFrame {
+        let value_vars = if value_vars.is_empty() {
+            self.columns
+                .iter()
+                .map(|series| series.name())
+                .filter(|name| !id_vars.contains(name))
+                .collect()
+        } else {
+            value_vars.to_vec()
+        };
+        if value_vars.is_empty() {
+            panic!("melt needs at least one value column");
+        }
+
+        let values = self.select_series(&value_vars);
+        let dtype = values[1..].iter().fold(values[0].dtype(), |dtype, series| {
+            let right = series.dtype();
+            get_supertype(&dtype, &right).unwrap_or_else(|| {
+                panic!("cannot melt columns of {dtype:?} and {right:?} into one column")
+            })
+        });
+        let value = values
+            .into_iter()
+            .enumerate()
+            .map(|(idx, series)| {
+                let mut series = series.cast(&dtype);
+                // The stacked series takes the name of the first one
+                if idx == 0 {
+                    series.rename("value");
+                }
+                series
+            })
+            .collect();
+
+        let height = self.rows_count();
+        let mut columns = self
+            .select_series(id_vars)
+            .into_iter()
+            .map(|series| accumulate_series_vertical(vec![series; value_vars.len()]))
+            .collect::<Vec<Series>>();
+        let variable = value_vars
+            .iter()
+            .flat_map(|name| std::iter::repeat_n(Some(*name), height));
+        columns.push(Utf8Chunked::from_iter_options("variable", variable).into_series());
+        columns.push(accumulate_series_vertical(value));
+        DataFrame::new_no_checks(columns)
+    }
+
+    // Spreads the `values` column into one column for every distinct value of `columns`, with
+    // a row for every distinct combination of the `index` columns. The values of a cell are
+    // aggregated with `aggregate_fn`, and cells without values are null. The rows are in the
+    // order of their first appearance, and so are the new columns unless `sort_columns`. Without
+    // `index` columns, all the rows are aggregated into a single row.
+    pub fn pivot(
+        &self,
+        index: &[&str],
+        columns: &str,
+        values: &str,
+        aggregate_fn: PivotAgg,
+        sort_columns: bool,
+    ) -> DataFrame {
+        let index = self.select_series(index);
+        if self.rows_count() == 0 {
+            return DataFrame::new_no_checks(index);
+        }
+        let columns = self.column(columns);
+        let values = self.column(values);
+
+        let row_groups = if index.is_empty() {
+            GroupsProxy::Slice {
+                offset: vec![0],
+                len: vec![self.rows_count() as u32],
+            }
+        } else {
+            self.compute_group_proxy(index.clone(), true)
+        };
+        let column_groups = self.compute_group_proxy(vec![columns.clone()], true);
+        let mut cell_keys = index.clone();
+        cell_keys.push(columns.clone());
+        let cell_groups = self.compute_group_proxy(cell_keys, true);
+        let aggregated = aggregate(&values, &cell_groups, aggregate_fn);
+
+        // The position of the aggregated value of every (column, row) pair
+        let row_ids = group_ids(&row_groups, self.rows_count());
+        let column_ids = group_ids(&column_groups, self.rows_count());
+        let mut cells = vec![vec![None; row_groups.len()]; column_groups.len()];
+        for (cell, first) in cell_groups.first_indices().into_iter().enumerate() {
+            let first = first.unwrap();
+            cells[column_ids[first]][row_ids[first]] = Some(cell);
+        }
+
+        let column_firsts = column_groups
+            .first_indices()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<usize>>();
+        let mut column_order = (0..column_groups.len()).collect::<Vec<usize>>();
+        if sort_columns {
+            column_order.sort_by(|a, b| {
+                let a = columns.get(column_firsts[*a]);
+                let b = columns.get(column_firsts[*b]);
+                a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+            });
+        }
+
+        let row_firsts = row_groups
+            .first_indices()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<usize>>();
+        let pivot_names = column_order
+            .iter()
+            .map(|column| column_name(columns.get(column_firsts[*column])))
+            .collect::<Vec<String>>();
+        let mut names = HashSet::new();
+        for name in index
+            .iter()
+            .map(|series| series.name())
+            .chain(pivot_names.iter().map(|name| name.as_str()))
+        {
+            if !names.insert(name) {
+                panic!("the pivot outputs more than one column named {name:?}");
+            }
+        }
+
+        let index_columns = index.iter().map(|series| series.take_indices(&row_firsts));
+        let pivot_columns = column_order.iter().zip(&pivot_names).map(|(column, name)| {
+            let mut series = aggregated.take_opt_indices(&cells[*column]);
+            series.rename(name);
+            series
+        });
+        DataFrame::new_no_checks(index_columns.chain(pivot_columns).collect())
+    }
+}
+
+// The position of the group of every row
+fn group_ids(groups: &GroupsProxy, len: usize) -> Vec<usize> {
+    let mut ids = vec![0; len];
+    for (group, idx) in groups.all_indices().iter().enumerate() {
+        idx.iter().for_each(|idx| ids[*idx as usize] = group);
+    }
+    ids
+}
+
+fn aggregate(values: &Series, groups: &GroupsProxy, aggregate_fn: PivotAgg) -> Series {
+    match aggregate_fn {
+        PivotAgg::First => values.agg_first(groups),
+        PivotAgg::Last => values.agg_last(groups),
+        PivotAgg::Sum => values.agg_sum(groups),
+        PivotAgg::Mean => values.agg_mean(groups),
+        PivotAgg::Min => values.agg_min(groups),
+        PivotAgg::Max => values.agg_max(groups),
+        PivotAgg::Median => values.agg_median(groups),
+        PivotAgg::Count => values.agg_count(groups),
+    }
+}
+
+fn column_name(value: Option<AnyValue>) -> String {
+    match value {
+        Some(AnyValue::Utf8(value)) => value.to_string(),
+        Some(value) => value.to_string(),
+        None => "null".to_string(),
+    }
+}
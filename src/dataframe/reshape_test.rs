@@ -0,0 +1,107 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::{reshape::PivotAgg, DataFrame},
+    series::Series,
+};
+
+#[test]
+fn test_melt() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("id", &vec!["a", "b"]),
+        Series::from_vec("x", &vec![1, 2]),
+        Series::from_vec("y", &vec![0.5, 1.5]),
+    ]);
+
+    let melted = df.melt(&["id"], &[]);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("id", &vec!["a", "b", "a", "b"]),
+        Series::from_vec("variable", &vec!["x", "x", "y", "y"]),
+        Series::from_vec("value", &vec![1.0, 2.0, 0.5, 1.5]),
+    ]);
+    assert_eq!(melted, expected);
+
+    let melted = df.melt(&[], &["x"]);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("variable", &vec!["x", "x"]),
+        Series::from_vec("value", &vec![1, 2]),
+    ]);
+    assert_eq!(melted, expected);
+}
+
+#[cfg(test)]
+fn sales_df() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_vec("store", &vec!["s2", "s1", "s2", "s1", "s2"]),
+        Series::from_vec("month", &vec!["feb", "jan", "jan", "jan", "feb"]),
+        Series::from_vec("amount", &vec![5, 1, 2, 3, 4]),
+    ])
+}
+
+#[test]
+fn test_pivot_sum() {
+    let pivoted = sales_df().pivot(&["store"], "month", "amount", PivotAgg::Sum, false);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("store", &vec!["s2", "s1"]),
+        Series::from_slice_options("feb", &vec![Some(9), None]),
+        Series::from_vec("jan", &vec![2, 4]),
+    ]);
+    assert_eq!(pivoted, expected);
+}
+
+#[test]
+fn test_pivot_sorted_columns() {
+    let pivoted = sales_df().pivot(&["store"], "amount", "month", PivotAgg::First, true);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("store", &vec!["s2", "s1"]),
+        Series::from_slice_options("1", &vec![None, Some("jan")]),
+        Series::from_slice_options("2", &vec![Some("jan"), None]),
+        Series::from_slice_options("3", &vec![None, Some("jan")]),
+        Series::from_slice_options("4", &vec![Some("feb"), None]),
+        Series::from_slice_options("5", &vec![Some("feb"), None]),
+    ]);
+    assert_eq!(pivoted, expected);
+}
+
+#[test]
+fn test_pivot_melt_round_trip() {
+    let wide = sales_df().pivot(&["store"], "month", "amount", PivotAgg::Count, true);
+    let long = wide.melt(&["store"], &[]);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("store", &vec!["s2", "s1", "s2", "s1"]),
+        Series::from_vec("variable", &vec!["feb", "feb", "jan", "jan"]),
+        Series::from_slice_options("value", &vec![Some(2), None, Some(1), Some(2)]),
+    ]);
+    assert_eq!(long, expected);
+}
+
+#[test]
+fn test_pivot_without_index() {
+    let pivoted = sales_df().pivot(&[], "month", "amount", PivotAgg::Max, false);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("feb", &vec![5]),
+        Series::from_vec("jan", &vec![3]),
+    ]);
+    assert_eq!(pivoted, expected);
+}
+
+#[test]
+#[should_panic(expected = "the pivot outputs more than one column named \"s1\"")]
+fn test_pivot_column_named_like_index() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("s1", &vec!["a", "b"]),
+        Series::from_vec("store", &vec!["s1", "s2"]),
+        Series::from_vec("amount", &vec![1, 2]),
+    ]);
+    df.pivot(&["s1"], "store", "amount", PivotAgg::Sum, false);
+}
+
+#[test]
+#[should_panic(expected = "the pivot outputs more than one column named \"null\"")]
+fn test_pivot_null_and_null_string_columns() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("id", &vec![1, 1]),
+        Series::from_slice_options("key", &vec![Some("null"), None]),
+        Series::from_vec("amount", &vec![1, 2]),
+    ]);
+    df.pivot(&["id"], "key", "amount", PivotAgg::Sum, false);
+}
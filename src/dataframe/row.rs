@@ -0,0 +1,112 @@
+use crate::{
+    chunked_array::types::{AnyValue, Utf8Chunked},
+    core::schema::Schema,
+    series::{cast::get_supertype, constructor::IntoSeries, Series},
+};
+
+use super::DataFrame;
+
+// The values of a row in the order of the columns, where None is a null
+pub type Row<'a> = Vec<Option<AnyValue<'a>>>;
+
+// Yields the rows of a DataFrame by walking the chunks of every column side by side
+pub struct RowIter<'a> {
+    columns: Vec<Box<dyn Iterator<Item = Option<AnyValue<'a>>> + 'a>>,
+    remaining: usize,
+}
+
+impl<'a> Iterator for RowIter<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(
+            self.columns
+                .iter_mut()
+                .map(|column| column.next().unwrap())
+                .collect(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for RowIter<'_> {}
+
+impl DataFrame {
+    pub fn get_row(&self, idx: usize) -> Row<'_> {
+        let rows = self.rows_count();
+        if idx >= rows {
+            panic!("row {idx} is out of bounds for a DataFrame of {rows} rows");
+        }
+        self.columns.iter().map(|column| column.get(idx)).collect()
+    }
+
+    // Cheaper than calling get_row for every row, which looks up the chunk of every value
+    pub fn iter_rows(&self) -> RowIter<'_> {
+        RowIter {
+            columns: self
+                .columns
+                .iter()
+                .map(|column| column.iter_any_values())
+                .collect(),
+            remaining: self.columns.first().map_or(0, |column| column.len()),
+        }
+    }
+
+    // Builds the columns of `schema` from rows of values in the same order. Int32 values
+    // are accepted in Float64 columns.
+    pub fn from_rows(schema: &Schema, rows: &[Row]) -> DataFrame {
+        let width = schema.iter().count();
+        let columns = schema
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, dtype))| {
+                let values = rows.iter().map(|row| {
+                    if row.len() != width {
+                        panic!("expected rows of {width} values, got {}", row.len());
+                    }
+                    row[idx].clone()
+                });
+                Series::from_any_values(name, dtype, values)
+            })
+            .collect();
+        DataFrame::new_no_checks(columns)
+    }
+
+    // Turns the rows into columns named "column_0", "column_1", ... The values are cast to a
+    // common type. With a `header_name`, the names of the columns become the first column.
+    pub fn transpose(&self, header_name: Option<&str>) -> DataFrame {
+        let dtype = self
+            .columns
+            .iter()
+            .map(|column| column.dtype())
+            .reduce(|left, right| {
+                get_supertype(&left, &right).unwrap_or_else(|| {
+                    panic!("cannot transpose columns of {left:?} and {right:?} into rows")
+                })
+            });
+        let Some(dtype) = dtype else {
+            return DataFrame::new_no_checks(vec![]);
+        };
+        let casted = DataFrame::new_no_checks(
+            self.columns
+                .iter()
+                .map(|column| column.cast(&dtype))
+                .collect(),
+        );
+        let header = header_name.map(|header_name| {
+            let names = self.columns.iter().map(|column| Some(column.name()));
+            Utf8Chunked::from_iter_options(header_name, names).into_series()
+        });
+        let columns = casted.iter_rows().enumerate().map(|(idx, row)| {
+            Series::from_any_values(&format!("column_{idx}"), &dtype, row.into_iter())
+        });
+        DataFrame::new_no_checks(header.into_iter().chain(columns).collect())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    chunked_array::{builder::NewFrom, types::AnyValue},
+    core::schema::Schema,
+    dataframe::DataFrame,
+    series::Series,
+    types::DataType,
+};
+
+#[cfg(test)]
+fn people_df() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_slice_options("name", &vec![Some("ann"), Some("bob"), None]),
+        Series::from_slice_options("age", &vec![Some(31), None, Some(25)]),
+        Series::from_vec("height", &vec![1.6, 1.8, 1.7]),
+    ])
+}
+
+#[test]
+fn test_get_row() {
+    let df = people_df();
+    assert_eq!(
+        df.get_row(1),
+        vec![
+            Some(AnyValue::Utf8("bob")),
+            None,
+            Some(AnyValue::Float64(1.8))
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_get_row_out_of_bounds() {
+    people_df().get_row(3);
+}
+
+#[test]
+fn test_iter_rows() {
+    // Rows crossing the boundary between chunks
+    let df = people_df();
+    let stacked = df.slice(0, 2).vstack(&df.slice(2, 1));
+    let rows = stacked.iter_rows();
+    assert_eq!(rows.len(), 3);
+    let rows = rows.collect::<Vec<_>>();
+    let expected = (0..3).map(|idx| df.get_row(idx)).collect::<Vec<_>>();
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_from_rows() {
+    let df = people_df();
+    let rows = df.iter_rows().collect::<Vec<_>>();
+    assert_eq!(DataFrame::from_rows(&df.schema(), &rows), df);
+
+    // Int32 values go into Float64 columns
+    let mut schema = Schema::new();
+    schema.with_column("x".to_string(), DataType::Float64);
+    let rows = vec![vec![Some(AnyValue::Int32(1))], vec![None]];
+    let expected = DataFrame::new(vec![Series::from_slice_options(
+        "x",
+        &vec![Some(1.0), None],
+    )]);
+    assert_eq!(DataFrame::from_rows(&schema, &rows), expected);
+}
+
+#[test]
+#[should_panic(expected = "cannot put")]
+fn test_from_rows_mismatch() {
+    let mut schema = Schema::new();
+    schema.with_column("x".to_string(), DataType::Int32);
+    DataFrame::from_rows(&schema, &[vec![Some(AnyValue::Utf8("a"))]]);
+}
+
+#[test]
+fn test_transpose() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![Some(1), None]),
+        Series::from_vec("b", &vec![0.5, 1.5]),
+    ]);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("column", &vec!["a", "b"]),
+        Series::from_slice_options("column_0", &vec![Some(1.0), Some(0.5)]),
+        Series::from_slice_options("column_1", &vec![None, Some(1.5)]),
+    ]);
+    assert_eq!(df.transpose(Some("column")), expected);
+}
+
+#[test]
+#[should_panic(expected = "cannot transpose")]
+fn test_transpose_mixed_types() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("a", &vec!["x"]),
+        Series::from_vec("b", &vec![2]),
+    ]);
+    df.transpose(None);
+}
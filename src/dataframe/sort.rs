@@ -0,0 +1,100 @@
+use rayon::prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    chunked_array::sort::{sort_list, sorted_flag, SortOptions},
+    core::POOL,
+    series::Series,
+};
+
+use super::DataFrame;
+
+impl DataFrame {
+    // Sorts the rows by the `by` columns, the first one taking precedence. `descending` has
+    // one flag for every column, or a single flag for all of them. Equal rows keep their
+    // order.
+    pub fn sort(&self, by: &[&str], descending: &[bool], nulls_last: bool) -> DataFrame {
+        let by = self.select_series(by);
+        let first_descending = descending.first().copied().unwrap_or(false);
+        let mut df = self.sort_impl(by.clone(), descending, nulls_last);
+
+        // Sorted arrays are expected to have their nulls first
+        let first = &by[0];
+        if !nulls_last || first.null_count() == 0 {
+            let idx = df.get_index_with_name(first.name());
+            df.columns[idx].set_sorted_flag(sorted_flag(first_descending));
+        }
+        df
+    }
+
+    pub fn sort_impl(&self, by: Vec<Series>, descending: &[bool], nulls_last: bool) -> DataFrame {
+        let order = arg_sort_multiple(&by, descending, nulls_last);
+        let columns = self.apply_columns_par(&|series| series.take_indices(&order));
+        DataFrame::new_no_checks(columns)
+    }
+}
+
+// Returns the positions of the rows in the order of the keys
+pub fn arg_sort_multiple(by: &[Series], descending: &[bool], nulls_last: bool) -> Vec<usize> {
+    let options = sort_options(by, descending, nulls_last);
+    if by.len() == 1 {
+        return by[0].arg_sort(options[0]);
+    }
+
+    // Replaces the values of every key by their position in its order, so that comparing
+    // rows doesn't have to look at the values
+    let codes = POOL.install(|| {
+        by.par_iter()
+            .zip(options.par_iter())
+            .map(|(key, options)| sort_codes(key, *options))
+            .collect::<Vec<Vec<u32>>>()
+    });
+    let compare = |a: &usize, b: &usize| {
+        codes
+            .iter()
+            .map(|codes| codes[*a].cmp(&codes[*b]))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.cmp(b))
+    };
+    let mut order = (0..by[0].len()).collect::<Vec<usize>>();
+    sort_list(&mut order, false, compare, compare);
+    order
+}
+
+// The sort options of every key
+pub(super) fn sort_options(
+    by: &[Series],
+    descending: &[bool],
+    nulls_last: bool,
+) -> Vec<SortOptions> {
+    if by.is_empty() {
+        panic!("sort needs at least one column");
+    }
+    if descending.len() != 1 && descending.len() != by.len() {
+        panic!(
+            "expected 1 or {} descending flags, got {}",
+            by.len(),
+            descending.len()
+        );
+    }
+    by.iter()
+        .enumerate()
+        .map(|(idx, _)| SortOptions {
+            descending: descending[idx.min(descending.len() - 1)],
+            nulls_last,
+        })
+        .collect()
+}
+
+// The position of every value in the sorted order, where equal values share a position
+fn sort_codes(key: &Series, options: SortOptions) -> Vec<u32> {
+    let order = key.arg_sort(options);
+    let mut codes = vec![0; order.len()];
+    let mut code = 0;
+    for (position, idx) in order.iter().enumerate() {
+        if position > 0 && key.get(order[position - 1]) != key.get(*idx) {
+            code += 1;
+        }
+        codes[*idx] = code;
+    }
+    codes
+}
@@ -0,0 +1,66 @@
+use crate::{
+    chunked_array::{builder::NewFrom, IsSorted},
+    dataframe::DataFrame,
+    series::Series,
+};
+
+#[test]
+fn test_sort_single_column() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![Some(3), None, Some(1), Some(2)]),
+        Series::from_vec("b", &vec!["x", "y", "z", "w"]),
+    ]);
+
+    let sorted = df.sort(&["a"], &[false], false);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![None, Some(1), Some(2), Some(3)]),
+        Series::from_vec("b", &vec!["y", "z", "w", "x"]),
+    ]);
+    assert_eq!(sorted, expected);
+    assert_eq!(sorted.column("a").is_sorted_flag(), IsSorted::Ascending);
+
+    let sorted = df.sort(&["a"], &[true], true);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![Some(3), Some(2), Some(1), None]),
+        Series::from_vec("b", &vec!["x", "w", "z", "y"]),
+    ]);
+    assert_eq!(sorted, expected);
+    assert_eq!(sorted.column("a").is_sorted_flag(), IsSorted::Not);
+}
+
+#[test]
+fn test_sort_multiple_columns() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("group", &vec!["b", "a", "b", "a", "a"]),
+        Series::from_slice_options(
+            "value",
+            &vec![Some(1.5), Some(2.0), None, Some(0.5), Some(2.0)],
+        ),
+        Series::from_vec("row", &vec![0, 1, 2, 3, 4]),
+    ]);
+
+    let sorted = df.sort(&["group", "value"], &[false, true], true);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("group", &vec!["a", "a", "a", "b", "b"]),
+        Series::from_slice_options(
+            "value",
+            &vec![Some(2.0), Some(2.0), Some(0.5), Some(1.5), None],
+        ),
+        Series::from_vec("row", &vec![1, 4, 3, 0, 2]),
+    ]);
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn test_sort_booleans_keeps_order_of_ties() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("flag", &vec![true, false, true, false]),
+        Series::from_vec("row", &vec![0, 1, 2, 3]),
+    ]);
+
+    let sorted = df.sort(&["flag"], &[true], false);
+    assert_eq!(
+        sorted.column("row"),
+        Series::from_vec("row", &vec![0, 2, 1, 3])
+    );
+}
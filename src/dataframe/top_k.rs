@@ -0,0 +1,98 @@
+use std::cmp::Ordering;
+
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    chunked_array::{sort::SortOptions, top_k::select_first_k, types::AnyValue},
+    core::POOL,
+    series::Series,
+};
+
+use super::{sort::sort_options, DataFrame};
+
+impl DataFrame {
+    // The `k` rows with the largest values of the `by` columns, the first one taking
+    // precedence. Rows with nulls come last.
+    pub fn top_k(&self, k: usize, by: &[&str]) -> DataFrame {
+        self.top_k_impl(k, self.select_series(by), &[true], true)
+    }
+
+    // The first `k` rows of `sort_impl` with the same arguments, found without sorting all
+    // the rows
+    pub fn top_k_impl(
+        &self,
+        k: usize,
+        by: Vec<Series>,
+        descending: &[bool],
+        nulls_last: bool,
+    ) -> DataFrame {
+        let order = arg_top_k_multiple(&by, k, descending, nulls_last);
+        let columns = self.apply_columns_par(&|series| series.take_indices(&order));
+        DataFrame::new_no_checks(columns)
+    }
+}
+
+pub fn arg_top_k_multiple(
+    by: &[Series],
+    k: usize,
+    descending: &[bool],
+    nulls_last: bool,
+) -> Vec<usize> {
+    let options = sort_options(by, descending, nulls_last);
+    if by.len() == 1 {
+        return by[0].arg_top_k(k, options[0]);
+    }
+
+    let compare = |a: &usize, b: &usize| {
+        by.iter()
+            .zip(&options)
+            .map(|(key, options)| compare_values(key.get(*a), key.get(*b), *options))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.cmp(b))
+    };
+    // Selects the first rows of every chunk in parallel, then the first of those
+    let mut offset = 0;
+    let chunks = by[0]
+        .chunks()
+        .iter()
+        .map(|chunk| {
+            let rows = (offset..offset + chunk.len()).collect::<Vec<usize>>();
+            offset += chunk.len();
+            rows
+        })
+        .collect::<Vec<_>>();
+    let candidates = POOL.install(|| {
+        chunks
+            .into_par_iter()
+            .flat_map(|rows| select_first_k(rows, k, &compare))
+            .collect::<Vec<usize>>()
+    });
+    let mut order = select_first_k(candidates, k, &compare);
+    order.sort_unstable_by(compare);
+    order
+}
+
+fn compare_values(a: Option<AnyValue>, b: Option<AnyValue>, options: SortOptions) -> Ordering {
+    let null_ordering = if options.nulls_last {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    };
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => null_ordering,
+        (Some(_), None) => null_ordering.reverse(),
+        (Some(a), Some(b)) => {
+            // NaN sorts above every other float, like in `arg_sort`
+            let ordering = match (a, b) {
+                (AnyValue::Float64(a), AnyValue::Float64(b)) => a.total_cmp(&b),
+                (a, b) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            };
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{chunked_array::builder::NewFrom, dataframe::DataFrame, series::Series};
+
+#[test]
+fn test_top_k_single_column() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![Some(3), None, Some(1), Some(5)]),
+        Series::from_vec("b", &vec!["x", "y", "z", "w"]),
+    ]);
+
+    let top = df.top_k(2, &["a"]);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("a", &vec![5, 3]),
+        Series::from_vec("b", &vec!["w", "x"]),
+    ]);
+    assert_eq!(top, expected);
+}
+
+#[test]
+fn test_top_k_matches_sort() {
+    let df = DataFrame::new(vec![
+        Series::from_lists("group", vec![&["b", "a", "b"], &["a", "a", "c"]]),
+        Series::from_lists("value", vec![&[1.5, 2.0, 0.5], &[0.5, 2.0, 1.0]]),
+        Series::from_lists("row", vec![&[0, 1, 2], &[3, 4, 5]]),
+    ]);
+
+    for k in 0..=df.rows_count() {
+        for descending in [vec![false, true], vec![true, false]] {
+            let by = df.select_series(["group", "value"]);
+            let top = df.top_k_impl(k, by.clone(), &descending, true);
+            let sorted = df.sort_impl(by, &descending, true);
+            assert_eq!(top, sorted.slice(0, k));
+        }
+    }
+}
+
+#[test]
+fn test_top_k_with_nan_matches_sort() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("group", &vec!["a", "a", "b", "a", "b"]),
+        Series::from_vec("value", &vec![1.0, f64::NAN, 2.0, -1.0, f64::NAN]),
+        Series::from_vec("row", &vec![0, 1, 2, 3, 4]),
+    ]);
+
+    for k in 0..=df.rows_count() {
+        for descending in [vec![false, true], vec![false, false]] {
+            let by = df.select_series(["group", "value"]);
+            let top = df.top_k_impl(k, by.clone(), &descending, true);
+            let sorted = df.sort_impl(by, &descending, true);
+            assert_eq!(top.column("row"), sorted.slice(0, k).column("row"));
+        }
+    }
+}
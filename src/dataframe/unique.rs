@@ -0,0 +1,98 @@
+use crate::{
+    chunked_array::{builder::NewFrom, types::BooleanChunked},
+    series::Series,
+};
+
+use super::DataFrame;
+
+// Which row of every group of duplicates is kept
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UniqueKeepStrategy {
+    #[default]
+    First,
+    Last,
+    // Drops the rows that have duplicates
+    None,
+    // Any of the rows, which doesn't have to be the first or the last
+    Any,
+}
+
+impl DataFrame {
+    // Removes the duplicate rows of the `subset` columns, or of all the columns. With
+    // `maintain_order`, the rows that are kept stay in their order.
+    pub fn unique(
+        &self,
+        subset: Option<&[&str]>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+    ) -> DataFrame {
+        let by = match subset {
+            Some(subset) => self.select_series(subset),
+            None => self.columns.clone(),
+        };
+        self.unique_impl(by, keep, maintain_order)
+    }
+
+    pub fn unique_impl(
+        &self,
+        by: Vec<Series>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+    ) -> DataFrame {
+        if self.rows_count() == 0 {
+            return self.clone();
+        }
+        let groups = self.compute_group_proxy(by, maintain_order);
+        let mut indices = match keep {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Any => groups.first_indices(),
+            UniqueKeepStrategy::Last => groups.last_indices(),
+            UniqueKeepStrategy::None => groups
+                .all_indices()
+                .iter()
+                .map(|idx| (idx.len() == 1).then(|| idx[0] as usize))
+                .collect(),
+        }
+        .into_iter()
+        .flatten()
+        .collect::<Vec<usize>>();
+        // The groups are in the order of their first row, which isn't the order of their
+        // last rows
+        if maintain_order && keep == UniqueKeepStrategy::Last {
+            indices.sort_unstable();
+        }
+        let columns = self.apply_columns_par(&|series| series.take_indices(&indices));
+        DataFrame::new_no_checks(columns)
+    }
+
+    // Marks the rows whose `subset` values, or whole row, appear more than once
+    pub fn is_duplicated(&self, subset: Option<&[&str]>) -> BooleanChunked {
+        BooleanChunked::new("", &self.duplicated_mask(subset))
+    }
+
+    // Marks the rows whose `subset` values, or whole row, appear only once
+    pub fn is_unique(&self, subset: Option<&[&str]>) -> BooleanChunked {
+        let is_unique = self
+            .duplicated_mask(subset)
+            .into_iter()
+            .map(|is_duplicated| !is_duplicated)
+            .collect::<Vec<bool>>();
+        BooleanChunked::new("", &is_unique)
+    }
+
+    fn duplicated_mask(&self, subset: Option<&[&str]>) -> Vec<bool> {
+        let mut is_duplicated = vec![false; self.rows_count()];
+        if self.rows_count() == 0 {
+            return is_duplicated;
+        }
+        let by = match subset {
+            Some(subset) => self.select_series(subset),
+            None => self.columns.clone(),
+        };
+        let groups = self.compute_group_proxy(by, false);
+        for idx in groups.all_indices().iter().filter(|idx| idx.len() > 1) {
+            idx.iter()
+                .for_each(|idx| is_duplicated[*idx as usize] = true);
+        }
+        is_duplicated
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::{unique::UniqueKeepStrategy, DataFrame},
+    series::Series,
+};
+
+#[cfg(test)]
+fn example_df() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_slice_options("key", &vec![Some("a"), None, Some("b"), Some("a"), None]),
+        Series::from_vec("value", &vec![1, 2, 3, 4, 2]),
+    ])
+}
+
+#[test]
+fn test_unique_keep_strategies() {
+    let df = example_df();
+
+    let unique = df.unique(Some(&["key"]), UniqueKeepStrategy::First, true);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("key", &vec![Some("a"), None, Some("b")]),
+        Series::from_vec("value", &vec![1, 2, 3]),
+    ]);
+    assert_eq!(unique, expected);
+
+    let unique = df.unique(Some(&["key"]), UniqueKeepStrategy::Last, true);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("key", &vec![Some("b"), Some("a"), None]),
+        Series::from_vec("value", &vec![3, 4, 2]),
+    ]);
+    assert_eq!(unique, expected);
+
+    let unique = df.unique(Some(&["key"]), UniqueKeepStrategy::None, true);
+    let expected = DataFrame::new(vec![
+        Series::from_vec("key", &vec!["b"]),
+        Series::from_vec("value", &vec![3]),
+    ]);
+    assert_eq!(unique, expected);
+
+    let unique = df.unique(Some(&["key"]), UniqueKeepStrategy::Any, false);
+    assert_eq!(unique.rows_count(), 3);
+}
+
+#[test]
+fn test_unique_whole_rows() {
+    let df = example_df();
+
+    let unique = df.unique(None, UniqueKeepStrategy::First, true);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("key", &vec![Some("a"), None, Some("b"), Some("a")]),
+        Series::from_vec("value", &vec![1, 2, 3, 4]),
+    ]);
+    assert_eq!(unique, expected);
+
+    let is_duplicated = df.is_duplicated(None);
+    assert_eq!(
+        is_duplicated.into_iter().collect::<Vec<_>>(),
+        vec![
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true)
+        ]
+    );
+}
+
+#[test]
+fn test_series_unique() {
+    let series = Series::from_slice_options("a", &vec![Some(3), None, Some(3), Some(1), None]);
+
+    assert_eq!(
+        series.unique(),
+        Series::from_slice_options("a", &vec![Some(3), None, Some(1)])
+    );
+    assert_eq!(series.n_unique(), 3);
+    assert_eq!(
+        series.is_duplicated(),
+        Series::from_vec("a", &vec![true, true, true, false, true])
+    );
+    assert_eq!(
+        series.is_unique(),
+        Series::from_vec("a", &vec![false, false, false, true, false])
+    );
+}
@@ -1,3 +1,5 @@
+use crate::series::Series;
+
 use super::DataFrame;
 
 pub fn split_df(df: &DataFrame, n: usize) -> Vec<DataFrame> {
@@ -26,3 +28,50 @@ pub fn split_df(df: &DataFrame, n: usize) -> Vec<DataFrame> {
     }
     frames
 }
+
+// Stacks the frames on top of each other by appending their chunks. The frames must have
+// the same columns in the same order.
+pub fn accumulate_dataframes_vertical(dfs: Vec<DataFrame>) -> DataFrame {
+    let mut iter = dfs.into_iter();
+    let first = iter
+        .next()
+        .expect("cannot accumulate an empty list of dataframes");
+    let mut chunks = first
+        .columns
+        .iter()
+        .map(|series| series.chunks().clone())
+        .collect::<Vec<_>>();
+    for df in iter {
+        assert_eq!(df.columns_count(), first.columns_count());
+        for (acc, (series, other)) in chunks.iter_mut().zip(first.columns.iter().zip(&df.columns)) {
+            assert_eq!(series.name(), other.name());
+            assert_eq!(series.dtype(), other.dtype());
+            acc.extend(other.chunks().iter().cloned());
+        }
+    }
+    let columns = first
+        .columns
+        .iter()
+        .zip(chunks)
+        .map(|(series, chunks)| {
+            Series::from_chunks_and_dtype(series.name(), chunks, &series.dtype())
+        })
+        .collect();
+    DataFrame::new_no_checks(columns)
+}
+
+// Stacks the series on top of each other by appending their chunks. The series must have the
+// same dtype.
+pub fn accumulate_series_vertical(series: Vec<Series>) -> Series {
+    let first = series
+        .first()
+        .expect("cannot accumulate an empty list of series");
+    let chunks = series
+        .iter()
+        .flat_map(|other| {
+            assert_eq!(first.dtype(), other.dtype());
+            other.chunks().iter().cloned()
+        })
+        .collect();
+    Series::from_chunks_and_dtype(first.name(), chunks, &first.dtype())
+}
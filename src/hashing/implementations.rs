@@ -8,7 +8,7 @@ use arrow2::{array::Array, compute::cast::utf8_to_binary};
 use xxhash_rust::xxh3::xxh3_64_with_seed;
 
 use super::VecHash;
-use crate::chunked_array::types::{BooleanChunked, I32Chunked, Utf8Chunked};
+use crate::chunked_array::types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked};
 
 impl VecHash for I32Chunked {
     fn vec_hash(&self, random_state: RandomState, hashes: &mut Vec<u64>) {
@@ -75,6 +75,71 @@ impl VecHash for I32Chunked {
     }
 }
 
+impl VecHash for F64Chunked {
+    fn vec_hash(&self, random_state: RandomState, hashes: &mut Vec<u64>) {
+        self.iter_primitive().for_each(|chunked_array| {
+            let arr = chunked_array.values().iter().copied().map(|ele| {
+                let ele = ele.to_bits();
+                folded_multiply(ele, MULTIPLE)
+            });
+            hashes.extend(arr);
+        });
+        let mut offset = 0;
+        let null_hash = get_null_hash(&random_state);
+        self.iter_primitive().for_each(|chunked_array| {
+            if chunked_array.null_count() != 0 {
+                chunked_array
+                    .validity()
+                    .unwrap()
+                    .iter()
+                    .enumerate()
+                    .for_each(|(idx, t)| {
+                        if !t {
+                            hashes[offset + idx] = null_hash;
+                        }
+                    })
+            }
+            offset += chunked_array.len();
+        });
+    }
+
+    fn vec_hash_combine(&self, random_state: RandomState, hashes: &mut [u64]) {
+        let mut offset = 0;
+        let null_hash = get_null_hash(&random_state);
+        self.iter_primitive().for_each(|chunked_array| {
+            if chunked_array.null_count() == 0 {
+                chunked_array
+                    .values()
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .for_each(|(idx, ele)| {
+                        // TODO: Remove indexing operation
+                        hashes[offset + idx] =
+                            folded_multiply(ele.to_bits() ^ hashes[offset + idx], MULTIPLE);
+                    })
+            } else {
+                chunked_array
+                    .values()
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .zip(chunked_array.validity().unwrap().iter())
+                    .for_each(|((idx, ele), is_valid)| {
+                        if is_valid {
+                            hashes[offset + idx] =
+                                folded_multiply(ele.to_bits() ^ hashes[offset + idx], MULTIPLE);
+                        } else {
+                            hashes[offset + idx] =
+                                folded_multiply(null_hash ^ hashes[offset + idx], MULTIPLE);
+                        }
+                    })
+            }
+            offset += chunked_array.len();
+        })
+    }
+}
+
 impl VecHash for Utf8Chunked {
     fn vec_hash(&self, random_state: RandomState, hashes: &mut Vec<u64>) {
         let null_hash = get_null_hash(&random_state);
@@ -1,4 +1,7 @@
-use std::collections::hash_map::RandomState;
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasherDefault, Hasher},
+};
 
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
@@ -35,3 +38,28 @@ pub fn hash_dataframes(frames: &[DataFrame], hasher: &RandomState) -> Vec<Vec<u6
     })
     .collect()
 }
+
+// Hashmaps keyed by IdxHash are filled with precomputed hashes (insert_hashed_nocheck).
+// When the map grows it rehashes its keys, so the hasher must return the precomputed hash
+// unchanged. Otherwise the entries end up in the wrong buckets.
+// See Polars: https://github.com/pola-rs/polars/blob/f566963f526a11585805088c96e579045a0a2b79/polars/polars-core/src/hashing/identity.rs
+#[derive(Default)]
+pub struct IdHasher {
+    hash: u64,
+}
+
+impl Hasher for IdHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {
+        unreachable!("IdHasher should only be used for u64 keys")
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.hash = i;
+    }
+}
+
+pub type IdBuildHasher = BuildHasherDefault<IdHasher>;
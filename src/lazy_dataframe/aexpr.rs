@@ -1,14 +1,24 @@
 use std::{iter::FilterMap, sync::Arc};
 
-use crate::core::{field::Field, iterator::AExprIter, schema::Schema};
+use crate::{
+    chunked_array::aggregate::QuantileInterpolOptions,
+    core::{field::Field, iterator::AExprIter, schema::Schema},
+    series::{
+        cast::{get_supertype, numeric_supertype},
+        nulls::coalesce_supertype,
+    },
+    types::DataType,
+};
 
 use super::{
     arena::{Arena, Node},
     expr::{AggExpr, Expr, Operator},
+    function_expr::FunctionExpr,
     lit::LiteralValue,
     physical_plan::physical_expr::{
-        agg::AggregationExpr, binary_expr::BinaryExpr, column::ColumnExpr, literal::LiteralExpr,
-        PhysicalExpr,
+        agg::AggregationExpr, alias::AliasExpr, apply::ApplyExpr, binary_expr::BinaryExpr,
+        coalesce::CoalesceExpr, column::ColumnExpr, filter::FilterExpr, literal::LiteralExpr,
+        ternary::TernaryExpr, window::WindowExpr, PhysicalExpr,
     },
 };
 
@@ -23,11 +33,67 @@ pub enum AExpr {
     Column(Arc<str>),
     Literal(LiteralValue),
     Agg(AAggExpr),
+    Filter {
+        input: Node,
+        by: Node,
+    },
+    Window {
+        function: Node,
+        partition_by: Vec<Node>,
+        order_by: Option<Node>,
+    },
+    Alias(Node, Arc<str>),
+    Function {
+        input: Node,
+        function: FunctionExpr,
+    },
+    Coalesce(Vec<Node>),
+    Ternary {
+        predicate: Node,
+        truthy: Node,
+        falsy: Node,
+    },
 }
 
 #[derive(Clone, Debug)]
 pub enum AAggExpr {
     Min(Node),
+    Max(Node),
+    Sum(Node),
+    Mean(Node),
+    Count(Node),
+    Len(Node),
+    NUnique(Node),
+    First(Node),
+    Last(Node),
+    Median(Node),
+    Quantile {
+        expr: Node,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
+    Std(Node, u8),
+    Var(Node, u8),
+}
+
+impl AAggExpr {
+    pub fn get_input(&self) -> Node {
+        match self {
+            AAggExpr::Min(input)
+            | AAggExpr::Max(input)
+            | AAggExpr::Sum(input)
+            | AAggExpr::Mean(input)
+            | AAggExpr::Count(input)
+            | AAggExpr::Len(input)
+            | AAggExpr::NUnique(input)
+            | AAggExpr::First(input)
+            | AAggExpr::Last(input)
+            | AAggExpr::Median(input)
+            | AAggExpr::Quantile { expr: input, .. }
+            | AAggExpr::Std(input, _)
+            | AAggExpr::Var(input, _) => *input,
+        }
+    }
 }
 
 impl AExpr {
@@ -39,18 +105,119 @@ impl AExpr {
             }
             AExpr::Column(_) => {}
             AExpr::Literal(_) => {}
-            AExpr::Agg(agg) => match agg {
-                AAggExpr::Min(agg) => stack.push(*agg),
-            },
+            AExpr::Agg(agg) => stack.push(agg.get_input()),
+            AExpr::Filter { input, by } => {
+                stack.push(*input);
+                stack.push(*by);
+            }
+            AExpr::Window {
+                function,
+                partition_by,
+                order_by,
+            } => {
+                stack.push(*function);
+                stack.extend(partition_by);
+                stack.extend(order_by);
+            }
+            AExpr::Alias(input, _) => stack.push(*input),
+            AExpr::Function { input, .. } => stack.push(*input),
+            AExpr::Coalesce(inputs) => stack.extend(inputs),
+            AExpr::Ternary {
+                predicate,
+                truthy,
+                falsy,
+            } => stack.extend([*predicate, *truthy, *falsy]),
         }
     }
 
-    pub fn to_field(&self, schema: &Schema) -> Field {
+    pub fn to_field(&self, schema: &Schema, arena: &Arena<AExpr>) -> Field {
         match self {
-            AExpr::BinaryExpr { left, op, right } => todo!(),
+            AExpr::BinaryExpr { left, op, right } => {
+                let left = arena.get(*left).to_field(schema, arena);
+                let dtype = match op {
+                    Operator::And
+                    | Operator::Or
+                    | Operator::Eq
+                    | Operator::NotEq
+                    | Operator::Gt
+                    | Operator::GtEq
+                    | Operator::Lt
+                    | Operator::LtEq => DataType::Boolean,
+                    Operator::Plus | Operator::Minus | Operator::Multiply | Operator::Divide => {
+                        let right = arena.get(*right).to_field(schema, arena);
+                        numeric_supertype(&left.dtype, &right.dtype)
+                    }
+                };
+                Field {
+                    name: left.name,
+                    dtype,
+                }
+            }
+            AExpr::Filter { input, .. } => arena.get(*input).to_field(schema, arena),
+            AExpr::Window { function, .. } => arena.get(*function).to_field(schema, arena),
+            AExpr::Function { input, function } => {
+                let mut field = arena.get(*input).to_field(schema, arena);
+                field.dtype = function.output_dtype(&field.dtype);
+                field
+            }
+            AExpr::Coalesce(inputs) => {
+                let mut fields = inputs
+                    .iter()
+                    .map(|input| arena.get(*input).to_field(schema, arena));
+                let first = fields
+                    .next()
+                    .expect("coalesce needs at least one expression");
+                let dtype = fields.fold(first.dtype, |dtype, field| {
+                    coalesce_supertype(&dtype, &field.dtype)
+                });
+                Field {
+                    name: first.name,
+                    dtype,
+                }
+            }
+            AExpr::Ternary { truthy, falsy, .. } => {
+                let truthy = arena.get(*truthy).to_field(schema, arena);
+                let falsy = arena.get(*falsy).to_field(schema, arena);
+                let dtype = get_supertype(&truthy.dtype, &falsy.dtype).unwrap_or_else(|| {
+                    panic!(
+                        "cannot combine {:?} and {:?} values in a column",
+                        truthy.dtype, falsy.dtype
+                    )
+                });
+                Field {
+                    name: truthy.name,
+                    dtype,
+                }
+            }
+            AExpr::Alias(input, name) => Field {
+                name: name.to_string(),
+                dtype: arena.get(*input).to_field(schema, arena).dtype,
+            },
             AExpr::Column(col_name) => schema.get_field(&col_name).unwrap(),
-            AExpr::Literal(_) => todo!(),
-            AExpr::Agg(_) => todo!(),
+            AExpr::Literal(value) => Field {
+                name: "literal".to_string(),
+                dtype: value.get_dtype(),
+            },
+            AExpr::Agg(agg) => {
+                let mut field = arena.get(agg.get_input()).to_field(schema, arena);
+                field.dtype = match agg {
+                    AAggExpr::Min(_)
+                    | AAggExpr::Max(_)
+                    | AAggExpr::First(_)
+                    | AAggExpr::Last(_) => field.dtype,
+                    AAggExpr::Sum(_) => match field.dtype {
+                        DataType::Boolean => DataType::Int32,
+                        dtype => dtype,
+                    },
+                    AAggExpr::Mean(_)
+                    | AAggExpr::Median(_)
+                    | AAggExpr::Quantile { .. }
+                    | AAggExpr::Std(_, _)
+                    | AAggExpr::Var(_, _) => DataType::Float64,
+                    AAggExpr::Count(_) | AAggExpr::Len(_) | AAggExpr::NUnique(_) => DataType::Int32,
+                };
+                field
+            }
         }
     }
 }
@@ -64,9 +231,65 @@ pub fn expr_to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             right: expr_to_aexpr(*right, arena),
         },
         Expr::Literal(v) => AExpr::Literal(v),
-        Expr::Agg(agg) => match agg {
-            AggExpr::Min(input) => AExpr::Agg(AAggExpr::Min(expr_to_aexpr(*input, arena))),
+        Expr::Filter { input, by } => AExpr::Filter {
+            input: expr_to_aexpr(*input, arena),
+            by: expr_to_aexpr(*by, arena),
+        },
+        Expr::Window {
+            function,
+            partition_by,
+            order_by,
+        } => AExpr::Window {
+            function: expr_to_aexpr(*function, arena),
+            partition_by: partition_by
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, arena))
+                .collect(),
+            order_by: order_by.map(|expr| expr_to_aexpr(*expr, arena)),
+        },
+        Expr::Alias(input, name) => AExpr::Alias(expr_to_aexpr(*input, arena), name),
+        Expr::Function { input, function } => AExpr::Function {
+            input: expr_to_aexpr(*input, arena),
+            function,
         },
+        Expr::Coalesce(inputs) => AExpr::Coalesce(
+            inputs
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, arena))
+                .collect(),
+        ),
+        Expr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => AExpr::Ternary {
+            predicate: expr_to_aexpr(*predicate, arena),
+            truthy: expr_to_aexpr(*truthy, arena),
+            falsy: expr_to_aexpr(*falsy, arena),
+        },
+        Expr::Agg(agg) => AExpr::Agg(match agg {
+            AggExpr::Min(input) => AAggExpr::Min(expr_to_aexpr(*input, arena)),
+            AggExpr::Max(input) => AAggExpr::Max(expr_to_aexpr(*input, arena)),
+            AggExpr::Sum(input) => AAggExpr::Sum(expr_to_aexpr(*input, arena)),
+            AggExpr::Mean(input) => AAggExpr::Mean(expr_to_aexpr(*input, arena)),
+            AggExpr::Count(input) => AAggExpr::Count(expr_to_aexpr(*input, arena)),
+            AggExpr::Len(input) => AAggExpr::Len(expr_to_aexpr(*input, arena)),
+            AggExpr::NUnique(input) => AAggExpr::NUnique(expr_to_aexpr(*input, arena)),
+            AggExpr::First(input) => AAggExpr::First(expr_to_aexpr(*input, arena)),
+            AggExpr::Last(input) => AAggExpr::Last(expr_to_aexpr(*input, arena)),
+            AggExpr::Median(input) => AAggExpr::Median(expr_to_aexpr(*input, arena)),
+            AggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => AAggExpr::Quantile {
+                expr: expr_to_aexpr(*expr, arena),
+                quantile,
+                interpol,
+            },
+            AggExpr::Std(input, ddof) => AAggExpr::Std(expr_to_aexpr(*input, arena), ddof),
+            AggExpr::Var(input, ddof) => AAggExpr::Var(expr_to_aexpr(*input, arena), ddof),
+        }),
     };
     arena.add(aexpr)
 }
@@ -80,11 +303,81 @@ pub fn create_physical_expr(expr: Node, expr_arena: &mut Arena<AExpr>) -> Arc<dy
         )),
         AExpr::Column(col_name) => Arc::new(ColumnExpr::new(col_name)),
         AExpr::Literal(lit) => Arc::new(LiteralExpr::new(lit)),
-        AExpr::Agg(agg) => match agg {
-            AAggExpr::Min(input) => Arc::new(AggregationExpr::Min(create_physical_expr(
-                input, expr_arena,
-            ))),
-        },
+        AExpr::Filter { input, by } => Arc::new(FilterExpr::new(
+            create_physical_expr(input, expr_arena),
+            create_physical_expr(by, expr_arena),
+        )),
+        AExpr::Window {
+            function,
+            partition_by,
+            order_by,
+        } => {
+            // Window expressions with the same keys share their groups
+            let cache_key = format!(
+                "{:?}{:?}",
+                partition_by
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect::<Vec<Expr>>(),
+                order_by.map(|node| expr_node_to_expr(node, expr_arena))
+            );
+            Arc::new(WindowExpr {
+                function: create_physical_expr(function, expr_arena),
+                partition_by: partition_by
+                    .iter()
+                    .map(|node| create_physical_expr(*node, expr_arena))
+                    .collect(),
+                order_by: order_by.map(|node| create_physical_expr(node, expr_arena)),
+                cache_key,
+            })
+        }
+        AExpr::Function { input, function } => Arc::new(ApplyExpr::new(
+            create_physical_expr(input, expr_arena),
+            function,
+        )),
+        AExpr::Coalesce(inputs) => Arc::new(CoalesceExpr::new(
+            inputs
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect(),
+        )),
+        AExpr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => Arc::new(TernaryExpr::new(
+            create_physical_expr(predicate, expr_arena),
+            create_physical_expr(truthy, expr_arena),
+            create_physical_expr(falsy, expr_arena),
+        )),
+        AExpr::Alias(input, name) => Arc::new(AliasExpr::new(
+            create_physical_expr(input, expr_arena),
+            name,
+        )),
+        AExpr::Agg(agg) => {
+            let input = create_physical_expr(agg.get_input(), expr_arena);
+            Arc::new(match agg {
+                AAggExpr::Min(_) => AggregationExpr::Min(input),
+                AAggExpr::Max(_) => AggregationExpr::Max(input),
+                AAggExpr::Sum(_) => AggregationExpr::Sum(input),
+                AAggExpr::Mean(_) => AggregationExpr::Mean(input),
+                AAggExpr::Count(_) => AggregationExpr::Count(input),
+                AAggExpr::Len(_) => AggregationExpr::Len(input),
+                AAggExpr::NUnique(_) => AggregationExpr::NUnique(input),
+                AAggExpr::First(_) => AggregationExpr::First(input),
+                AAggExpr::Last(_) => AggregationExpr::Last(input),
+                AAggExpr::Median(_) => AggregationExpr::Median(input),
+                AAggExpr::Quantile {
+                    quantile, interpol, ..
+                } => AggregationExpr::Quantile {
+                    input,
+                    quantile,
+                    interpol,
+                },
+                AAggExpr::Std(_, ddof) => AggregationExpr::Std(input, ddof),
+                AAggExpr::Var(_, ddof) => AggregationExpr::Var(input, ddof),
+            })
+        }
     }
 }
 
@@ -132,10 +425,68 @@ pub fn expr_node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
         },
         AExpr::Column(col) => Expr::Column(col.clone()),
         AExpr::Literal(lit) => Expr::Literal(lit.clone()),
-        AExpr::Agg(agg) => match agg {
-            AAggExpr::Min(input) => Expr::Agg(AggExpr::Min(Box::new(expr_node_to_expr(
-                *input, expr_arena,
-            )))),
+        AExpr::Filter { input, by } => Expr::Filter {
+            input: Box::new(expr_node_to_expr(*input, expr_arena)),
+            by: Box::new(expr_node_to_expr(*by, expr_arena)),
         },
+        AExpr::Window {
+            function,
+            partition_by,
+            order_by,
+        } => Expr::Window {
+            function: Box::new(expr_node_to_expr(*function, expr_arena)),
+            partition_by: partition_by
+                .iter()
+                .map(|node| expr_node_to_expr(*node, expr_arena))
+                .collect(),
+            order_by: order_by.map(|node| Box::new(expr_node_to_expr(node, expr_arena))),
+        },
+        AExpr::Function { input, function } => Expr::Function {
+            input: Box::new(expr_node_to_expr(*input, expr_arena)),
+            function: function.clone(),
+        },
+        AExpr::Coalesce(inputs) => Expr::Coalesce(
+            inputs
+                .iter()
+                .map(|node| expr_node_to_expr(*node, expr_arena))
+                .collect(),
+        ),
+        AExpr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => Expr::Ternary {
+            predicate: Box::new(expr_node_to_expr(*predicate, expr_arena)),
+            truthy: Box::new(expr_node_to_expr(*truthy, expr_arena)),
+            falsy: Box::new(expr_node_to_expr(*falsy, expr_arena)),
+        },
+        AExpr::Alias(input, name) => Expr::Alias(
+            Box::new(expr_node_to_expr(*input, expr_arena)),
+            name.clone(),
+        ),
+        AExpr::Agg(agg) => {
+            let input = Box::new(expr_node_to_expr(agg.get_input(), expr_arena));
+            Expr::Agg(match agg {
+                AAggExpr::Min(_) => AggExpr::Min(input),
+                AAggExpr::Max(_) => AggExpr::Max(input),
+                AAggExpr::Sum(_) => AggExpr::Sum(input),
+                AAggExpr::Mean(_) => AggExpr::Mean(input),
+                AAggExpr::Count(_) => AggExpr::Count(input),
+                AAggExpr::Len(_) => AggExpr::Len(input),
+                AAggExpr::NUnique(_) => AggExpr::NUnique(input),
+                AAggExpr::First(_) => AggExpr::First(input),
+                AAggExpr::Last(_) => AggExpr::Last(input),
+                AAggExpr::Median(_) => AggExpr::Median(input),
+                AAggExpr::Quantile {
+                    quantile, interpol, ..
+                } => AggExpr::Quantile {
+                    expr: input,
+                    quantile: *quantile,
+                    interpol: *interpol,
+                },
+                AAggExpr::Std(_, ddof) => AggExpr::Std(input, *ddof),
+                AAggExpr::Var(_, ddof) => AggExpr::Var(input, *ddof),
+            })
+        }
     }
 }
@@ -2,16 +2,20 @@ use std::sync::Arc;
 
 use crate::{
     core::schema::Schema,
-    dataframe::{join::JoinType, DataFrame},
+    dataframe::{
+        concat::ConcatHow, groupby::dynamic::DynamicGroupOptions, join::JoinType, DataFrame,
+    },
 };
 
 use super::{
     aexpr::{create_physical_expr, expr_node_to_expr, expr_to_aexpr, AExpr},
     arena::{Arena, Node},
-    logical_plan::LogicalPlan,
+    logical_plan::{DataFrameUdf, DistinctOptions, GroupByOptions, LogicalPlan, SortArguments},
     physical_plan::executor::{
         data_frame_scan::DataFrameScanExec, filter::FilterExec, groupby::GroupByExec,
-        join::JoinExec, Executor,
+        groupby_dynamic::GroupByDynamicExec, join::JoinExec, projection::ProjectionExec,
+        slice::SliceExec, sort::SortExec, stack::StackExec, top_k::TopKExec, union::UnionExec,
+        unique::DistinctExec, value_counts::ValueCountsExec, Executor,
     },
 };
 
@@ -35,12 +39,67 @@ pub enum ALogicalPlan {
         df: Arc<DataFrame>,
         projection: Option<Arc<Vec<String>>>,
         selection: Option<Node>,
+        slice: Option<(i64, usize)>,
         schema: Arc<Schema>,
     },
     GroupBy {
         input: Node,
         by: Vec<Node>,
         agg: Vec<Node>,
+        apply: Option<Arc<dyn DataFrameUdf>>,
+        schema: Arc<Schema>,
+        options: GroupByOptions,
+    },
+    GroupByDynamic {
+        input: Node,
+        by: Vec<Node>,
+        agg: Vec<Node>,
+        options: DynamicGroupOptions,
+        schema: Arc<Schema>,
+    },
+    Projection {
+        input: Node,
+        expr: Vec<Node>,
+        schema: Arc<Schema>,
+    },
+    HStack {
+        input: Node,
+        exprs: Vec<Node>,
+        schema: Arc<Schema>,
+    },
+    Sort {
+        input: Node,
+        by_column: Vec<Node>,
+        args: SortArguments,
+    },
+    Slice {
+        input: Node,
+        offset: i64,
+        len: usize,
+    },
+    TopK {
+        input: Node,
+        by_column: Vec<Node>,
+        args: SortArguments,
+        k: usize,
+    },
+    Distinct {
+        input: Node,
+        options: DistinctOptions,
+    },
+    ValueCounts {
+        input: Node,
+        keys: Vec<Node>,
+        values: Node,
+        sort: bool,
+        normalize: bool,
+        options: GroupByOptions,
+        schema: Arc<Schema>,
+    },
+    Union {
+        inputs: Vec<Node>,
+        how: ConcatHow,
+        schema: Arc<Schema>,
     },
 }
 
@@ -50,7 +109,35 @@ impl ALogicalPlan {
             ALogicalPlan::Join { schema, .. } => schema.as_ref().clone(),
             ALogicalPlan::Selection { input, .. } => arena.get(*input).schema(arena),
             ALogicalPlan::DataFrameScan { schema, .. } => schema.as_ref().clone(),
-            ALogicalPlan::GroupBy { input, by, agg } => todo!(),
+            ALogicalPlan::GroupBy { schema, .. } => schema.as_ref().clone(),
+            ALogicalPlan::GroupByDynamic { schema, .. } => schema.as_ref().clone(),
+            ALogicalPlan::Projection { schema, .. } => schema.as_ref().clone(),
+            ALogicalPlan::HStack { schema, .. } => schema.as_ref().clone(),
+            ALogicalPlan::Sort { input, .. } => arena.get(*input).schema(arena),
+            ALogicalPlan::Slice { input, .. } => arena.get(*input).schema(arena),
+            ALogicalPlan::TopK { input, .. } => arena.get(*input).schema(arena),
+            ALogicalPlan::Distinct { input, .. } => arena.get(*input).schema(arena),
+            ALogicalPlan::ValueCounts { schema, .. } => schema.as_ref().clone(),
+            ALogicalPlan::Union { schema, .. } => schema.as_ref().clone(),
+        }
+    }
+
+    // The nodes this node reads from
+    pub fn inputs(&self) -> Vec<Node> {
+        match self {
+            ALogicalPlan::Join { left, right, .. } => vec![*left, *right],
+            ALogicalPlan::DataFrameScan { .. } => vec![],
+            ALogicalPlan::Selection { input, .. }
+            | ALogicalPlan::GroupBy { input, .. }
+            | ALogicalPlan::GroupByDynamic { input, .. }
+            | ALogicalPlan::Projection { input, .. }
+            | ALogicalPlan::HStack { input, .. }
+            | ALogicalPlan::Sort { input, .. }
+            | ALogicalPlan::Slice { input, .. }
+            | ALogicalPlan::TopK { input, .. }
+            | ALogicalPlan::Distinct { input, .. }
+            | ALogicalPlan::ValueCounts { input, .. } => vec![*input],
+            ALogicalPlan::Union { inputs, .. } => inputs.clone(),
         }
     }
 
@@ -89,14 +176,23 @@ impl ALogicalPlan {
                 df,
                 projection,
                 selection,
+                slice,
                 schema,
             } => LogicalPlan::DataFrameScan {
                 df,
                 projection,
                 selection: selection.map(|node| expr_node_to_expr(node, expr_arena)),
+                slice,
                 schema,
             },
-            ALogicalPlan::GroupBy { input, by, agg } => LogicalPlan::GroupBy {
+            ALogicalPlan::GroupBy {
+                input,
+                by,
+                agg,
+                apply,
+                schema,
+                options,
+            } => LogicalPlan::GroupBy {
                 keys: by
                     .iter()
                     .map(|node| expr_node_to_expr(*node, expr_arena))
@@ -106,6 +202,119 @@ impl ALogicalPlan {
                     .map(|node| expr_node_to_expr(*node, expr_arena))
                     .collect(),
                 input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                apply,
+                schema,
+                options,
+            },
+            ALogicalPlan::GroupByDynamic {
+                input,
+                by,
+                agg,
+                options,
+                schema,
+            } => LogicalPlan::GroupByDynamic {
+                keys: by
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                agg: agg
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                options,
+                schema,
+            },
+            ALogicalPlan::Projection {
+                input,
+                expr,
+                schema,
+            } => LogicalPlan::Projection {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                expr: expr
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                schema,
+            },
+            ALogicalPlan::HStack {
+                input,
+                exprs,
+                schema,
+            } => LogicalPlan::HStack {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                exprs: exprs
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                schema,
+            },
+            ALogicalPlan::Sort {
+                input,
+                by_column,
+                args,
+            } => LogicalPlan::Sort {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                by_column: by_column
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                args,
+            },
+            ALogicalPlan::Slice { input, offset, len } => LogicalPlan::Slice {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                offset,
+                len,
+            },
+            ALogicalPlan::TopK {
+                input,
+                by_column,
+                args,
+                k,
+            } => LogicalPlan::TopK {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                by_column: by_column
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                args,
+                k,
+            },
+            ALogicalPlan::Distinct { input, options } => LogicalPlan::Distinct {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                options,
+            },
+            ALogicalPlan::ValueCounts {
+                input,
+                keys,
+                values,
+                sort,
+                normalize,
+                options,
+                schema,
+            } => LogicalPlan::ValueCounts {
+                input: Box::new(alp_arena.take(input).to_lp(alp_arena, expr_arena)),
+                keys: keys
+                    .iter()
+                    .map(|node| expr_node_to_expr(*node, expr_arena))
+                    .collect(),
+                values: expr_node_to_expr(values, expr_arena),
+                sort,
+                normalize,
+                options,
+                schema,
+            },
+            ALogicalPlan::Union {
+                inputs,
+                how,
+                schema,
+            } => LogicalPlan::Union {
+                inputs: inputs
+                    .into_iter()
+                    .map(|input| alp_arena.take(input).to_lp(alp_arena, expr_arena))
+                    .collect(),
+                how,
+                schema,
             },
         }
     }
@@ -146,14 +355,43 @@ pub fn logical_to_alp(
             df,
             projection,
             selection,
+            slice,
             schema,
         } => ALogicalPlan::DataFrameScan {
             df,
             projection,
             selection: selection.map(|expr| expr_to_aexpr(expr, expr_arena)),
+            slice,
+            schema,
+        },
+        LogicalPlan::GroupBy {
+            keys,
+            agg,
+            input,
+            apply,
+            schema,
+            options,
+        } => ALogicalPlan::GroupBy {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            by: keys
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            agg: agg
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            apply,
             schema,
+            options,
         },
-        LogicalPlan::GroupBy { keys, agg, input } => ALogicalPlan::GroupBy {
+        LogicalPlan::GroupByDynamic {
+            keys,
+            agg,
+            input,
+            options,
+            schema,
+        } => ALogicalPlan::GroupByDynamic {
             input: logical_to_alp(*input, expr_arena, alp_arena),
             by: keys
                 .into_iter()
@@ -163,6 +401,99 @@ pub fn logical_to_alp(
                 .into_iter()
                 .map(|expr| expr_to_aexpr(expr, expr_arena))
                 .collect(),
+            options,
+            schema,
+        },
+        LogicalPlan::Projection {
+            input,
+            expr,
+            schema,
+        } => ALogicalPlan::Projection {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            expr: expr
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            schema,
+        },
+        LogicalPlan::HStack {
+            input,
+            exprs,
+            schema,
+        } => ALogicalPlan::HStack {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            exprs: exprs
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            schema,
+        },
+        LogicalPlan::Sort {
+            input,
+            by_column,
+            args,
+        } => ALogicalPlan::Sort {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            by_column: by_column
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            args,
+        },
+        LogicalPlan::Slice { input, offset, len } => ALogicalPlan::Slice {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            offset,
+            len,
+        },
+        LogicalPlan::TopK {
+            input,
+            by_column,
+            args,
+            k,
+        } => ALogicalPlan::TopK {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            by_column: by_column
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            args,
+            k,
+        },
+        LogicalPlan::Distinct { input, options } => ALogicalPlan::Distinct {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            options,
+        },
+        LogicalPlan::ValueCounts {
+            input,
+            keys,
+            values,
+            sort,
+            normalize,
+            options,
+            schema,
+        } => ALogicalPlan::ValueCounts {
+            input: logical_to_alp(*input, expr_arena, alp_arena),
+            keys: keys
+                .into_iter()
+                .map(|expr| expr_to_aexpr(expr, expr_arena))
+                .collect(),
+            values: expr_to_aexpr(values, expr_arena),
+            sort,
+            normalize,
+            options,
+            schema,
+        },
+        LogicalPlan::Union {
+            inputs,
+            how,
+            schema,
+        } => ALogicalPlan::Union {
+            inputs: inputs
+                .into_iter()
+                .map(|input| logical_to_alp(input, expr_arena, alp_arena))
+                .collect(),
+            how,
+            schema,
         },
     };
     alp_arena.add(node)
@@ -213,12 +544,20 @@ pub fn alp_node_to_physical_plan(
             df,
             projection,
             selection,
-            schema,
+            slice,
+            ..
         } => {
             let selection = selection.map(|node| create_physical_expr(node, expr_arena));
-            Box::new(DataFrameScanExec::new(df, projection, selection))
+            Box::new(DataFrameScanExec::new(df, projection, selection, slice))
         }
-        ALogicalPlan::GroupBy { input, by, agg } => {
+        ALogicalPlan::GroupBy {
+            input,
+            by,
+            agg,
+            apply,
+            options,
+            ..
+        } => {
             let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
             let by = by
                 .iter()
@@ -232,7 +571,120 @@ pub fn alp_node_to_physical_plan(
                 input,
                 keys: by,
                 agg,
+                apply,
+                options,
+            })
+        }
+        ALogicalPlan::GroupByDynamic {
+            input,
+            by,
+            agg,
+            options,
+            ..
+        } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            let by = by
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            let agg = agg
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            Box::new(GroupByDynamicExec {
+                input,
+                keys: by,
+                agg,
+                options,
             })
         }
+        ALogicalPlan::Projection { input, expr, .. } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            let expr = expr
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            Box::new(ProjectionExec { input, expr })
+        }
+        ALogicalPlan::HStack { input, exprs, .. } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            let exprs = exprs
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            Box::new(StackExec { input, exprs })
+        }
+        ALogicalPlan::Sort {
+            input,
+            by_column,
+            args,
+        } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            let by_column = by_column
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            Box::new(SortExec {
+                input,
+                by_column,
+                args,
+            })
+        }
+        ALogicalPlan::Slice { input, offset, len } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            Box::new(SliceExec { input, offset, len })
+        }
+        ALogicalPlan::TopK {
+            input,
+            by_column,
+            args,
+            k,
+        } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            let by_column = by_column
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            Box::new(TopKExec {
+                input,
+                by_column,
+                args,
+                k,
+            })
+        }
+        ALogicalPlan::Distinct { input, options } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            Box::new(DistinctExec { input, options })
+        }
+        ALogicalPlan::ValueCounts {
+            input,
+            keys,
+            values,
+            sort,
+            normalize,
+            options,
+            ..
+        } => {
+            let input = alp_node_to_physical_plan(input, expr_arena, alp_arena);
+            let keys = keys
+                .iter()
+                .map(|node| create_physical_expr(*node, expr_arena))
+                .collect();
+            Box::new(ValueCountsExec {
+                input,
+                keys,
+                values: create_physical_expr(values, expr_arena),
+                sort,
+                normalize,
+                options,
+            })
+        }
+        ALogicalPlan::Union { inputs, how, .. } => {
+            let inputs = inputs
+                .into_iter()
+                .map(|input| alp_node_to_physical_plan(input, expr_arena, alp_arena))
+                .collect();
+            Box::new(UnionExec { inputs, how })
+        }
     }
 }
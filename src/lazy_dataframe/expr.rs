@@ -1,6 +1,23 @@
-use std::{fmt::Debug, sync::Arc};
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Sub},
+    sync::Arc,
+};
 
-use super::lit::LiteralValue;
+use crate::{
+    chunked_array::{
+        aggregate::QuantileInterpolOptions,
+        rank::{RankMethod, RankOptions},
+        rolling_window::RollingOptions,
+        types::AnyValue,
+    },
+    series::nulls::FillNullStrategy,
+};
+
+use super::{
+    function_expr::{FunctionExpr, RollingFunction},
+    lit::LiteralValue,
+};
 
 #[derive(Clone)]
 pub enum Expr {
@@ -12,6 +29,30 @@ pub enum Expr {
     },
     Literal(LiteralValue),
     Agg(AggExpr),
+    Filter {
+        input: Box<Expr>,
+        by: Box<Expr>,
+    },
+    // Evaluates `function` within the groups of `partition_by` and returns a value for every
+    // row. The rows of every group are ordered by `order_by`.
+    Window {
+        function: Box<Expr>,
+        partition_by: Vec<Expr>,
+        order_by: Option<Box<Expr>>,
+    },
+    Alias(Box<Expr>, Arc<str>),
+    Function {
+        input: Box<Expr>,
+        function: FunctionExpr,
+    },
+    // The first of the expressions that isn't null, for every row
+    Coalesce(Vec<Expr>),
+    // `truthy` where the predicate is true, `falsy` where it is false or null. Built by `when`.
+    Ternary {
+        predicate: Box<Expr>,
+        truthy: Box<Expr>,
+        falsy: Box<Expr>,
+    },
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -19,6 +60,15 @@ pub enum Operator {
     And,
     Or,
     Eq,
+    NotEq,
+    Gt,
+    GtEq,
+    Lt,
+    LtEq,
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
 }
 
 impl Expr {
@@ -30,24 +80,337 @@ impl Expr {
         }
     }
 
+    pub fn neq(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::NotEq,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn gt(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::Gt,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn gt_eq(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::GtEq,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn lt(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::Lt,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn lt_eq(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::LtEq,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn and(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::And,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn or(self, other: Expr) -> Expr {
+        Expr::BinaryExpr {
+            left: Box::new(self),
+            op: Operator::Or,
+            right: Box::new(other),
+        }
+    }
+
+    pub fn filter(self, predicate: Expr) -> Expr {
+        Expr::Filter {
+            input: Box::new(self),
+            by: Box::new(predicate),
+        }
+    }
+
+    // Evaluates the expression within the groups of `partition_by`. Aggregations are broadcast
+    // to the rows of their group.
+    pub fn over(self, partition_by: Vec<Expr>) -> Expr {
+        Expr::Window {
+            function: Box::new(self),
+            partition_by,
+            order_by: None,
+        }
+    }
+
+    // Like `over`, with the rows of every group ordered by `order_by`
+    pub fn over_ordered(self, partition_by: Vec<Expr>, order_by: Expr) -> Expr {
+        Expr::Window {
+            function: Box::new(self),
+            partition_by,
+            order_by: Some(Box::new(order_by)),
+        }
+    }
+
+    // Renames the output of the expression
+    pub fn alias(self, name: &str) -> Expr {
+        Expr::Alias(Box::new(self), Arc::from(name))
+    }
+
+    fn function(self, function: FunctionExpr) -> Expr {
+        Expr::Function {
+            input: Box::new(self),
+            function,
+        }
+    }
+
+    // Ranks the values starting from 1. Combine with `over` to rank within groups.
+    pub fn rank(self, method: RankMethod, descending: bool) -> Expr {
+        self.function(FunctionExpr::Rank(RankOptions { method, descending }))
+    }
+
+    pub fn dense_rank(self, descending: bool) -> Expr {
+        self.rank(RankMethod::Dense, descending)
+    }
+
+    // Numbers the rows in the order of their values. Ties are numbered in the order of the
+    // rows, which `over_ordered` sets within groups.
+    pub fn row_number(self, descending: bool) -> Expr {
+        self.rank(RankMethod::Ordinal, descending)
+    }
+
+    // The relative rank of the values, between 0 and 1
+    pub fn percent_rank(self, descending: bool) -> Expr {
+        self.function(FunctionExpr::PercentRank { descending })
+    }
+
+    // Splits the values in order into `n` buckets of about the same size numbered from 1
+    pub fn ntile(self, n: u32, descending: bool) -> Expr {
+        self.function(FunctionExpr::Ntile { n, descending })
+    }
+
+    // Running aggregations. Combine with `over` to restart them in every group.
+    pub fn cum_sum(self) -> Expr {
+        self.function(FunctionExpr::CumSum)
+    }
+
+    pub fn cum_prod(self) -> Expr {
+        self.function(FunctionExpr::CumProd)
+    }
+
+    pub fn cum_min(self) -> Expr {
+        self.function(FunctionExpr::CumMin)
+    }
+
+    pub fn cum_max(self) -> Expr {
+        self.function(FunctionExpr::CumMax)
+    }
+
+    pub fn cum_count(self) -> Expr {
+        self.function(FunctionExpr::CumCount)
+    }
+
+    pub fn shift(self, periods: i64) -> Expr {
+        self.function(FunctionExpr::Shift {
+            periods,
+            fill_value: None,
+        })
+    }
+
+    pub fn shift_and_fill(self, periods: i64, fill_value: AnyValue<'static>) -> Expr {
+        self.function(FunctionExpr::Shift {
+            periods,
+            fill_value: Some(fill_value),
+        })
+    }
+
+    pub fn diff(self, periods: i64) -> Expr {
+        self.function(FunctionExpr::Diff { periods })
+    }
+
+    pub fn pct_change(self, periods: i64) -> Expr {
+        self.function(FunctionExpr::PctChange { periods })
+    }
+
+    fn rolling(self, function: RollingFunction, options: RollingOptions) -> Expr {
+        self.function(FunctionExpr::Rolling { function, options })
+    }
+
+    // Aggregations over a window around every row, like moving averages
+    pub fn rolling_sum(self, options: RollingOptions) -> Expr {
+        self.rolling(RollingFunction::Sum, options)
+    }
+
+    pub fn rolling_mean(self, options: RollingOptions) -> Expr {
+        self.rolling(RollingFunction::Mean, options)
+    }
+
+    pub fn rolling_min(self, options: RollingOptions) -> Expr {
+        self.rolling(RollingFunction::Min, options)
+    }
+
+    pub fn rolling_max(self, options: RollingOptions) -> Expr {
+        self.rolling(RollingFunction::Max, options)
+    }
+
+    pub fn rolling_std(self, options: RollingOptions) -> Expr {
+        self.rolling(RollingFunction::Std, options)
+    }
+
+    pub fn rolling_quantile(
+        self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+        options: RollingOptions,
+    ) -> Expr {
+        self.rolling(RollingFunction::Quantile { quantile, interpol }, options)
+    }
+
+    // The distinct values, next to their counts in a column named "count", or "proportion"
+    // with `normalize`. With `sort`, the most frequent values come first. It must be the only
+    // expression of a select, or of a groupby aggregation to count within every group.
+    pub fn value_counts(self, sort: bool, normalize: bool) -> Expr {
+        self.function(FunctionExpr::ValueCounts { sort, normalize })
+    }
+
+    pub fn is_null(self) -> Expr {
+        self.function(FunctionExpr::IsNull)
+    }
+
+    pub fn is_not_null(self) -> Expr {
+        self.function(FunctionExpr::IsNotNull)
+    }
+
+    // Replaces the nulls with the values of `value` at the same rows
+    pub fn fill_null(self, value: Expr) -> Expr {
+        coalesce(vec![self, value])
+    }
+
+    // Forward and Backward fill within the groups in a groupby or window context
+    pub fn fill_null_with_strategy(self, strategy: FillNullStrategy) -> Expr {
+        self.function(FunctionExpr::FillNull(strategy))
+    }
+
     pub fn min(self) -> Expr {
         Expr::Agg(AggExpr::Min(Box::new(self)))
     }
+
+    pub fn max(self) -> Expr {
+        Expr::Agg(AggExpr::Max(Box::new(self)))
+    }
+
+    pub fn sum(self) -> Expr {
+        Expr::Agg(AggExpr::Sum(Box::new(self)))
+    }
+
+    pub fn mean(self) -> Expr {
+        Expr::Agg(AggExpr::Mean(Box::new(self)))
+    }
+
+    // Counts the non-null values
+    pub fn count(self) -> Expr {
+        Expr::Agg(AggExpr::Count(Box::new(self)))
+    }
+
+    // Counts the values, nulls included
+    pub fn len(self) -> Expr {
+        Expr::Agg(AggExpr::Len(Box::new(self)))
+    }
+
+    pub fn n_unique(self) -> Expr {
+        Expr::Agg(AggExpr::NUnique(Box::new(self)))
+    }
+
+    pub fn first(self) -> Expr {
+        Expr::Agg(AggExpr::First(Box::new(self)))
+    }
+
+    pub fn last(self) -> Expr {
+        Expr::Agg(AggExpr::Last(Box::new(self)))
+    }
+
+    pub fn median(self) -> Expr {
+        Expr::Agg(AggExpr::Median(Box::new(self)))
+    }
+
+    pub fn quantile(self, quantile: f64, interpol: QuantileInterpolOptions) -> Expr {
+        Expr::Agg(AggExpr::Quantile {
+            expr: Box::new(self),
+            quantile,
+            interpol,
+        })
+    }
+
+    pub fn std(self, ddof: u8) -> Expr {
+        Expr::Agg(AggExpr::Std(Box::new(self), ddof))
+    }
+
+    pub fn var(self, ddof: u8) -> Expr {
+        Expr::Agg(AggExpr::Var(Box::new(self), ddof))
+    }
 }
 
 pub fn col(str: &str) -> Expr {
     Expr::Column(Arc::from(str))
 }
 
+// For every row, the value of the first expression that isn't null there. Int32 and Float64
+// values are combined as Float64.
+pub fn coalesce(exprs: Vec<Expr>) -> Expr {
+    Expr::Coalesce(exprs)
+}
+
 #[derive(Clone)]
 pub enum AggExpr {
     Min(Box<Expr>),
+    Max(Box<Expr>),
+    Sum(Box<Expr>),
+    Mean(Box<Expr>),
+    Count(Box<Expr>),
+    Len(Box<Expr>),
+    NUnique(Box<Expr>),
+    First(Box<Expr>),
+    Last(Box<Expr>),
+    Median(Box<Expr>),
+    Quantile {
+        expr: Box<Expr>,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
+    Std(Box<Expr>, u8),
+    Var(Box<Expr>, u8),
 }
 
 impl Debug for AggExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Min(input) => write!(f, "Min(\"{input:?}\")"),
+            Self::Max(input) => write!(f, "Max(\"{input:?}\")"),
+            Self::Sum(input) => write!(f, "Sum(\"{input:?}\")"),
+            Self::Mean(input) => write!(f, "Mean(\"{input:?}\")"),
+            Self::Count(input) => write!(f, "Count(\"{input:?}\")"),
+            Self::Len(input) => write!(f, "Len(\"{input:?}\")"),
+            Self::NUnique(input) => write!(f, "NUnique(\"{input:?}\")"),
+            Self::First(input) => write!(f, "First(\"{input:?}\")"),
+            Self::Last(input) => write!(f, "Last(\"{input:?}\")"),
+            Self::Median(input) => write!(f, "Median(\"{input:?}\")"),
+            Self::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => write!(f, "Quantile(\"{expr:?}\", {quantile}, {interpol:?})"),
+            Self::Std(input, ddof) => write!(f, "Std(\"{input:?}\", {ddof})"),
+            Self::Var(input, ddof) => write!(f, "Var(\"{input:?}\", {ddof})"),
         }
     }
 }
@@ -59,6 +422,49 @@ impl Debug for Expr {
             Expr::BinaryExpr { left, op, right } => write!(f, "[({left:?}) {op:?} ({right:?})]"),
             Expr::Literal(lit) => write!(f, "lit(\"{lit:?}\")"),
             Expr::Agg(agg_expr) => write!(f, "Agg(\"{agg_expr:?}\")"),
+            Expr::Filter { input, by } => write!(f, "{input:?}.filter({by:?})"),
+            Expr::Window {
+                function,
+                partition_by,
+                order_by: None,
+            } => write!(f, "{function:?}.over({partition_by:?})"),
+            Expr::Window {
+                function,
+                partition_by,
+                order_by: Some(order_by),
+            } => write!(f, "{function:?}.over({partition_by:?}, {order_by:?})"),
+            Expr::Alias(input, name) => write!(f, "{input:?}.alias(\"{name}\")"),
+            Expr::Function { input, function } => write!(f, "{input:?}.{function:?}"),
+            Expr::Coalesce(exprs) => write!(f, "coalesce({exprs:?})"),
+            Expr::Ternary {
+                predicate,
+                truthy,
+                falsy,
+            } => write!(
+                f,
+                "when({predicate:?}).then({truthy:?}).otherwise({falsy:?})"
+            ),
         }
     }
 }
+
+macro_rules! impl_expr_arithmetic {
+    ($trait: ident, $method: ident, $op: expr) => {
+        impl $trait for Expr {
+            type Output = Expr;
+
+            fn $method(self, rhs: Expr) -> Expr {
+                Expr::BinaryExpr {
+                    left: Box::new(self),
+                    op: $op,
+                    right: Box::new(rhs),
+                }
+            }
+        }
+    };
+}
+
+impl_expr_arithmetic!(Add, add, Operator::Plus);
+impl_expr_arithmetic!(Sub, sub, Operator::Minus);
+impl_expr_arithmetic!(Mul, mul, Operator::Multiply);
+impl_expr_arithmetic!(Div, div, Operator::Divide);
@@ -1,7 +1,13 @@
 use std::sync::Arc;
 
 use crate::{
-    dataframe::{join::JoinType, DataFrame},
+    dataframe::{
+        concat::{concat_schema, ConcatHow},
+        groupby::dynamic::DynamicGroupOptions,
+        join::JoinType,
+        unique::UniqueKeepStrategy,
+        DataFrame,
+    },
     lazy_dataframe::alogical_plan::logical_to_alp,
 };
 
@@ -9,14 +15,30 @@ use super::{
     aexpr::AExpr,
     alogical_plan::{alp_node_to_physical_plan, ALogicalPlan},
     arena::{Arena, Node},
-    expr::Expr,
+    expr::{col, Expr},
     lazy_groupby::LazyGroupBy,
-    logical_plan::LogicalPlan,
+    logical_plan::{DistinctOptions, LogicalPlan, SortArguments},
     logical_plan_builder::LogicalPlanBuilder,
-    optimizer::predicate_pushdown::PredicatePushdown,
+    optimizer::{
+        predicate_pushdown::PredicatePushdown, slice_pushdown::SlicePushdown, top_k::TopKRewrite,
+    },
     physical_plan::executor::Executor,
 };
 
+// Combines the frames like `dataframe::concat::concat`. The inputs are executed in parallel.
+pub fn concat(frames: Vec<LazyFrame>, how: ConcatHow) -> LazyFrame {
+    let inputs = frames
+        .into_iter()
+        .map(|lf| lf.logical_plan)
+        .collect::<Vec<LogicalPlan>>();
+    let schema = concat_schema(inputs.iter().map(|lp| lp.schema().as_ref().clone()), how);
+    LazyFrame::from_logical_plan(LogicalPlan::Union {
+        inputs,
+        how,
+        schema: Arc::new(schema),
+    })
+}
+
 impl DataFrame {
     pub fn lazy(self) -> LazyFrame {
         let schema = self.schema();
@@ -24,6 +46,7 @@ impl DataFrame {
             df: Arc::new(self),
             projection: None,
             selection: None,
+            slice: None,
             schema: Arc::new(schema),
         })
     }
@@ -47,6 +70,89 @@ impl LazyFrame {
         Self::from_logical_plan(self.get_plan_builder().filter(predicate).build())
     }
 
+    pub fn select(self, expr: Vec<Expr>) -> Self {
+        Self::from_logical_plan(self.get_plan_builder().project(expr).build())
+    }
+
+    // Adds the outputs of the expressions as columns, replacing columns of the same name
+    pub fn with_columns(self, exprs: Vec<Expr>) -> Self {
+        Self::from_logical_plan(self.get_plan_builder().with_columns(exprs).build())
+    }
+
+    // Sorts the rows by the columns, the first one taking precedence. See `DataFrame::sort`.
+    pub fn sort(self, by: &[&str], descending: &[bool], nulls_last: bool) -> Self {
+        let by_column = by.iter().map(|name| col(name)).collect();
+        self.sort_by_exprs(by_column, descending, nulls_last)
+    }
+
+    pub fn sort_by_exprs(
+        self,
+        by_column: Vec<Expr>,
+        descending: &[bool],
+        nulls_last: bool,
+    ) -> Self {
+        let args = SortArguments {
+            descending: descending.to_vec(),
+            nulls_last,
+        };
+        Self::from_logical_plan(self.get_plan_builder().sort(by_column, args).build())
+    }
+
+    // Removes the duplicate rows. See `DataFrame::unique`.
+    pub fn unique(
+        self,
+        subset: Option<&[&str]>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+    ) -> Self {
+        let options = DistinctOptions {
+            subset: subset.map(|subset| Arc::new(subset.iter().map(|s| s.to_string()).collect())),
+            keep,
+            maintain_order,
+        };
+        Self::from_logical_plan(self.get_plan_builder().distinct(options).build())
+    }
+
+    // Removes the rows with a null in any of the `subset` columns, or in any column. This is a
+    // filter on `is_not_null`, which is pushed down to the scan.
+    pub fn drop_nulls(self, subset: Option<&[&str]>) -> Self {
+        let columns = match subset {
+            Some(subset) => subset.iter().map(|name| name.to_string()).collect(),
+            None => self
+                .logical_plan
+                .schema()
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<String>>(),
+        };
+        let predicate = columns
+            .iter()
+            .map(|name| col(name).is_not_null())
+            .reduce(|left, right| left.and(right));
+        match predicate {
+            Some(predicate) => self.filter(predicate),
+            None => self,
+        }
+    }
+
+    // The `len` rows from `offset`. A negative offset counts from the end.
+    pub fn slice(self, offset: i64, len: usize) -> Self {
+        Self::from_logical_plan(self.get_plan_builder().slice(offset, len).build())
+    }
+
+    // The first `n` rows. After a sort, only the first rows are sorted.
+    pub fn limit(self, n: usize) -> Self {
+        self.slice(0, n)
+    }
+
+    pub fn head(self, n: usize) -> Self {
+        self.slice(0, n)
+    }
+
+    pub fn tail(self, n: usize) -> Self {
+        self.slice(-(n as i64), n)
+    }
+
     pub fn join(
         self,
         left_on: Vec<Expr>,
@@ -65,6 +171,12 @@ impl LazyFrame {
         LazyGroupBy::new(self.logical_plan, by)
     }
 
+    // Groups by `by` and by time windows over an index column. See
+    // `DataFrame::groupby_dynamic`.
+    pub fn groupby_dynamic(self, by: Vec<Expr>, options: DynamicGroupOptions) -> LazyGroupBy {
+        LazyGroupBy::new_dynamic(self.logical_plan, by, options)
+    }
+
     pub fn optimize_with_scratch(
         self,
         alp_arena: &mut Arena<ALogicalPlan>,
@@ -76,6 +188,14 @@ impl LazyFrame {
         let alp = alp_arena.take(node);
         let new_alp = predicate_pushdown.optimize(alp, alp_arena, expr_arena);
         alp_arena.replace(node, new_alp);
+
+        let slice_pushdown = SlicePushdown::new();
+        let alp = alp_arena.take(node);
+        let new_alp = slice_pushdown.optimize(alp, alp_arena, expr_arena);
+        alp_arena.replace(node, new_alp);
+
+        // After the slice pushdown, which moves limits below projections onto the sorts
+        TopKRewrite::new().optimize(node, alp_arena);
         node
     }
 
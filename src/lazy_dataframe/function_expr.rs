@@ -0,0 +1,181 @@
+use std::fmt::Debug;
+
+use crate::{
+    chunked_array::{
+        aggregate::QuantileInterpolOptions,
+        rank::{RankMethod, RankOptions},
+        rolling_window::RollingOptions,
+        types::AnyValue,
+    },
+    series::{constructor::IntoSeries, nulls::FillNullStrategy, Series},
+    types::DataType,
+};
+
+// Functions that map a Series to a Series of the same length. In a groupby or window context
+// they are applied to every group separately. `ValueCounts` is the exception: the planner turns
+// it into a plan node, so it is never called.
+pub(super) const VALUE_COUNTS_MISPLACED: &str =
+    "value_counts must be the only expression of a select or of a groupby aggregation";
+
+#[derive(Clone, PartialEq)]
+pub enum FunctionExpr {
+    Rank(RankOptions),
+    PercentRank {
+        descending: bool,
+    },
+    Ntile {
+        n: u32,
+        descending: bool,
+    },
+    CumSum,
+    CumProd,
+    CumMin,
+    CumMax,
+    CumCount,
+    Shift {
+        periods: i64,
+        fill_value: Option<AnyValue<'static>>,
+    },
+    Diff {
+        periods: i64,
+    },
+    PctChange {
+        periods: i64,
+    },
+    Rolling {
+        function: RollingFunction,
+        options: RollingOptions,
+    },
+    IsNull,
+    IsNotNull,
+    FillNull(FillNullStrategy),
+    ValueCounts {
+        sort: bool,
+        normalize: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RollingFunction {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Std,
+    Quantile {
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
+}
+
+impl FunctionExpr {
+    pub fn call(&self, series: &Series) -> Series {
+        match self {
+            FunctionExpr::Rank(options) => series.rank(*options),
+            FunctionExpr::PercentRank { descending } => series.percent_rank(*descending),
+            FunctionExpr::Ntile { n, descending } => series.ntile(*n, *descending),
+            FunctionExpr::CumSum => series.cum_sum(),
+            FunctionExpr::CumProd => series.cum_prod(),
+            FunctionExpr::CumMin => series.cum_min(),
+            FunctionExpr::CumMax => series.cum_max(),
+            FunctionExpr::CumCount => series.cum_count(),
+            FunctionExpr::Shift {
+                periods,
+                fill_value,
+            } => series.shift(*periods, fill_value.clone()),
+            FunctionExpr::Diff { periods } => series.diff(*periods),
+            FunctionExpr::PctChange { periods } => series.pct_change(*periods),
+            FunctionExpr::Rolling { function, options } => match function {
+                RollingFunction::Sum => series.rolling_sum(options),
+                RollingFunction::Mean => series.rolling_mean(options),
+                RollingFunction::Min => series.rolling_min(options),
+                RollingFunction::Max => series.rolling_max(options),
+                RollingFunction::Std => series.rolling_std(options),
+                RollingFunction::Quantile { quantile, interpol } => {
+                    series.rolling_quantile(*quantile, *interpol, options)
+                }
+            },
+            FunctionExpr::IsNull => series.is_null().into_series(),
+            FunctionExpr::IsNotNull => series.is_not_null().into_series(),
+            FunctionExpr::FillNull(strategy) => series.fill_null(*strategy),
+            FunctionExpr::ValueCounts { .. } => panic!("{VALUE_COUNTS_MISPLACED}"),
+        }
+    }
+
+    // Whether every output value only depends on the input value at the same row, so the
+    // function gives the same values on any subset of the rows
+    pub fn is_elementwise(&self) -> bool {
+        matches!(
+            self,
+            FunctionExpr::IsNull
+                | FunctionExpr::IsNotNull
+                | FunctionExpr::FillNull(FillNullStrategy::Zero)
+        )
+    }
+
+    pub fn output_dtype(&self, input: &DataType) -> DataType {
+        match self {
+            FunctionExpr::Rank(RankOptions {
+                method: RankMethod::Average,
+                ..
+            }) => DataType::Float64,
+            FunctionExpr::Rank(_) => DataType::Int32,
+            FunctionExpr::PercentRank { .. } => DataType::Float64,
+            FunctionExpr::Ntile { .. } => DataType::Int32,
+            FunctionExpr::CumCount => DataType::Int32,
+            FunctionExpr::IsNull | FunctionExpr::IsNotNull => DataType::Boolean,
+            FunctionExpr::PctChange { .. } => DataType::Float64,
+            FunctionExpr::CumSum
+            | FunctionExpr::CumProd
+            | FunctionExpr::CumMin
+            | FunctionExpr::CumMax
+            | FunctionExpr::Shift { .. }
+            | FunctionExpr::Diff { .. }
+            | FunctionExpr::FillNull(_) => input.clone(),
+            // Integers stay integers when the values are only selected or added up
+            FunctionExpr::Rolling {
+                function: RollingFunction::Sum | RollingFunction::Min | RollingFunction::Max,
+                options: RollingOptions { weights: None, .. },
+            } => input.clone(),
+            FunctionExpr::Rolling { .. } => DataType::Float64,
+            // The dtype of the values column
+            FunctionExpr::ValueCounts { .. } => input.clone(),
+        }
+    }
+}
+
+impl Debug for FunctionExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FunctionExpr::Rank(RankOptions { method, descending }) => {
+                write!(f, "rank({method:?}, {descending})")
+            }
+            FunctionExpr::PercentRank { descending } => write!(f, "percent_rank({descending})"),
+            FunctionExpr::Ntile { n, descending } => write!(f, "ntile({n}, {descending})"),
+            FunctionExpr::CumSum => write!(f, "cum_sum()"),
+            FunctionExpr::CumProd => write!(f, "cum_prod()"),
+            FunctionExpr::CumMin => write!(f, "cum_min()"),
+            FunctionExpr::CumMax => write!(f, "cum_max()"),
+            FunctionExpr::CumCount => write!(f, "cum_count()"),
+            FunctionExpr::Shift {
+                periods,
+                fill_value: None,
+            } => write!(f, "shift({periods})"),
+            FunctionExpr::Shift {
+                periods,
+                fill_value: Some(fill_value),
+            } => write!(f, "shift_and_fill({periods}, {fill_value:?})"),
+            FunctionExpr::Diff { periods } => write!(f, "diff({periods})"),
+            FunctionExpr::PctChange { periods } => write!(f, "pct_change({periods})"),
+            FunctionExpr::Rolling { function, options } => {
+                write!(f, "rolling({function:?}, {options:?})")
+            }
+            FunctionExpr::IsNull => write!(f, "is_null()"),
+            FunctionExpr::IsNotNull => write!(f, "is_not_null()"),
+            FunctionExpr::FillNull(strategy) => write!(f, "fill_null({strategy:?})"),
+            FunctionExpr::ValueCounts { sort, normalize } => {
+                write!(f, "value_counts({sort}, {normalize})")
+            }
+        }
+    }
+}
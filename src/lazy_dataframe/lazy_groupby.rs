@@ -1,23 +1,81 @@
-use super::{expr::Expr, frame::LazyFrame, logical_plan::LogicalPlan};
+use std::sync::Arc;
+
+use crate::{
+    core::schema::Schema,
+    dataframe::{groupby::dynamic::DynamicGroupOptions, DataFrame},
+};
+
+use super::{
+    expr::Expr,
+    frame::LazyFrame,
+    logical_plan::{GroupByOptions, LogicalPlan},
+    logical_plan_builder::LogicalPlanBuilder,
+};
 
 pub struct LazyGroupBy {
     input: LogicalPlan,
     by: Vec<Expr>,
+    options: GroupByOptions,
+    dynamic_options: Option<DynamicGroupOptions>,
 }
 
 impl LazyGroupBy {
     pub fn new(input: LogicalPlan, by: Vec<Expr>) -> Self {
-        LazyGroupBy { input, by }
+        LazyGroupBy {
+            input,
+            by,
+            options: GroupByOptions {
+                maintain_order: false,
+                sort: false,
+            },
+            dynamic_options: None,
+        }
+    }
+
+    // Groups by `by` and by the windows of `options` over its index column
+    pub fn new_dynamic(input: LogicalPlan, by: Vec<Expr>, options: DynamicGroupOptions) -> Self {
+        LazyGroupBy {
+            dynamic_options: Some(options),
+            ..LazyGroupBy::new(input, by).maintain_order(true)
+        }
+    }
+
+    // Keeps the groups in the order of their first row
+    pub fn maintain_order(mut self, maintain_order: bool) -> Self {
+        self.options.maintain_order = maintain_order;
+        self
+    }
+
+    // Sorts the output of the groupby by its keys
+    pub fn sort(mut self, sort: bool) -> Self {
+        self.options.sort = sort;
+        self
     }
 }
 
 impl LazyGroupBy {
     pub fn agg(self, agg: Vec<Expr>) -> LazyFrame {
-        let lp = LogicalPlan::GroupBy {
-            keys: self.by,
-            agg,
-            input: Box::new(self.input),
-        };
+        let builder = LogicalPlanBuilder::from_logical_plan(self.input);
+        let lp = match self.dynamic_options {
+            Some(options) => builder.groupby_dynamic(self.by, agg, options),
+            None => builder.groupby(self.by, agg, self.options),
+        }
+        .build();
+        LazyFrame::from_logical_plan(lp)
+    }
+
+    // Calls `f` with the sub-frame of every group and stacks the outputs vertically. Every
+    // output must have the columns of `schema`.
+    pub fn apply<F>(self, f: F, schema: Schema) -> LazyFrame
+    where
+        F: Fn(DataFrame) -> DataFrame + Send + Sync + 'static,
+    {
+        if self.dynamic_options.is_some() {
+            panic!("apply is not supported on a dynamic groupby");
+        }
+        let lp = LogicalPlanBuilder::from_logical_plan(self.input)
+            .groupby_apply(self.by, Arc::new(f), schema, self.options)
+            .build();
         LazyFrame::from_logical_plan(lp)
     }
 }
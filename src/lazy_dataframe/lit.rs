@@ -1,3 +1,5 @@
+use crate::types::DataType;
+
 use super::expr::Expr;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -7,6 +9,16 @@ pub enum LiteralValue {
     Utf8(String),
 }
 
+impl LiteralValue {
+    pub fn get_dtype(&self) -> DataType {
+        match self {
+            LiteralValue::Boolean(_) => DataType::Boolean,
+            LiteralValue::Int32(_) => DataType::Int32,
+            LiteralValue::Utf8(_) => DataType::Utf8,
+        }
+    }
+}
+
 pub trait Literal {
     fn lit(self) -> Expr;
 }
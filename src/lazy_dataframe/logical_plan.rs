@@ -7,7 +7,10 @@ use ahash::{HashSet, HashSetExt};
 
 use crate::{
     core::schema::{Schema, SchemaRef},
-    dataframe::{join::JoinType, DataFrame},
+    dataframe::{
+        concat::ConcatHow, groupby::dynamic::DynamicGroupOptions, join::JoinType,
+        unique::UniqueKeepStrategy, DataFrame,
+    },
 };
 
 use super::{aexpr::expr_to_aexpr, arena::Arena, expr::Expr};
@@ -32,14 +35,122 @@ pub enum LogicalPlan {
         df: Arc<DataFrame>,
         projection: Option<Arc<Vec<String>>>,
         selection: Option<Expr>,
+        // Rows taken after the selection, as (offset, len)
+        slice: Option<(i64, usize)>,
         schema: Arc<Schema>,
     },
     GroupBy {
         keys: Vec<Expr>,
         agg: Vec<Expr>,
         input: Box<LogicalPlan>,
+        // Called on the sub-frame of every group instead of the aggregations
+        apply: Option<Arc<dyn DataFrameUdf>>,
+        schema: SchemaRef,
+        options: GroupByOptions,
+    },
+    // Groups the rows by `keys` and by time windows over an index column
+    GroupByDynamic {
+        keys: Vec<Expr>,
+        agg: Vec<Expr>,
+        input: Box<LogicalPlan>,
+        options: DynamicGroupOptions,
+        schema: SchemaRef,
+    },
+    Projection {
+        input: Box<LogicalPlan>,
+        expr: Vec<Expr>,
+        schema: SchemaRef,
+    },
+    // Adds the outputs of the expressions to the input columns, replacing columns of the same
+    // name
+    HStack {
+        input: Box<LogicalPlan>,
+        exprs: Vec<Expr>,
+        schema: SchemaRef,
+    },
+    Sort {
+        input: Box<LogicalPlan>,
+        by_column: Vec<Expr>,
+        args: SortArguments,
+    },
+    // The `len` rows from `offset`. A negative offset counts from the end.
+    Slice {
+        input: Box<LogicalPlan>,
+        offset: i64,
+        len: usize,
+    },
+    // The first `k` rows of a sort, which the optimizer creates from a sort followed by a
+    // slice from the start
+    TopK {
+        input: Box<LogicalPlan>,
+        by_column: Vec<Expr>,
+        args: SortArguments,
+        k: usize,
     },
-    // TODO: Projection
+    Distinct {
+        input: Box<LogicalPlan>,
+        options: DistinctOptions,
+    },
+    // The distinct values of `values` and their counts, within the groups of `keys` if there
+    // are any. Planned from `Expr::value_counts`. See `Series::value_counts`.
+    ValueCounts {
+        input: Box<LogicalPlan>,
+        keys: Vec<Expr>,
+        values: Expr,
+        sort: bool,
+        normalize: bool,
+        options: GroupByOptions,
+        schema: SchemaRef,
+    },
+    // The inputs combined with `concat`
+    Union {
+        inputs: Vec<LogicalPlan>,
+        how: ConcatHow,
+        schema: SchemaRef,
+    },
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SortArguments {
+    // One flag for every column, or a single flag for all of them
+    pub descending: Vec<bool>,
+    pub nulls_last: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DistinctOptions {
+    // The columns that identify duplicates, or all of them
+    pub subset: Option<Arc<Vec<String>>>,
+    pub keep: UniqueKeepStrategy,
+    pub maintain_order: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GroupByOptions {
+    // Output the groups in the order of their first occurrence
+    pub maintain_order: bool,
+    // Output the groups sorted by their keys
+    pub sort: bool,
+}
+
+// A user-defined function from a dataframe to a dataframe
+pub trait DataFrameUdf: Send + Sync {
+    fn call_udf(&self, df: DataFrame) -> DataFrame;
+}
+
+impl<F> DataFrameUdf for F
+where
+    F: Fn(DataFrame) -> DataFrame + Send + Sync,
+{
+    fn call_udf(&self, df: DataFrame) -> DataFrame {
+        self(df)
+    }
+}
+
+impl Debug for dyn DataFrameUdf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "udf")
+    }
 }
 
 impl LogicalPlan {
@@ -48,11 +159,32 @@ impl LogicalPlan {
             LogicalPlan::Join { schema, .. } => schema.clone(),
             LogicalPlan::Selection { input, predicate } => input.schema(),
             LogicalPlan::DataFrameScan { schema, .. } => schema.clone(),
-            LogicalPlan::GroupBy { keys, agg, .. } => todo!(),
+            LogicalPlan::GroupBy { schema, .. } => schema.clone(),
+            LogicalPlan::GroupByDynamic { schema, .. } => schema.clone(),
+            LogicalPlan::Projection { schema, .. } => schema.clone(),
+            LogicalPlan::HStack { schema, .. } => schema.clone(),
+            LogicalPlan::Sort { input, .. } => input.schema(),
+            LogicalPlan::Slice { input, .. } => input.schema(),
+            LogicalPlan::TopK { input, .. } => input.schema(),
+            LogicalPlan::Distinct { input, .. } => input.schema(),
+            LogicalPlan::ValueCounts { schema, .. } => schema.clone(),
+            LogicalPlan::Union { schema, .. } => schema.clone(),
         }
     }
 }
 
+pub fn det_projection_schema(input_schema: &Schema, expr: &[Expr]) -> SchemaRef {
+    let mut expr_arena = Arena::new();
+    let schema = expr
+        .iter()
+        .map(|expr| {
+            let aexpr = expr_to_aexpr(expr.clone(), &mut expr_arena);
+            expr_arena.get(aexpr).to_field(input_schema, &expr_arena)
+        })
+        .collect::<Schema>();
+    Arc::new(schema)
+}
+
 pub fn det_join_schema(
     schema_left: &SchemaRef,
     schema_right: &SchemaRef,
@@ -70,7 +202,7 @@ pub fn det_join_schema(
     let mut expr_arena = Arena::new();
     right_on.iter().for_each(|key| {
         let aexpr = expr_to_aexpr(key.clone(), &mut expr_arena);
-        let field = expr_arena.get(aexpr).to_field(&schema_right);
+        let field = expr_arena.get(aexpr).to_field(&schema_right, &expr_arena);
         right_join_keys.insert(field.name);
     });
     schema_right.iter().for_each(|(name, dtype)| {
@@ -109,20 +241,103 @@ impl LogicalPlan {
                 df,
                 projection,
                 selection,
+                slice,
                 schema,
             } => {
                 write!(f, "{:indent$}DF:", "")?;
                 write!(f, "\n{:indent$} PROJECT: {projection:?}", "")?;
                 write!(f, "\n{:indent$} SELECTION: {selection:?}", "")?;
+                write!(f, "\n{:indent$} SLICE: {slice:?}", "")?;
                 write!(f, "\n{:indent$} SCHEMA: {schema:?}", "")
             }
-            LogicalPlan::GroupBy { keys, agg, input } => {
+            LogicalPlan::GroupBy {
+                keys,
+                agg,
+                input,
+                apply,
+                options,
+                ..
+            } => {
                 write!(f, "{:indent$}GROUPBY:", "")?;
+                write!(f, "\n{:indent$} OPTIONS: {options:?}", "")?;
 
                 write!(f, "\n{:indent$} KEYS: {keys:?}", "")?;
-                write!(f, "\n{:indent$} BY: {agg:?}", "");
+                write!(f, "\n{:indent$} BY: {agg:?}", "")?;
+                if let Some(apply) = apply {
+                    write!(f, "\n{:indent$} APPLY: {apply:?}", "")?;
+                }
+                write!(f, "\n{:indent$} INPUT: {input:?}", "")
+            }
+            LogicalPlan::GroupByDynamic {
+                keys,
+                agg,
+                input,
+                options,
+                ..
+            } => {
+                write!(f, "{:indent$}GROUPBY_DYNAMIC:", "")?;
+                write!(f, "\n{:indent$} OPTIONS: {options:?}", "")?;
+                write!(f, "\n{:indent$} KEYS: {keys:?}", "")?;
+                write!(f, "\n{:indent$} BY: {agg:?}", "")?;
                 write!(f, "\n{:indent$} INPUT: {input:?}", "")
             }
+            LogicalPlan::Projection { input, expr, .. } => {
+                write!(f, "{:indent$}SELECT {expr:?} FROM", "")?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::HStack { input, exprs, .. } => {
+                write!(f, "{:indent$}WITH_COLUMNS {exprs:?} FROM", "")?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::Sort {
+                input,
+                by_column,
+                args,
+            } => {
+                write!(f, "{:indent$}SORT BY {by_column:?} {args:?} FROM", "")?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::Slice { input, offset, len } => {
+                write!(f, "{:indent$}SLICE {offset}, {len} FROM", "")?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::TopK {
+                input,
+                by_column,
+                args,
+                k,
+            } => {
+                write!(f, "{:indent$}TOP {k} BY {by_column:?} {args:?} FROM", "")?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::Distinct { input, options } => {
+                write!(f, "{:indent$}DISTINCT {options:?} FROM", "")?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::ValueCounts {
+                input,
+                keys,
+                values,
+                sort,
+                normalize,
+                ..
+            } => {
+                write!(
+                    f,
+                    "{:indent$}VALUE COUNTS OF {values:?} BY {keys:?} sort: {sort}, normalize: \
+                     {normalize} FROM",
+                    ""
+                )?;
+                input._fmt(f, indent)
+            }
+            LogicalPlan::Union { inputs, how, .. } => {
+                write!(f, "{:indent$}{how:?} UNION:", "")?;
+                for (idx, input) in inputs.iter().enumerate() {
+                    write!(f, "\n{:indent$}PLAN {idx}:", "")?;
+                    input._fmt(f, next_indent)?;
+                }
+                write!(f, "\n{:indent$}END {how:?} UNION", "")
+            }
         }
     }
 }
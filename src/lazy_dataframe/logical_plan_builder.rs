@@ -1,10 +1,20 @@
 use std::sync::Arc;
 
-use crate::dataframe::join::JoinType;
+use crate::{
+    core::schema::Schema,
+    dataframe::{groupby::dynamic::DynamicGroupOptions, join::JoinType},
+    types::DataType,
+};
 
 use super::{
+    aexpr::{expr_to_aexpr, AExpr},
+    arena::Arena,
     expr::Expr,
-    logical_plan::{det_join_schema, LogicalPlan},
+    function_expr::{FunctionExpr, VALUE_COUNTS_MISPLACED},
+    logical_plan::{
+        det_join_schema, det_projection_schema, DataFrameUdf, DistinctOptions, GroupByOptions,
+        LogicalPlan, SortArguments,
+    },
 };
 
 pub struct LogicalPlanBuilder(LogicalPlan);
@@ -32,6 +42,169 @@ impl LogicalPlanBuilder {
         .into()
     }
 
+    pub fn project(self, expr: Vec<Expr>) -> Self {
+        if let [expr] = expr.as_slice() {
+            if let Some((values, sort, normalize)) = as_value_counts(expr) {
+                let options = GroupByOptions::default();
+                return self.value_counts(vec![], values, sort, normalize, options);
+            }
+        }
+        assert_no_value_counts(&expr);
+        let schema = det_projection_schema(&self.0.schema(), &expr);
+        LogicalPlan::Projection {
+            input: Box::new(self.0),
+            expr,
+            schema,
+        }
+        .into()
+    }
+
+    // The output has the key columns followed by the aggregated columns
+    pub fn groupby(self, keys: Vec<Expr>, agg: Vec<Expr>, options: GroupByOptions) -> Self {
+        if let [expr] = agg.as_slice() {
+            if let Some((values, sort, normalize)) = as_value_counts(expr) {
+                return self.value_counts(keys, values, sort, normalize, options);
+            }
+        }
+        assert_no_value_counts(&keys);
+        assert_no_value_counts(&agg);
+        let input_schema = self.0.schema();
+        let mut schema = Schema::new();
+        with_unique_columns(&mut schema, &input_schema, &keys);
+        with_unique_columns(&mut schema, &input_schema, &agg);
+        LogicalPlan::GroupBy {
+            keys,
+            agg,
+            input: Box::new(self.0),
+            apply: None,
+            schema: Arc::new(schema),
+            options,
+        }
+        .into()
+    }
+
+    // The output schema of `apply` can't be inferred, so the caller declares it
+    pub fn groupby_apply(
+        self,
+        keys: Vec<Expr>,
+        apply: Arc<dyn DataFrameUdf>,
+        schema: Schema,
+        options: GroupByOptions,
+    ) -> Self {
+        LogicalPlan::GroupBy {
+            keys,
+            agg: vec![],
+            input: Box::new(self.0),
+            apply: Some(apply),
+            schema: Arc::new(schema),
+            options,
+        }
+        .into()
+    }
+
+    // The output has the key columns, the start and the stop of the windows, and the
+    // aggregated columns
+    pub fn groupby_dynamic(
+        self,
+        keys: Vec<Expr>,
+        agg: Vec<Expr>,
+        options: DynamicGroupOptions,
+    ) -> Self {
+        assert_no_value_counts(&keys);
+        assert_no_value_counts(&agg);
+        let input_schema = self.0.schema();
+        let mut schema = Schema::new();
+        with_unique_columns(&mut schema, &input_schema, &keys);
+        with_unique_column(&mut schema, "_lower_boundary".into(), DataType::Int32);
+        with_unique_column(&mut schema, "_upper_boundary".into(), DataType::Int32);
+        with_unique_columns(&mut schema, &input_schema, &agg);
+        LogicalPlan::GroupByDynamic {
+            keys,
+            agg,
+            input: Box::new(self.0),
+            options,
+            schema: Arc::new(schema),
+        }
+        .into()
+    }
+
+    pub fn with_columns(self, exprs: Vec<Expr>) -> Self {
+        assert_no_value_counts(&exprs);
+        let input_schema = self.0.schema();
+        let mut schema = input_schema.as_ref().clone();
+        for (name, dtype) in det_projection_schema(&input_schema, &exprs).iter() {
+            schema.with_column(name.clone(), dtype.clone());
+        }
+        LogicalPlan::HStack {
+            input: Box::new(self.0),
+            exprs,
+            schema: Arc::new(schema),
+        }
+        .into()
+    }
+
+    pub fn sort(self, by_column: Vec<Expr>, args: SortArguments) -> Self {
+        LogicalPlan::Sort {
+            input: Box::new(self.0),
+            by_column,
+            args,
+        }
+        .into()
+    }
+
+    pub fn distinct(self, options: DistinctOptions) -> Self {
+        LogicalPlan::Distinct {
+            input: Box::new(self.0),
+            options,
+        }
+        .into()
+    }
+
+    // The output has the key columns, the distinct values and a "count" or "proportion" column
+    fn value_counts(
+        self,
+        keys: Vec<Expr>,
+        values: Expr,
+        sort: bool,
+        normalize: bool,
+        options: GroupByOptions,
+    ) -> Self {
+        assert_no_value_counts(&keys);
+        assert_no_value_counts(std::slice::from_ref(&values));
+        let input_schema = self.0.schema();
+        let mut schema = Schema::new();
+        with_unique_columns(&mut schema, &input_schema, &keys);
+        with_unique_columns(&mut schema, &input_schema, std::slice::from_ref(&values));
+        let (name, dtype) = if normalize {
+            ("proportion", DataType::Float64)
+        } else {
+            ("count", DataType::Int32)
+        };
+        if schema.index_of(name).is_some() {
+            panic!("cannot count the values into a column named {name:?}, which is taken");
+        }
+        schema.with_column(name.to_string(), dtype);
+        LogicalPlan::ValueCounts {
+            input: Box::new(self.0),
+            keys,
+            values,
+            sort,
+            normalize,
+            options,
+            schema: Arc::new(schema),
+        }
+        .into()
+    }
+
+    pub fn slice(self, offset: i64, len: usize) -> Self {
+        LogicalPlan::Slice {
+            input: Box::new(self.0),
+            offset,
+            len,
+        }
+        .into()
+    }
+
     pub fn join(
         self,
         left_on: Vec<Expr>,
@@ -54,3 +227,57 @@ impl LogicalPlanBuilder {
         .into()
     }
 }
+
+// Adds the columns of `exprs` to the schema of a groupby output. Every output column of a
+// groupby is computed on its own, so two columns with the same name are an error instead of
+// one replacing the other like in a projection.
+fn with_unique_columns(schema: &mut Schema, input_schema: &Schema, exprs: &[Expr]) {
+    for expr in exprs {
+        let fields = det_projection_schema(input_schema, std::slice::from_ref(expr));
+        for (name, dtype) in fields.iter() {
+            with_unique_column(schema, name.clone(), dtype.clone());
+        }
+    }
+}
+
+fn with_unique_column(schema: &mut Schema, name: String, dtype: DataType) {
+    if schema.index_of(&name).is_some() {
+        panic!("the groupby outputs more than one column named {name:?}");
+    }
+    schema.with_column(name, dtype);
+}
+
+// The counted values and the options of a `value_counts` expression, which may be aliased
+fn as_value_counts(expr: &Expr) -> Option<(Expr, bool, bool)> {
+    match expr {
+        Expr::Function {
+            input,
+            function: FunctionExpr::ValueCounts { sort, normalize },
+        } => Some((input.as_ref().clone(), *sort, *normalize)),
+        Expr::Alias(expr, name) => {
+            let (values, sort, normalize) = as_value_counts(expr)?;
+            Some((values.alias(name), sort, normalize))
+        }
+        _ => None,
+    }
+}
+
+// `value_counts` changes the number of rows, so it can't be a part of other expressions
+fn assert_no_value_counts(exprs: &[Expr]) {
+    let mut expr_arena = Arena::new();
+    for expr in exprs {
+        let node = expr_to_aexpr(expr.clone(), &mut expr_arena);
+        let has_value_counts = expr_arena.iter(node).any(|(_, aexpr)| {
+            matches!(
+                aexpr,
+                AExpr::Function {
+                    function: FunctionExpr::ValueCounts { .. },
+                    ..
+                }
+            )
+        });
+        if has_value_counts {
+            panic!("{VALUE_COUNTS_MISPLACED}");
+        }
+    }
+}
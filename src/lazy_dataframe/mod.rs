@@ -3,9 +3,11 @@ pub mod alogical_plan;
 pub mod arena;
 pub mod expr;
 pub mod frame;
+pub mod function_expr;
 pub mod lazy_groupby;
 pub mod lit;
 pub mod logical_plan;
 pub mod logical_plan_builder;
 pub mod optimizer;
 pub mod physical_plan;
+pub mod when;
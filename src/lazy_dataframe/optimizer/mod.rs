@@ -1,2 +1,6 @@
 pub mod predicate_pushdown;
 mod predicate_pushdown_test;
+pub mod slice_pushdown;
+mod slice_pushdown_test;
+pub mod top_k;
+mod top_k_test;
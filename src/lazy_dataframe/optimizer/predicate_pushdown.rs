@@ -2,12 +2,15 @@ use std::sync::Arc;
 
 use hashbrown::{hash_map::Entry, HashMap};
 
-use crate::lazy_dataframe::{
-    aexpr::{aexpr_to_leaf_names_iter, check_input_node, AExpr},
-    alogical_plan::ALogicalPlan,
-    arena::{self, Arena, Node},
-    expr::Operator,
-    logical_plan::{self, LogicalPlan},
+use crate::{
+    dataframe::concat::ConcatHow,
+    lazy_dataframe::{
+        aexpr::{aexpr_to_leaf_names_iter, check_input_node, AExpr},
+        alogical_plan::ALogicalPlan,
+        arena::{self, Arena, Node},
+        expr::Operator,
+        logical_plan::{self, LogicalPlan},
+    },
 };
 
 pub struct PredicatePushdown {}
@@ -77,6 +80,17 @@ impl PredicatePushdown {
                 };
                 self.optional_wrap_selection(new_join, local_predicates, alp_arena, expr_arena)
             }
+            // A predicate over aggregations or windows depends on all the rows of its input, so
+            // neither it nor the predicates above it can be pushed down
+            ALogicalPlan::Selection { input, predicate }
+                if predicate_is_pushdown_boundary(predicate, expr_arena) =>
+            {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let mut local_predicates = acc_predicates.into_values().collect::<Vec<Node>>();
+                local_predicates.push(predicate);
+                let input = alp_arena.take(input);
+                self.optional_wrap_selection(input, local_predicates, alp_arena, expr_arena)
+            }
             ALogicalPlan::Selection { input, predicate } => {
                 let local_predicates = extract_local_predicates(&mut acc_predicates, |node| {
                     predicate_is_pushdown_boundary(node, expr_arena)
@@ -91,6 +105,7 @@ impl PredicatePushdown {
                 df,
                 projection,
                 selection,
+                slice,
                 schema,
             } => {
                 let selection = if !acc_predicates.is_empty() {
@@ -111,15 +126,201 @@ impl PredicatePushdown {
                     df,
                     projection,
                     selection,
+                    slice,
                     schema,
                 }
             }
-            ALogicalPlan::GroupBy { input, by, agg } => {
+            ALogicalPlan::GroupBy {
+                input,
+                by,
+                agg,
+                apply,
+                schema,
+                options,
+            } => {
+                // The udf sees the whole groups, so filtering its input changes its output
+                if apply.is_some() {
+                    self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                    let lp = ALogicalPlan::GroupBy {
+                        input,
+                        by,
+                        agg,
+                        apply,
+                        schema,
+                        options,
+                    };
+                    let local_predicates = acc_predicates.into_values().collect();
+                    return self.optional_wrap_selection(
+                        lp,
+                        local_predicates,
+                        alp_arena,
+                        expr_arena,
+                    );
+                }
                 self.pushdown_and_replace(input, acc_predicates, alp_arena, expr_arena);
-                let lp = ALogicalPlan::GroupBy { input, by, agg };
+                let lp = ALogicalPlan::GroupBy {
+                    input,
+                    by,
+                    agg,
+                    apply,
+                    schema,
+                    options,
+                };
                 // TODO: We might need to have some local predicates here
                 self.optional_wrap_selection(lp, vec![], alp_arena, expr_arena)
             }
+            // Filtering the input would change the windows, so predicates stay above it.
+            ALogicalPlan::GroupByDynamic {
+                input,
+                by,
+                agg,
+                options,
+                schema,
+            } => {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let lp = ALogicalPlan::GroupByDynamic {
+                    input,
+                    by,
+                    agg,
+                    options,
+                    schema,
+                };
+                let local_predicates = acc_predicates.into_values().collect();
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            // Window expressions depend on all the rows, so predicates stay above it.
+            ALogicalPlan::HStack {
+                input,
+                exprs,
+                schema,
+            } => {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let lp = ALogicalPlan::HStack {
+                    input,
+                    exprs,
+                    schema,
+                };
+                let local_predicates = acc_predicates.into_values().collect();
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            // Filtering and sorting commute, so predicates are pushed through
+            ALogicalPlan::Sort {
+                input,
+                by_column,
+                args,
+            } => {
+                self.pushdown_and_replace(input, acc_predicates, alp_arena, expr_arena);
+                ALogicalPlan::Sort {
+                    input,
+                    by_column,
+                    args,
+                }
+            }
+            // Filtering before taking rows would select other rows, so predicates stay above
+            // slices and top k
+            ALogicalPlan::Slice { input, offset, len } => {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let lp = ALogicalPlan::Slice { input, offset, len };
+                let local_predicates = acc_predicates.into_values().collect();
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            ALogicalPlan::TopK {
+                input,
+                by_column,
+                args,
+                k,
+            } => {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let lp = ALogicalPlan::TopK {
+                    input,
+                    by_column,
+                    args,
+                    k,
+                };
+                let local_predicates = acc_predicates.into_values().collect();
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            // Rows with the same subset values are kept or dropped together, so predicates on
+            // the subset are pushed through
+            ALogicalPlan::Distinct { input, options } => {
+                let local_predicates = extract_local_predicates(&mut acc_predicates, |predicate| {
+                    predicate_is_pushdown_boundary(predicate, expr_arena)
+                        || options.subset.as_ref().is_some_and(|subset| {
+                            aexpr_to_leaf_names_iter(predicate, expr_arena)
+                                .any(|name| !subset.iter().any(|s| **s == *name))
+                        })
+                });
+                self.pushdown_and_replace(input, acc_predicates, alp_arena, expr_arena);
+                let lp = ALogicalPlan::Distinct { input, options };
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            // The counts depend on all the rows, so predicates stay above
+            ALogicalPlan::ValueCounts {
+                input,
+                keys,
+                values,
+                sort,
+                normalize,
+                options,
+                schema,
+            } => {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let lp = ALogicalPlan::ValueCounts {
+                    input,
+                    keys,
+                    values,
+                    sort,
+                    normalize,
+                    options,
+                    schema,
+                };
+                let local_predicates = acc_predicates.into_values().collect();
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            // Filtering the stacked rows is filtering the rows of every input. The other unions
+            // add columns to the inputs, so predicates stay above them.
+            ALogicalPlan::Union {
+                inputs,
+                how,
+                schema,
+            } => {
+                let local_predicates = if how == ConcatHow::Vertical {
+                    extract_local_predicates(&mut acc_predicates, |predicate| {
+                        predicate_is_pushdown_boundary(predicate, expr_arena)
+                    })
+                } else {
+                    std::mem::take(&mut acc_predicates).into_values().collect()
+                };
+                for input in &inputs {
+                    self.pushdown_and_replace(
+                        *input,
+                        acc_predicates.clone(),
+                        alp_arena,
+                        expr_arena,
+                    );
+                }
+                let lp = ALogicalPlan::Union {
+                    inputs,
+                    how,
+                    schema,
+                };
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
+            // The projection may rename or aggregate columns, so predicates stay above it.
+            ALogicalPlan::Projection {
+                input,
+                expr,
+                schema,
+            } => {
+                self.pushdown_and_replace(input, HashMap::new(), alp_arena, expr_arena);
+                let lp = ALogicalPlan::Projection {
+                    input,
+                    expr,
+                    schema,
+                };
+                let local_predicates = acc_predicates.into_values().collect();
+                self.optional_wrap_selection(lp, local_predicates, alp_arena, expr_arena)
+            }
         }
     }
 
@@ -177,12 +378,14 @@ pub(super) fn insert_and_combine_predicate(
         .or_insert_with(|| predicate);
 }
 
-// Returns true if predicate cannot be pushed down
+// Returns true if predicate cannot be pushed down. Only predicates that give the same result
+// for a row on any subset of the rows can be, like `is_not_null`, but not `cum_sum`.
 pub fn predicate_is_pushdown_boundary(node: Node, expr_arena: &Arena<AExpr>) -> bool {
-    // TODO: Add code here once we have Agg
-    // let mut iter = expr_arena.iter(node);
-    // iter.all(|(_, expr)| !matches!(expr))
-    false
+    expr_arena.iter(node).any(|(_, expr)| match expr {
+        AExpr::Agg(_) | AExpr::Window { .. } => true,
+        AExpr::Function { function, .. } => !function.is_elementwise(),
+        _ => false,
+    })
 }
 
 pub fn extract_local_predicates<F>(
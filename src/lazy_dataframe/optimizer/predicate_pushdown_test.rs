@@ -4,8 +4,9 @@ use crate::{
     lazy_dataframe::{
         expr::col,
         lit::{self, lit},
+        logical_plan::LogicalPlan,
     },
-    series::Series,
+    series::{nulls::FillNullStrategy, Series},
 };
 
 #[test]
@@ -33,3 +34,61 @@ fn test_inner_join_pushdown() {
     let optimized_plan = out.get_optimized_plan();
     println!("Optimized Plan: {optimized_plan:?}")
 }
+
+#[test]
+fn test_sort_pushdown() {
+    let df = DataFrame::new(vec![Series::from_vec("points", &vec![3, 1, 2])]);
+
+    let optimized_plan = df
+        .lazy()
+        .sort(&["points"], &[false], false)
+        .filter(col("points").gt(lit(1)))
+        .get_optimized_plan();
+
+    match optimized_plan {
+        LogicalPlan::Sort { input, .. } => assert!(matches!(
+            *input,
+            LogicalPlan::DataFrameScan {
+                selection: Some(_),
+                ..
+            }
+        )),
+        plan => panic!("expected the sort at the top of the plan, got {plan:?}"),
+    }
+}
+
+#[test]
+fn test_drop_nulls_pushdown() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("points", &vec![Some(3), None, Some(1)]),
+        Series::from_slice_options("name", &vec![Some("a"), Some("b"), None]),
+    ]);
+    let lf = || df.clone().lazy().sort(&["points"], &[false], false);
+
+    match lf().drop_nulls(Some(&["points"])).get_optimized_plan() {
+        LogicalPlan::Sort { input, .. } => assert!(matches!(
+            *input,
+            LogicalPlan::DataFrameScan {
+                selection: Some(_),
+                ..
+            }
+        )),
+        plan => panic!("expected the sort at the top of the plan, got {plan:?}"),
+    }
+    let expected = DataFrame::new(vec![
+        Series::from_vec("points", &vec![1, 3]),
+        Series::from_slice_options("name", &vec![None, Some("a")]),
+    ]);
+    assert_eq!(lf().drop_nulls(Some(&["points"])).collect(), expected);
+    assert_eq!(lf().drop_nulls(None).collect(), df.drop_nulls(None));
+
+    // A forward fill depends on the rows before, so it is not pushed below the sort
+    let plan = lf()
+        .filter(
+            col("points")
+                .fill_null_with_strategy(FillNullStrategy::Forward)
+                .gt(lit(2)),
+        )
+        .get_optimized_plan();
+    assert!(matches!(plan, LogicalPlan::Selection { .. }), "{plan:?}");
+}
@@ -0,0 +1,186 @@
+use crate::lazy_dataframe::{
+    aexpr::AExpr,
+    alogical_plan::ALogicalPlan,
+    arena::{Arena, Node},
+};
+
+// Rows to take, as in ALogicalPlan::Slice
+#[derive(Clone, Copy, Debug)]
+struct SliceState {
+    offset: i64,
+    len: usize,
+}
+
+// Moves slices down to the scans, so that the nodes in between only see the rows they need.
+// Slices stop at nodes whose output rows depend on other input rows, like filters, joins and
+// groupbys.
+#[derive(Default)]
+pub struct SlicePushdown {}
+
+impl SlicePushdown {
+    pub fn new() -> Self {
+        SlicePushdown {}
+    }
+
+    pub fn optimize(
+        &self,
+        logical_plan: ALogicalPlan,
+        alp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> ALogicalPlan {
+        self.push_down(logical_plan, None, alp_arena, expr_arena)
+    }
+
+    fn push_down(
+        &self,
+        logical_plan: ALogicalPlan,
+        state: Option<SliceState>,
+        alp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> ALogicalPlan {
+        match (logical_plan, state) {
+            (ALogicalPlan::Slice { input, offset, len }, None) => {
+                let state = SliceState { offset, len };
+                self.push_down(alp_arena.take(input), Some(state), alp_arena, expr_arena)
+            }
+            (ALogicalPlan::Slice { input, offset, len }, Some(outer)) => {
+                match combine_slices(SliceState { offset, len }, outer) {
+                    Some(state) => {
+                        self.push_down(alp_arena.take(input), Some(state), alp_arena, expr_arena)
+                    }
+                    None => {
+                        let lp = ALogicalPlan::Slice { input, offset, len };
+                        let lp = self.push_down(lp, None, alp_arena, expr_arena);
+                        self.optional_wrap_slice(lp, Some(outer), alp_arena)
+                    }
+                }
+            }
+            (
+                ALogicalPlan::DataFrameScan {
+                    df,
+                    projection,
+                    selection,
+                    slice,
+                    schema,
+                },
+                Some(outer),
+            ) => {
+                // The scan takes its rows after its selection, so the slices don't commute
+                // with it either
+                let inner = slice.map(|(offset, len)| SliceState { offset, len });
+                let (slice, local) = match inner {
+                    None => (Some(outer), None),
+                    Some(inner) => match combine_slices(inner, outer) {
+                        Some(state) => (Some(state), None),
+                        None => (Some(inner), Some(outer)),
+                    },
+                };
+                let lp = ALogicalPlan::DataFrameScan {
+                    df,
+                    projection,
+                    selection,
+                    slice: slice.map(|state| (state.offset, state.len)),
+                    schema,
+                };
+                self.optional_wrap_slice(lp, local, alp_arena)
+            }
+            // Elementwise expressions give the same rows whether the slice is taken before or
+            // after them
+            (
+                ALogicalPlan::Projection {
+                    input,
+                    expr,
+                    schema,
+                },
+                Some(state),
+            ) if all_elementwise(&expr, expr_arena) => {
+                self.pushdown_and_replace(input, Some(state), alp_arena, expr_arena);
+                ALogicalPlan::Projection {
+                    input,
+                    expr,
+                    schema,
+                }
+            }
+            (
+                ALogicalPlan::HStack {
+                    input,
+                    exprs,
+                    schema,
+                },
+                Some(state),
+            ) if all_elementwise(&exprs, expr_arena) => {
+                self.pushdown_and_replace(input, Some(state), alp_arena, expr_arena);
+                ALogicalPlan::HStack {
+                    input,
+                    exprs,
+                    schema,
+                }
+            }
+            (lp, state) => self.no_pushdown_restart(lp, state, alp_arena, expr_arena),
+        }
+    }
+
+    // Keeps the slice above this node, and starts over below it
+    fn no_pushdown_restart(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<SliceState>,
+        alp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> ALogicalPlan {
+        for input in lp.inputs() {
+            self.pushdown_and_replace(input, None, alp_arena, expr_arena);
+        }
+        self.optional_wrap_slice(lp, state, alp_arena)
+    }
+
+    fn optional_wrap_slice(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<SliceState>,
+        alp_arena: &mut Arena<ALogicalPlan>,
+    ) -> ALogicalPlan {
+        match state {
+            Some(SliceState { offset, len }) => {
+                let input = alp_arena.add(lp);
+                ALogicalPlan::Slice { input, offset, len }
+            }
+            None => lp,
+        }
+    }
+
+    fn pushdown_and_replace(
+        &self,
+        node: Node,
+        state: Option<SliceState>,
+        alp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) {
+        let new_alp = self.push_down(alp_arena.take(node), state, alp_arena, expr_arena);
+        alp_arena.replace(node, new_alp);
+    }
+}
+
+// The `outer` slice of the `inner` slice as a single slice, if both count from the start
+fn combine_slices(inner: SliceState, outer: SliceState) -> Option<SliceState> {
+    if inner.offset < 0 || outer.offset < 0 {
+        return None;
+    }
+    Some(SliceState {
+        offset: inner.offset + outer.offset,
+        len: outer
+            .len
+            .min(inner.len.saturating_sub(outer.offset as usize)),
+    })
+}
+
+fn all_elementwise(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
+    exprs.iter().all(|node| {
+        expr_arena.iter(*node).all(|(_, expr)| {
+            matches!(
+                expr,
+                AExpr::Column(_) | AExpr::Literal(_) | AExpr::BinaryExpr { .. } | AExpr::Alias(..)
+            )
+        })
+    })
+}
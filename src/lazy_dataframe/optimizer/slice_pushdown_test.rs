@@ -0,0 +1,112 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::DataFrame,
+    lazy_dataframe::{expr::col, lit::lit, logical_plan::LogicalPlan},
+    series::Series,
+};
+
+#[cfg(test)]
+fn example_df() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "c", "b"]),
+        Series::from_vec("points", &vec![1, 2, 3, 4, 5]),
+    ])
+}
+
+#[test]
+fn test_limit_pushdown_through_projection() {
+    let lf = || {
+        example_df()
+            .lazy()
+            .select(vec![col("name"), (col("points") * lit(2)).alias("double")])
+            .limit(2)
+    };
+
+    match lf().get_optimized_plan() {
+        LogicalPlan::Projection { input, .. } => assert!(matches!(
+            *input,
+            LogicalPlan::DataFrameScan {
+                slice: Some((0, 2)),
+                ..
+            }
+        )),
+        plan => panic!("expected the projection at the top of the plan, got {plan:?}"),
+    }
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("double", &vec![2, 4]),
+    ]);
+    assert_eq!(&lf().collect(), &expected_df);
+}
+
+#[test]
+fn test_slice_applies_after_scan_selection() {
+    let computed_df = example_df()
+        .lazy()
+        .filter(col("points").gt(lit(1)))
+        .slice(1, 2)
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "c"]),
+        Series::from_vec("points", &vec![3, 4]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_slice_stops_at_groupby_and_cumulative_expressions() {
+    let optimized_plan = example_df()
+        .lazy()
+        .groupby(vec![col("name")])
+        .maintain_order(true)
+        .agg(vec![col("points").sum()])
+        .head(2)
+        .get_optimized_plan();
+    match optimized_plan {
+        LogicalPlan::Slice { input, .. } => {
+            assert!(matches!(*input, LogicalPlan::GroupBy { .. }))
+        }
+        plan => panic!("expected the slice at the top of the plan, got {plan:?}"),
+    }
+
+    let computed_df = example_df()
+        .lazy()
+        .select(vec![col("points").cum_sum()])
+        .tail(2)
+        .collect();
+    let expected_df = DataFrame::new(vec![Series::from_vec("points", &vec![10, 15])]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_nested_slices() {
+    let optimized_plan = example_df().lazy().slice(1, 3).head(2).get_optimized_plan();
+    assert!(matches!(
+        optimized_plan,
+        LogicalPlan::DataFrameScan {
+            slice: Some((1, 2)),
+            ..
+        }
+    ));
+
+    let computed_df = example_df().lazy().head(4).tail(2).collect();
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "c"]),
+        Series::from_vec("points", &vec![3, 4]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+
+    let computed_df = example_df().lazy().slice(-2, 5).slice(3, 1).collect();
+    assert_eq!(computed_df.rows_count(), 0);
+}
+
+#[test]
+fn test_eager_head_and_tail() {
+    let df = example_df();
+    assert_eq!(df.head(2), df.slice(0, 2));
+    assert_eq!(df.tail(2), df.slice(3, 2));
+    assert_eq!(df.tail(10), df);
+    assert_eq!(df.head(0).rows_count(), 0);
+}
@@ -0,0 +1,46 @@
+use crate::lazy_dataframe::{
+    alogical_plan::ALogicalPlan,
+    arena::{Arena, Node},
+};
+
+// Replaces a sort followed by a slice from the start with a top k, which doesn't sort all
+// the rows
+#[derive(Default)]
+pub struct TopKRewrite {}
+
+impl TopKRewrite {
+    pub fn new() -> Self {
+        TopKRewrite {}
+    }
+
+    pub fn optimize(&self, node: Node, alp_arena: &mut Arena<ALogicalPlan>) {
+        for input in alp_arena.get(node).inputs() {
+            self.optimize(input, alp_arena);
+        }
+        let ALogicalPlan::Slice {
+            input,
+            offset: 0,
+            len,
+        } = *alp_arena.get(node)
+        else {
+            return;
+        };
+        if let ALogicalPlan::Sort { .. } = alp_arena.get(input) {
+            let ALogicalPlan::Sort {
+                input,
+                by_column,
+                args,
+            } = alp_arena.take(input)
+            else {
+                unreachable!()
+            };
+            let top_k = ALogicalPlan::TopK {
+                input,
+                by_column,
+                args,
+                k: len,
+            };
+            alp_arena.replace(node, top_k);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::DataFrame,
+    lazy_dataframe::{expr::col, lit::lit, logical_plan::LogicalPlan},
+    series::Series,
+};
+
+#[test]
+fn test_sort_limit_becomes_top_k() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c", "d", "e"]),
+        Series::from_slice_options("points", &vec![Some(3), None, Some(9), Some(1), Some(5)]),
+    ]);
+
+    let optimized_plan = df
+        .clone()
+        .lazy()
+        .sort(&["points"], &[true], true)
+        .limit(2)
+        .get_optimized_plan();
+    assert!(matches!(optimized_plan, LogicalPlan::TopK { k: 2, .. }));
+
+    let computed_df = df
+        .clone()
+        .lazy()
+        .sort(&["points"], &[true], true)
+        .limit(2)
+        .collect();
+    let expected_df = df.sort(&["points"], &[true], true).slice(0, 2);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_filter_stays_above_top_k() {
+    let df = DataFrame::new(vec![Series::from_vec("points", &vec![3, 8, 1, 5])]);
+
+    let lf = || {
+        df.clone()
+            .lazy()
+            .sort(&["points"], &[false], false)
+            .limit(3)
+            .filter(col("points").gt(lit(2)))
+    };
+
+    match lf().get_optimized_plan() {
+        LogicalPlan::Selection { input, .. } => {
+            assert!(matches!(*input, LogicalPlan::TopK { k: 3, .. }))
+        }
+        plan => panic!("expected the filter at the top of the plan, got {plan:?}"),
+    }
+
+    let expected_df = DataFrame::new(vec![Series::from_vec("points", &vec![3, 5])]);
+    assert_eq!(&lf().collect(), &expected_df);
+}
+
+#[test]
+fn test_limit_without_sort() {
+    let df = DataFrame::new(vec![Series::from_vec("points", &vec![3, 8, 1])]);
+
+    let optimized_plan = df.clone().lazy().limit(5).get_optimized_plan();
+    assert!(matches!(
+        optimized_plan,
+        LogicalPlan::DataFrameScan {
+            slice: Some((0, 5)),
+            ..
+        }
+    ));
+
+    assert_eq!(&df.clone().lazy().limit(5).collect(), &df);
+    assert_eq!(df.lazy().limit(0).collect().rows_count(), 0);
+}
+
+#[test]
+fn test_sort_select_limit_becomes_top_k() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c", "d", "e"]),
+        Series::from_slice_options("points", &vec![Some(3), None, Some(9), Some(1), Some(5)]),
+    ]);
+
+    let lf = || {
+        df.clone()
+            .lazy()
+            .sort(&["points"], &[true], true)
+            .select(vec![col("name")])
+            .limit(2)
+    };
+
+    match lf().get_optimized_plan() {
+        LogicalPlan::Projection { input, .. } => {
+            assert!(matches!(*input, LogicalPlan::TopK { k: 2, .. }))
+        }
+        plan => panic!("expected the projection at the top of the plan, got {plan:?}"),
+    }
+
+    let expected_df = DataFrame::new(vec![Series::from_vec("name", &vec!["c", "e"])]);
+    assert_eq!(&lf().collect(), &expected_df);
+}
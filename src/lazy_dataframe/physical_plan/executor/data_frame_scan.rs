@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::{dataframe::DataFrame, lazy_dataframe::physical_plan::physical_expr::PhysicalExpr};
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+};
 
 use super::Executor;
 
@@ -8,6 +11,7 @@ pub struct DataFrameScanExec {
     df: Arc<DataFrame>,
     projection: Option<Arc<Vec<String>>>,
     selection: Option<Arc<dyn PhysicalExpr>>,
+    slice: Option<(i64, usize)>,
 }
 
 impl DataFrameScanExec {
@@ -15,11 +19,13 @@ impl DataFrameScanExec {
         df: Arc<DataFrame>,
         projection: Option<Arc<Vec<String>>>,
         selection: Option<Arc<dyn PhysicalExpr>>,
+        slice: Option<(i64, usize)>,
     ) -> Self {
         DataFrameScanExec {
             df,
             projection,
             selection,
+            slice,
         }
     }
 }
@@ -29,14 +35,25 @@ impl Executor for DataFrameScanExec {
         let df = std::mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
+        // Without a selection, the rows can be taken before anything else
+        if let (Some((offset, len)), None) = (self.slice, &self.selection) {
+            df = df.slice_clamped(offset, len);
+        }
+
         if let Some(projection) = &self.projection {
             df = df.select(projection.iter())
         }
 
-        let pred = self.selection.as_ref().map(|s| s.evaluate(&df));
+        let pred = self
+            .selection
+            .as_ref()
+            .map(|s| s.evaluate(&df, &ExecutionState::new()));
 
         if let Some(pred) = pred {
             df = df.filter(pred.bool());
+            if let Some((offset, len)) = self.slice {
+                df = df.slice_clamped(offset, len);
+            }
         };
         df
     }
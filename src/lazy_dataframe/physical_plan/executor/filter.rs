@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::{dataframe::DataFrame, lazy_dataframe::physical_plan::physical_expr::PhysicalExpr};
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+};
 
 use super::Executor;
 
@@ -18,7 +21,8 @@ impl FilterExec {
 impl Executor for FilterExec {
     fn execute(&mut self) -> DataFrame {
         let df = self.input.execute();
-        let predicate = self.predicate.evaluate(&df);
+        let state = ExecutionState::new();
+        let predicate = self.predicate.evaluate(&df, &state);
         df.filter(predicate.bool())
     }
 }
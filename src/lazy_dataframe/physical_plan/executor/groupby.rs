@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use crate::{
-    dataframe::DataFrame, lazy_dataframe::physical_plan::physical_expr::PhysicalExpr,
+    dataframe::DataFrame,
+    lazy_dataframe::{
+        logical_plan::{DataFrameUdf, GroupByOptions},
+        physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    },
     series::Series,
 };
 
@@ -11,34 +15,38 @@ pub struct GroupByExec {
     pub input: Box<dyn Executor>,
     pub keys: Vec<Arc<dyn PhysicalExpr>>,
     pub agg: Vec<Arc<dyn PhysicalExpr>>,
+    pub apply: Option<Arc<dyn DataFrameUdf>>,
+    pub options: GroupByOptions,
 }
 
 impl Executor for GroupByExec {
     fn execute(&mut self) -> DataFrame {
         let df = self.input.execute();
+        let state = ExecutionState::new();
         let by = self
             .keys
             .iter()
-            .map(|expr| expr.evaluate(&df))
+            .map(|expr| expr.evaluate(&df, &state))
             .collect::<Vec<Series>>();
-        let group_proxy = df.compute_group_proxy(by.clone());
+        let mut group_proxy = df.compute_group_proxy(by.clone(), self.options.maintain_order);
+        if self.options.sort {
+            group_proxy.sort_by_keys(&by);
+        }
+        if let Some(apply) = &self.apply {
+            return df.apply_groups(&group_proxy, &|df| apply.call_udf(df));
+        }
 
         let mut columns_selected = by
             .iter()
-            .map(|col| {
-                col.take_indices(
-                    &group_proxy
-                        .first
-                        .iter()
-                        .map(|idx| *idx as usize)
-                        .collect::<Vec<usize>>(),
-                )
-            })
+            .map(|col| col.take_opt_indices(&group_proxy.first_indices()))
             .collect::<Vec<Series>>();
         let columns_aggregated = self
             .agg
             .iter()
-            .map(|expr| expr.evaluate_for_groups(&df, &group_proxy))
+            .map(|expr| {
+                expr.evaluate_for_groups(&df, &group_proxy, &state)
+                    .aggregated()
+            })
             .collect::<Vec<Series>>();
         columns_selected.extend(columns_aggregated);
         DataFrame::new(columns_selected)
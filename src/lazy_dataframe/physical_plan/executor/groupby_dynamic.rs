@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::{groupby::dynamic::DynamicGroupOptions, DataFrame},
+    lazy_dataframe::physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    series::Series,
+};
+
+use super::Executor;
+
+pub struct GroupByDynamicExec {
+    pub input: Box<dyn Executor>,
+    pub keys: Vec<Arc<dyn PhysicalExpr>>,
+    pub agg: Vec<Arc<dyn PhysicalExpr>>,
+    pub options: DynamicGroupOptions,
+}
+
+impl Executor for GroupByDynamicExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        let state = ExecutionState::new();
+        let by = self
+            .keys
+            .iter()
+            .map(|expr| expr.evaluate(&df, &state))
+            .collect::<Vec<Series>>();
+        let (mut columns, group_proxy) = df.groupby_dynamic(by, &self.options);
+
+        let columns_aggregated = self
+            .agg
+            .iter()
+            .map(|expr| {
+                expr.evaluate_for_groups(&df, &group_proxy, &state)
+                    .aggregated()
+            })
+            .collect::<Vec<Series>>();
+        columns.extend(columns_aggregated);
+        DataFrame::new(columns)
+    }
+}
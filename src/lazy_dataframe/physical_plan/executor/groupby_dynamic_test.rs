@@ -0,0 +1,225 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::{groupby::dynamic::DynamicGroupOptions, DataFrame},
+    lazy_dataframe::{expr::col, lit::lit, when::when},
+    series::Series,
+};
+
+#[test]
+fn test_groupby_dynamic_agg() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "a", "b", "a", "b"]),
+        Series::from_vec("minute", &vec![0, 3, 4, 6, 12]),
+        Series::from_vec("clicks", &vec![1, 2, 3, 4, 5]),
+    ]);
+
+    let lf = df
+        .clone()
+        .lazy()
+        .groupby_dynamic(vec![col("user")], DynamicGroupOptions::new("minute", "5i"))
+        .agg(vec![col("clicks").sum()]);
+    let schema = lf.get_optimized_plan().schema();
+    assert_eq!(
+        schema
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["user", "_lower_boundary", "_upper_boundary", "clicks"]
+    );
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "a"]),
+        Series::from_vec("_lower_boundary", &vec![0, 5]),
+        Series::from_vec("_upper_boundary", &vec![5, 10]),
+        Series::from_vec("clicks", &vec![3, 4]),
+    ]);
+    let computed_df = df
+        .lazy()
+        .groupby_dynamic(vec![col("user")], DynamicGroupOptions::new("minute", "5i"))
+        .agg(vec![col("clicks").sum()])
+        .filter(col("user").eq(lit("a")))
+        .collect();
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+#[should_panic(expected = "more than one column named \"_lower_boundary\"")]
+fn test_groupby_dynamic_boundary_name_taken() {
+    clicks_per_minute()
+        .lazy()
+        .groupby_dynamic(vec![col("user")], DynamicGroupOptions::new("minute", "5i"))
+        .agg(vec![col("minute").min().alias("_lower_boundary")]);
+}
+
+#[test]
+#[should_panic(expected = "more than one column named \"clicks\"")]
+fn test_groupby_dynamic_duplicate_output_names() {
+    clicks_per_minute()
+        .lazy()
+        .groupby_dynamic(vec![col("user")], DynamicGroupOptions::new("minute", "5i"))
+        .agg(vec![col("clicks").min(), col("clicks").max()]);
+}
+
+#[cfg(test)]
+fn clicks_per_minute() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a"; 8]),
+        Series::from_vec("minute", &vec![0, 1, 2, 3, 4, 5, 6, 7]),
+        Series::from_vec("clicks", &vec![1, 2, 3, 4, 5, 6, 7, 8]),
+    ])
+}
+
+#[test]
+fn test_groupby_dynamic_agg_of_expressions_with_gaps() {
+    // Windows of 2 every 5 skip the rows in between
+    let computed_df = clicks_per_minute()
+        .lazy()
+        .groupby_dynamic(
+            vec![col("user")],
+            DynamicGroupOptions::new("minute", "5i").with_period("2i"),
+        )
+        .agg(vec![
+            (col("clicks") * lit(2)).sum().alias("double"),
+            lit(1).sum().alias("rows"),
+            (col("clicks") - col("clicks").min())
+                .sum()
+                .alias("above_min"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "a"]),
+        Series::from_vec("_lower_boundary", &vec![0, 5]),
+        Series::from_vec("_upper_boundary", &vec![2, 7]),
+        Series::from_vec("double", &vec![6, 26]),
+        Series::from_vec("rows", &vec![2, 2]),
+        Series::from_vec("above_min", &vec![1, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_dynamic_agg_of_expressions_with_overlap() {
+    // Windows of 6 every 2 share rows
+    let computed_df = clicks_per_minute()
+        .lazy()
+        .groupby_dynamic(
+            vec![col("user")],
+            DynamicGroupOptions::new("minute", "2i").with_period("6i"),
+        )
+        .agg(vec![
+            (col("clicks") * lit(2)).sum().alias("double"),
+            lit(1).sum().alias("rows"),
+            (col("clicks") - col("clicks").min())
+                .sum()
+                .alias("above_min"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a"; 6]),
+        Series::from_vec("_lower_boundary", &vec![-4, -2, 0, 2, 4, 6]),
+        Series::from_vec("_upper_boundary", &vec![2, 4, 6, 8, 10, 12]),
+        Series::from_vec("double", &vec![6, 20, 42, 66, 52, 30]),
+        Series::from_vec("rows", &vec![2, 4, 6, 6, 4, 2]),
+        Series::from_vec("above_min", &vec![1, 6, 15, 15, 6, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_dynamic_agg_of_filter_and_fill_null() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a"; 8]),
+        Series::from_vec("minute", &vec![0, 1, 2, 3, 4, 5, 6, 7]),
+        Series::from_slice_options(
+            "clicks",
+            &vec![
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                None,
+                Some(8),
+            ],
+        ),
+    ]);
+    let aggs = vec![
+        col("clicks")
+            .filter(col("clicks").gt(lit(2)))
+            .sum()
+            .alias("big"),
+        col("clicks")
+            .filter(col("clicks").gt(col("clicks").mean()))
+            .count()
+            .alias("above_mean"),
+        col("clicks")
+            .fill_null(col("clicks").max())
+            .sum()
+            .alias("filled"),
+    ];
+
+    let groupby = |every: &str, period: &str| {
+        df.clone()
+            .lazy()
+            .groupby_dynamic(
+                vec![col("user")],
+                DynamicGroupOptions::new("minute", every).with_period(period),
+            )
+            .agg(aggs.clone())
+            .collect()
+    };
+
+    // Windows with gaps
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a"; 2]),
+        Series::from_vec("_lower_boundary", &vec![0, 5]),
+        Series::from_vec("_upper_boundary", &vec![2, 7]),
+        Series::from_vec("big", &vec![0, 6]),
+        Series::from_vec("above_mean", &vec![0, 0]),
+        Series::from_vec("filled", &vec![2, 12]),
+    ]);
+    assert_eq!(&groupby("5i", "2i"), &expected_df);
+
+    // Overlapping windows
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a"; 6]),
+        Series::from_vec("_lower_boundary", &vec![-4, -2, 0, 2, 4, 6]),
+        Series::from_vec("_upper_boundary", &vec![2, 4, 6, 8, 10, 12]),
+        Series::from_vec("big", &vec![0, 7, 18, 26, 19, 8]),
+        Series::from_vec("above_mean", &vec![0, 2, 3, 2, 1, 0]),
+        Series::from_vec("filled", &vec![2, 12, 25, 34, 27, 16]),
+    ]);
+    assert_eq!(&groupby("2i", "6i"), &expected_df);
+}
+
+#[test]
+fn test_groupby_dynamic_when_then_otherwise() {
+    let when_above_mean = |then: i32| {
+        when(col("clicks").gt(col("clicks").mean()))
+            .then(lit(then))
+            .otherwise(col("clicks"))
+    };
+    let computed_df = clicks_per_minute()
+        .lazy()
+        .groupby_dynamic(
+            vec![col("user")],
+            DynamicGroupOptions::new("minute", "2i").with_period("6i"),
+        )
+        .agg(vec![
+            when_above_mean(0).sum().alias("clicks"),
+            when_above_mean(1).max().alias("max"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a"; 6]),
+        Series::from_vec("_lower_boundary", &vec![-4, -2, 0, 2, 4, 6]),
+        Series::from_vec("_upper_boundary", &vec![2, 4, 6, 8, 10, 12]),
+        Series::from_vec("clicks", &vec![1, 3, 6, 12, 11, 7]),
+        Series::from_vec("max", &vec![1, 2, 3, 5, 6, 7]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
@@ -1,8 +1,14 @@
 use crate::{
-    chunked_array::builder::NewFrom,
+    chunked_array::{aggregate::QuantileInterpolOptions, builder::NewFrom, IsSorted},
+    core::schema::Schema,
     dataframe::DataFrame,
-    lazy_dataframe::expr::{col, AggExpr, Expr},
-    series::Series,
+    lazy_dataframe::{
+        expr::{col, AggExpr, Expr},
+        lit::lit,
+        when::when,
+    },
+    series::{nulls::FillNullStrategy, Series},
+    types::DataType,
 };
 
 #[test]
@@ -15,13 +21,383 @@ fn test_simple_groupby_agg() {
     let computed_df = df
         .lazy()
         .groupby(vec![col("name")])
+        .maintain_order(true)
         .agg(vec![col("points").min()])
         .collect();
-    println!("Groupby: {computed_df:?}");
 
-    // let expected_df = DataFrame::new(vec![
-    //     Series::from_vec("name", &vec!["a", "c", "b"]),
-    //     Series::from_vec("points", &vec![1, 0, 2]),
-    // ])
-    // assert_eq!(&computed_df, &expected_df);
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_vec("points", &vec![1, 2, 0]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_maintain_order_and_sort() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["c", "a", "b", "a", "c", "d", "b"]),
+        Series::from_vec("points", &vec![1, 2, 3, 4, 5, 6, 7]),
+    ]);
+
+    let computed_df = df
+        .clone()
+        .lazy()
+        .groupby(vec![col("name")])
+        .maintain_order(true)
+        .agg(vec![col("points").sum()])
+        .collect();
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["c", "a", "b", "d"]),
+        Series::from_vec("points", &vec![6, 6, 10, 6]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .sort(true)
+        .agg(vec![col("points").sum()])
+        .collect();
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c", "d"]),
+        Series::from_vec("points", &vec![6, 10, 6, 6]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+// Group order isn't deterministic, so compare the rows after sorting them
+fn sorted_rows(df: &DataFrame) -> Vec<String> {
+    let mut rows = (0..df.rows_count())
+        .map(|idx| {
+            df.columns
+                .iter()
+                .map(|s| format!("{:?}", s.get(idx)))
+                .collect::<Vec<String>>()
+                .join(",")
+        })
+        .collect::<Vec<String>>();
+    rows.sort();
+    rows
+}
+
+#[test]
+fn test_groupby_multiple_aggs() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "c", "c"]),
+        Series::from_slice_options(
+            "points",
+            &vec![Some(1), Some(2), Some(3), None, Some(1), Some(0)],
+        ),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![
+            col("points").sum().alias("sum"),
+            col("points").max().alias("max"),
+            col("points").mean().alias("mean"),
+            col("points").count().alias("count"),
+            col("points").last().alias("last"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_vec("sum", &vec![4, 2, 1]),
+        Series::from_vec("max", &vec![3, 2, 1]),
+        Series::from_vec("mean", &vec![2.0, 2.0, 0.5]),
+        Series::from_vec("count", &vec![2, 1, 2]),
+        Series::from_slice_options("last", &vec![Some(3), None, Some(0)]),
+    ]);
+    assert_eq!(sorted_rows(&computed_df), sorted_rows(&expected_df));
+}
+
+#[test]
+fn test_groupby_statistics() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "c", "c"]),
+        Series::from_vec("points", &vec![1, 2, 3, 2, 1, 0]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![
+            col("points").median().alias("median"),
+            col("points")
+                .quantile(0.5, QuantileInterpolOptions::Lower)
+                .alias("lower"),
+            col("points").var(1).alias("var"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_vec("median", &vec![2.0, 2.0, 0.5]),
+        Series::from_vec("lower", &vec![1.0, 2.0, 0.0]),
+        Series::from_vec("var", &vec![2.0, 0.0, 0.5]),
+    ]);
+    assert_eq!(sorted_rows(&computed_df), sorted_rows(&expected_df));
+}
+
+#[test]
+fn test_groupby_agg_of_expressions() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "c"]),
+        Series::from_vec("price", &vec![2, 3, 4, 1, 5]),
+        Series::from_vec("quantity", &vec![1, 2, 3, 0, 2]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![
+            (col("price") * col("quantity")).sum().alias("total"),
+            (col("price").max() - col("price").min()).alias("range"),
+            (col("price") + lit(1)).min(),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_vec("total", &vec![14, 6, 10]),
+        Series::from_vec("range", &vec![2, 2, 0]),
+        Series::from_vec("price", &vec![3, 2, 6]),
+    ]);
+    assert_eq!(sorted_rows(&computed_df), sorted_rows(&expected_df));
+}
+
+#[test]
+#[should_panic(expected = "more than one column named \"points\"")]
+fn test_groupby_duplicate_output_names() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("points", &vec![1, 2]),
+    ]);
+    df.lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![col("points").min(), col("points").max()]);
+}
+
+#[test]
+fn test_groupby_agg_of_literals() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "a"]),
+        Series::from_vec("price", &vec![2, 3, 4, 1, 6]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![
+            lit(1).sum().alias("rows"),
+            (lit(2) * lit(3)).alias("six"),
+            (col("price") - col("price").min()).sum(),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("rows", &vec![3, 2]),
+        Series::from_vec("six", &vec![6, 6]),
+        Series::from_vec("price", &vec![6, 2]),
+    ]);
+    assert_eq!(sorted_rows(&computed_df), sorted_rows(&expected_df));
+}
+
+#[test]
+fn test_groupby_agg_of_filter() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "c", "a"]),
+        Series::from_vec("x", &vec![5, 3, 2, 7, 4, 9]),
+        Series::from_vec("y", &vec![1, 1, 1, 0, 0, 0]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![
+            col("x").filter(col("y").eq(lit(1))).min().alias("min"),
+            col("x").filter(col("y").eq(lit(1))).count().alias("count"),
+            col("x").filter(col("y").eq(lit(0))).first().alias("first"),
+            (col("x").filter(col("y").eq(lit(1))) * lit(2))
+                .sum()
+                .alias("double"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_slice_options("min", &vec![Some(2), Some(3), None]),
+        Series::from_vec("count", &vec![2, 1, 0]),
+        Series::from_vec("first", &vec![9, 7, 4]),
+        Series::from_vec("double", &vec![14, 6, 0]),
+    ]);
+    assert_eq!(sorted_rows(&computed_df), sorted_rows(&expected_df));
+}
+
+#[test]
+fn test_groupby_sorted_key() {
+    let mut name = Series::from_vec("name", &vec!["a", "a", "b", "c", "c", "c"]);
+    name.set_sorted_flag(IsSorted::Ascending);
+    let df = DataFrame::new(vec![
+        name,
+        Series::from_vec("points", &vec![1, 2, 3, 2, 1, 0]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .agg(vec![
+            col("points").sum(),
+            col("points")
+                .filter(col("points").eq(lit(1)))
+                .count()
+                .alias("ones"),
+        ])
+        .collect();
+
+    // Groups of a sorted key keep the order of the key
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c"]),
+        Series::from_vec("points", &vec![3, 3, 3]),
+        Series::from_vec("ones", &vec![1, 0, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_apply() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "c", "c"]),
+        Series::from_vec("points", &vec![1, 2, 3, 2, 1, 0]),
+    ]);
+    let mut schema = Schema::new();
+    schema.with_column("name".into(), DataType::Utf8);
+    schema.with_column("score".into(), DataType::Int32);
+
+    // Scores every group by its largest minus its smallest value
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .maintain_order(true)
+        .apply(
+            |group_df| {
+                let points = group_df.column("points");
+                let score = points.i32().max().unwrap() - points.i32().min().unwrap();
+                DataFrame::new(vec![
+                    group_df.column("name").slice(0, 1),
+                    Series::from_vec("score", &vec![score]),
+                ])
+            },
+            schema,
+        )
+        .filter(col("score").eq(lit(0)))
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b"]),
+        Series::from_vec("score", &vec![0]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_len_and_count() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "a", "b"]),
+        Series::from_slice_options("points", &vec![Some(1), None, None, Some(4), Some(2)]),
+    ]);
+
+    let computed_df = df
+        .clone()
+        .lazy()
+        .groupby(vec![col("name")])
+        .maintain_order(true)
+        .agg(vec![
+            col("points").len().alias("len"),
+            col("points").count().alias("count"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("len", &vec![3, 2]),
+        Series::from_vec("count", &vec![2, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+
+    // Every row gets the size of its group
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("name"),
+            col("points").len().over(vec![col("name")]),
+        ])
+        .collect();
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "a", "b"]),
+        Series::from_vec("points", &vec![3, 2, 3, 3, 2]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_fill_null() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "a"]),
+        Series::from_slice_options("points", &vec![Some(1), None, None, Some(4), Some(3)]),
+    ]);
+
+    // The mean of every group fills the nulls of the group
+    let computed_df = df
+        .clone()
+        .lazy()
+        .groupby(vec![col("name")])
+        .maintain_order(true)
+        .agg(vec![col("points").fill_null(col("points").mean()).sum()])
+        .collect();
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("points", &vec![6.0, 8.0]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+
+    // The values don't leak into other groups
+    let computed_df = df
+        .lazy()
+        .select(vec![col("points")
+            .fill_null_with_strategy(FillNullStrategy::Forward)
+            .over(vec![col("name")])])
+        .collect();
+    let expected_df = DataFrame::new(vec![Series::from_slice_options(
+        "points",
+        &vec![Some(1), None, Some(1), Some(4), Some(3)],
+    )]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_when_then_otherwise() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "b", "a"]),
+        Series::from_vec("points", &vec![1, 2, 3, 4, 5]),
+    ]);
+
+    // Sums the points above the mean of their group
+    let computed_df = df
+        .lazy()
+        .groupby(vec![col("name")])
+        .maintain_order(true)
+        .agg(vec![when(col("points").gt(col("points").mean()))
+            .then(col("points"))
+            .otherwise(lit(0))
+            .sum()])
+        .collect();
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_vec("points", &vec![5, 4]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
 }
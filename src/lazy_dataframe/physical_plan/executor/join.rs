@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::{
     dataframe::{join::JoinType, DataFrame},
-    lazy_dataframe::physical_plan::physical_expr::PhysicalExpr,
+    lazy_dataframe::physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
     series::Series,
 };
 
@@ -41,12 +41,12 @@ impl Executor for JoinExec {
         let left_on = self
             .left_on
             .iter()
-            .map(|expr| expr.evaluate(&left_df))
+            .map(|expr| expr.evaluate(&left_df, &ExecutionState::new()))
             .collect::<Vec<Series>>();
         let right_on = self
             .right_on
             .iter()
-            .map(|expr| expr.evaluate(&right_df))
+            .map(|expr| expr.evaluate(&right_df, &ExecutionState::new()))
             .collect::<Vec<Series>>();
 
         match self.join_type {
@@ -5,8 +5,24 @@ pub mod filter;
 mod filter_test;
 pub mod groupby;
 mod groupby_test;
+pub mod groupby_dynamic;
+mod groupby_dynamic_test;
 pub mod join;
 mod join_test;
+pub mod projection;
+mod projection_test;
+pub mod slice;
+pub mod sort;
+mod sort_test;
+pub mod stack;
+mod stack_test;
+pub mod top_k;
+pub mod union;
+mod union_test;
+pub mod unique;
+mod unique_test;
+pub mod value_counts;
+mod value_counts_test;
 
 // TODO: Why is PhysicalExpr Sync + Send but Executor is just Send...?
 pub trait Executor: Send {
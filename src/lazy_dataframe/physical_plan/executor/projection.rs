@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    series::Series,
+};
+
+use super::Executor;
+
+pub struct ProjectionExec {
+    pub input: Box<dyn Executor>,
+    pub expr: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl Executor for ProjectionExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        let state = ExecutionState::new();
+        let columns = self
+            .expr
+            .iter()
+            .map(|expr| expr.evaluate(&df, &state))
+            .collect::<Vec<Series>>();
+
+        // Aggregations return a single value, which is broadcast to the height of the other columns
+        let height = columns.iter().map(|s| s.len()).max().unwrap_or(0);
+        let columns = columns
+            .into_iter()
+            .map(|s| {
+                if s.len() == 1 && height > 1 {
+                    s.take_indices(&vec![0; height])
+                } else {
+                    s
+                }
+            })
+            .collect::<Vec<Series>>();
+        DataFrame::new_no_checks(columns)
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    chunked_array::{aggregate::QuantileInterpolOptions, builder::NewFrom},
+    dataframe::DataFrame,
+    lazy_dataframe::{
+        expr::{coalesce, col},
+        lit::lit,
+        when::when,
+    },
+    series::{nulls::FillNullStrategy, Series},
+};
+
+#[test]
+fn test_select_statistics() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("points", &vec![4, 1, 3, 2]),
+        Series::from_vec("score", &vec![0.5, 1.5, 2.5, 3.5]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("points").median(),
+            col("points").quantile(0.25, QuantileInterpolOptions::Nearest),
+            col("points").var(0),
+            col("score").std(0),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new_no_checks(vec![
+        Series::from_vec("points", &vec![2.5]),
+        Series::from_vec("points", &vec![2.0]),
+        Series::from_vec("points", &vec![1.25]),
+        Series::from_vec("score", &vec![1.25f64.sqrt()]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_aggregations() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![Some(3), None, Some(1), Some(3)]),
+        Series::from_vec("b", &vec![1.5, 2.5, 0.5, 1.5]),
+        Series::from_vec("c", &vec!["x", "z", "y", "x"]),
+        Series::from_vec("d", &vec![true, false, true, true]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("a").min(),
+            col("b").sum(),
+            col("c").max(),
+            col("d").sum(),
+            col("a").mean(),
+            col("a").count(),
+            col("c").n_unique(),
+            col("c").first(),
+            col("a").last(),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new_no_checks(vec![
+        Series::from_vec("a", &vec![1]),
+        Series::from_vec("b", &vec![6.0]),
+        Series::from_vec("c", &vec!["z"]),
+        Series::from_vec("d", &vec![3]),
+        Series::from_slice_options("a", &vec![Some(7.0 / 3.0)]),
+        Series::from_vec("a", &vec![3]),
+        Series::from_vec("c", &vec![3]),
+        Series::from_vec("c", &vec!["x"]),
+        Series::from_vec("a", &vec![3]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_aggregations_of_empty_frame() {
+    let df = DataFrame::new(vec![Series::from_vec("a", &Vec::<i32>::new())]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("a").first().alias("first"),
+            col("a").last().alias("last"),
+            col("a").sum().alias("sum"),
+            col("a").min().alias("min"),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_slice_options("first", &vec![None::<i32>]),
+        Series::from_slice_options("last", &vec![None::<i32>]),
+        Series::from_vec("sum", &vec![0]),
+        Series::from_slice_options("min", &vec![None::<i32>]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_broadcasts_aggregations() {
+    let df = DataFrame::new(vec![Series::from_vec("a", &vec![1, 2, 3])]);
+
+    let computed_df = df.lazy().select(vec![col("a"), col("a").max()]).collect();
+
+    let expected_df = DataFrame::new_no_checks(vec![
+        Series::from_vec("a", &vec![1, 2, 3]),
+        Series::from_vec("a", &vec![3, 3, 3]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_expressions() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("x", &vec![1, 2, 3, 4]),
+        Series::from_vec("y", &vec![0.5, 1.0, 1.5, 2.0]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("x") * col("y"),
+            col("x")
+                .filter(col("x").eq(lit(2)).or(col("x").eq(lit(4))))
+                .sum(),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new_no_checks(vec![
+        Series::from_vec("x", &vec![0.5, 2.0, 4.5, 8.0]),
+        Series::from_vec("x", &vec![6, 6, 6, 6]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_null_handling() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("a", &vec![None, Some(2), None]),
+        Series::from_slice_options("b", &vec![Some(1.5), None, None]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("a").is_null().alias("is_null"),
+            col("a").fill_null(lit(0)).alias("filled"),
+            col("a")
+                .fill_null_with_strategy(FillNullStrategy::Forward)
+                .alias("forward"),
+            coalesce(vec![col("a"), col("b"), lit(-1)]),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("is_null", &vec![true, false, true]),
+        Series::from_vec("filled", &vec![0, 2, 0]),
+        Series::from_slice_options("forward", &vec![None, Some(2), Some(2)]),
+        Series::from_vec("a", &vec![1.5, 2.0, -1.0]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_null_handling_of_empty_frame() {
+    let df = DataFrame::new(vec![Series::from_vec("a", &Vec::<i32>::new())]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            col("a").fill_null(lit(0)).alias("filled"),
+            coalesce(vec![col("a"), lit(-1)]),
+        ])
+        .collect();
+
+    assert_eq!(computed_df.dimensions(), (0, 2));
+}
+
+#[test]
+fn test_select_when_then_otherwise() {
+    let df = DataFrame::new(vec![
+        Series::from_slice_options("points", &vec![Some(1), Some(5), None, Some(10)]),
+        Series::from_vec("bonus", &vec![0.5, 1.5, 2.5, 3.5]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![
+            when(col("points").gt(lit(8)))
+                .then(lit("high"))
+                .when(col("points").gt(lit(3)))
+                .then(lit("medium"))
+                .otherwise(lit("low"))
+                .alias("level"),
+            // The branches are cast to a common type
+            when(col("points").lt(lit(5)))
+                .then(col("points"))
+                .otherwise(col("bonus")),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("level", &vec!["low", "medium", "low", "high"]),
+        Series::from_slice_options("points", &vec![Some(1.0), Some(1.5), Some(2.5), Some(3.5)]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_select_when_then_otherwise_of_empty_frame() {
+    let df = DataFrame::new(vec![Series::from_vec("a", &Vec::<i32>::new())]);
+
+    let computed_df = df
+        .lazy()
+        .select(vec![when(col("a").gt(lit(1)))
+            .then(lit(1))
+            .otherwise(lit(0))
+            .alias("b")])
+        .collect();
+
+    assert_eq!(computed_df.dimensions(), (0, 1));
+}
@@ -0,0 +1,16 @@
+use crate::dataframe::DataFrame;
+
+use super::Executor;
+
+pub struct SliceExec {
+    pub input: Box<dyn Executor>,
+    pub offset: i64,
+    pub len: usize,
+}
+
+impl Executor for SliceExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        df.slice_clamped(self.offset, self.len)
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::{
+        logical_plan::SortArguments,
+        physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    },
+    series::Series,
+};
+
+use super::Executor;
+
+pub struct SortExec {
+    pub input: Box<dyn Executor>,
+    pub by_column: Vec<Arc<dyn PhysicalExpr>>,
+    pub args: SortArguments,
+}
+
+impl Executor for SortExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        let state = ExecutionState::new();
+        let by = self
+            .by_column
+            .iter()
+            .map(|expr| expr.evaluate(&df, &state))
+            .collect::<Vec<Series>>();
+        df.sort_impl(by, &self.args.descending, self.args.nulls_last)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::DataFrame,
+    lazy_dataframe::{expr::col, lit::lit},
+    series::Series,
+};
+
+#[test]
+fn test_lazy_sort() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["c", "a", "b", "a"]),
+        Series::from_slice_options("points", &vec![Some(1), Some(7), None, Some(3)]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .sort(&["name", "points"], &[false], true)
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "a", "b", "c"]),
+        Series::from_slice_options("points", &vec![Some(3), Some(7), None, Some(1)]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_lazy_sort_by_expression_then_filter() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "c", "d"]),
+        Series::from_vec("points", &vec![2, -5, 4, -1]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .sort_by_exprs(vec![col("points") * col("points")], &[true], false)
+        .filter(col("points").lt(lit(3)))
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "a", "d"]),
+        Series::from_vec("points", &vec![-5, 2, -1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    series::Series,
+};
+
+use super::Executor;
+
+pub struct StackExec {
+    pub input: Box<dyn Executor>,
+    pub exprs: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl Executor for StackExec {
+    fn execute(&mut self) -> DataFrame {
+        let mut df = self.input.execute();
+        // The window expressions share their groups through the state
+        let state = ExecutionState::new();
+        let height = df.rows_count();
+        let new_columns = self
+            .exprs
+            .iter()
+            .map(|expr| {
+                let series = expr.evaluate(&df, &state);
+                // Aggregations return a single value, which is broadcast to every row
+                if series.len() == 1 && height > 1 {
+                    series.take_indices(&vec![0; height])
+                } else {
+                    series
+                }
+            })
+            .collect::<Vec<Series>>();
+
+        for series in new_columns {
+            match df.columns.iter().position(|s| s.name() == series.name()) {
+                Some(idx) => df.columns[idx] = series,
+                None => df.columns.push(series),
+            }
+        }
+        df
+    }
+}
@@ -0,0 +1,317 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    chunked_array::{
+        builder::NewFrom, rank::RankMethod, rolling_window::RollingOptions, types::AnyValue,
+    },
+    dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::{expr::col, lit::lit, physical_plan::state::ExecutionState},
+    series::Series,
+};
+
+#[test]
+fn test_with_columns_window() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["a", "b", "a", "b", "c"]),
+        Series::from_vec("sales", &vec![1.0, 2.0, 3.0, 6.0, 5.0]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![
+            (col("sales") / col("sales").sum().over(vec![col("region")])).alias("share"),
+            col("sales")
+                .count()
+                .over(vec![col("region")])
+                .alias("count"),
+            col("sales").max(),
+        ])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["a", "b", "a", "b", "c"]),
+        Series::from_vec("sales", &vec![6.0, 6.0, 6.0, 6.0, 6.0]),
+        Series::from_vec("share", &vec![0.25, 0.25, 0.75, 0.75, 1.0]),
+        Series::from_vec("count", &vec![2, 2, 2, 2, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_window_of_values_and_aggregations() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["a", "b", "a", "b", "c"]),
+        Series::from_vec("sales", &vec![1.0, 2.0, 3.0, 6.0, 5.0]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![
+            (col("sales") - col("sales").mean())
+                .over(vec![col("region")])
+                .alias("centered"),
+            lit(1).over(vec![col("region")]).alias("one"),
+        ])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("centered"),
+        Series::from_vec("centered", &vec![-1.0, -2.0, 1.0, 2.0, 0.0])
+    );
+    assert_eq!(
+        computed_df.column("one"),
+        Series::from_vec("one", &vec![1, 1, 1, 1, 1])
+    );
+}
+
+#[test]
+fn test_window_order_by() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "a", "b", "a", "b"]),
+        Series::from_vec("time", &vec![3, 1, 5, 2, 4]),
+        Series::from_vec("page", &vec!["x", "y", "z", "w", "v"]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![
+            col("page")
+                .first()
+                .over_ordered(vec![col("user")], col("time"))
+                .alias("landing"),
+            col("page")
+                .first()
+                .over(vec![col("user")])
+                .alias("first_row"),
+        ])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("landing"),
+        Series::from_vec("landing", &vec!["y", "y", "v", "y", "v"])
+    );
+    assert_eq!(
+        computed_df.column("first_row"),
+        Series::from_vec("first_row", &vec!["x", "x", "z", "x", "z"])
+    );
+}
+
+#[test]
+fn test_window_predicate_is_not_pushed_down() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["a", "a", "b", "b"]),
+        Series::from_vec("sales", &vec![1, 3, 2, 4]),
+        Series::from_vec("online", &vec![true, false, true, true]),
+    ]);
+
+    // The best sale of every region, if it was online
+    let computed_df = df
+        .lazy()
+        .filter(col("sales").eq(col("sales").max().over(vec![col("region")])))
+        .filter(col("online").eq(lit(true)))
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["b"]),
+        Series::from_vec("sales", &vec![4]),
+        Series::from_vec("online", &vec![true]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_execution_state_caches_groups() {
+    let state = ExecutionState::new();
+    let computed = AtomicUsize::new(0);
+    let compute = || {
+        computed.fetch_add(1, Ordering::Relaxed);
+        GroupsProxy::Slice {
+            offset: vec![0],
+            len: vec![2],
+        }
+    };
+
+    let first = state.groups_or_insert_with("[col(\"region\")]None", compute);
+    let second = state.groups_or_insert_with("[col(\"region\")]None", compute);
+    assert_eq!(first, second);
+    assert_eq!(computed.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_rank_over_partition() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["a", "b", "a", "a", "b"]),
+        Series::from_vec("sales", &vec![5, 2, 7, 5, 9]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![
+            col("sales")
+                .rank(RankMethod::Min, true)
+                .over(vec![col("region")])
+                .alias("rank"),
+            col("sales")
+                .dense_rank(false)
+                .over(vec![col("region")])
+                .alias("dense_rank"),
+            col("sales")
+                .percent_rank(false)
+                .over(vec![col("region")])
+                .alias("percent_rank"),
+            col("sales")
+                .ntile(2, false)
+                .over(vec![col("region")])
+                .alias("ntile"),
+        ])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("rank"),
+        Series::from_vec("rank", &vec![2, 2, 1, 2, 1])
+    );
+    assert_eq!(
+        computed_df.column("dense_rank"),
+        Series::from_vec("dense_rank", &vec![1, 1, 2, 1, 2])
+    );
+    assert_eq!(
+        computed_df.column("percent_rank"),
+        Series::from_vec("percent_rank", &vec![0.0, 0.0, 1.0, 0.0, 1.0])
+    );
+    assert_eq!(
+        computed_df.column("ntile"),
+        Series::from_vec("ntile", &vec![1, 1, 2, 1, 2])
+    );
+}
+
+#[test]
+fn test_row_number_ties_follow_order_by() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("user", &vec!["a", "a", "a", "b"]),
+        Series::from_vec("score", &vec![1, 1, 0, 4]),
+        Series::from_vec("time", &vec![2, 1, 3, 1]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![col("score")
+            .row_number(false)
+            .over_ordered(vec![col("user")], col("time"))
+            .alias("row_number")])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("row_number"),
+        Series::from_vec("row_number", &vec![3, 2, 1, 1])
+    );
+}
+
+#[test]
+fn test_top_n_per_group() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["a", "b", "a", "a", "b", "a"]),
+        Series::from_vec("sales", &vec![5, 2, 7, 1, 9, 6]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .filter(
+            col("sales")
+                .row_number(true)
+                .over(vec![col("region")])
+                .lt_eq(lit(2)),
+        )
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("region", &vec!["b", "a", "b", "a"]),
+        Series::from_vec("sales", &vec![2, 7, 9, 6]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_cumulative_over_partition() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("key", &vec!["a", "b", "a", "b", "a"]),
+        Series::from_slice_options("value", &vec![Some(1), Some(10), None, Some(20), Some(3)]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![
+            col("value")
+                .cum_sum()
+                .over(vec![col("key")])
+                .alias("cum_sum"),
+            col("value")
+                .cum_count()
+                .over(vec![col("key")])
+                .alias("cum_count"),
+            col("value").shift(1).over(vec![col("key")]).alias("shift"),
+            col("value").diff(1).over(vec![col("key")]).alias("diff"),
+        ])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("cum_sum"),
+        Series::from_slice_options("cum_sum", &vec![Some(1), Some(10), None, Some(30), Some(4)])
+    );
+    assert_eq!(
+        computed_df.column("cum_count"),
+        Series::from_vec("cum_count", &vec![1, 1, 1, 2, 2])
+    );
+    assert_eq!(
+        computed_df.column("shift"),
+        Series::from_slice_options("shift", &vec![None, None, Some(1), Some(10), None])
+    );
+    assert_eq!(
+        computed_df.column("diff"),
+        Series::from_slice_options("diff", &vec![None, None, None, Some(10), None])
+    );
+}
+
+#[test]
+fn test_shift_and_pct_change() {
+    let df = DataFrame::new(vec![Series::from_vec("price", &vec![2.0, 3.0, 1.5])]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![
+            col("price")
+                .shift_and_fill(-1, AnyValue::Float64(0.0))
+                .alias("next"),
+            col("price").pct_change(1).alias("change"),
+        ])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("next"),
+        Series::from_vec("next", &vec![3.0, 1.5, 0.0])
+    );
+    assert_eq!(
+        computed_df.column("change"),
+        Series::from_slice_options("change", &vec![None, Some(0.5), Some(-0.5)])
+    );
+}
+
+#[test]
+fn test_rolling_mean_over_partition() {
+    let df = DataFrame::new(vec![
+        Series::from_vec("key", &vec!["a", "a", "b", "a", "b"]),
+        Series::from_vec("value", &vec![1, 3, 10, 5, 20]),
+    ]);
+
+    let computed_df = df
+        .lazy()
+        .with_columns(vec![col("value")
+            .rolling_mean(RollingOptions::new(2).with_min_periods(1))
+            .over(vec![col("key")])
+            .alias("moving_average")])
+        .collect();
+
+    assert_eq!(
+        computed_df.column("moving_average"),
+        Series::from_vec("moving_average", &vec![1.0, 2.0, 10.0, 4.0, 15.0])
+    );
+}
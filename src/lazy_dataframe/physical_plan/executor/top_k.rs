@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::{
+        logical_plan::SortArguments,
+        physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    },
+    series::Series,
+};
+
+use super::Executor;
+
+pub struct TopKExec {
+    pub input: Box<dyn Executor>,
+    pub by_column: Vec<Arc<dyn PhysicalExpr>>,
+    pub args: SortArguments,
+    pub k: usize,
+}
+
+impl Executor for TopKExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        let state = ExecutionState::new();
+        let by = self
+            .by_column
+            .iter()
+            .map(|expr| expr.evaluate(&df, &state))
+            .collect::<Vec<Series>>();
+        df.top_k_impl(self.k, by, &self.args.descending, self.args.nulls_last)
+    }
+}
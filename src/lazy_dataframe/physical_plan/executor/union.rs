@@ -0,0 +1,28 @@
+use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{
+    core::POOL,
+    dataframe::{
+        concat::{concat, ConcatHow},
+        DataFrame,
+    },
+};
+
+use super::Executor;
+
+pub struct UnionExec {
+    pub inputs: Vec<Box<dyn Executor>>,
+    pub how: ConcatHow,
+}
+
+impl Executor for UnionExec {
+    fn execute(&mut self) -> DataFrame {
+        let dfs = POOL.install(|| {
+            self.inputs
+                .par_iter_mut()
+                .map(|input| input.execute())
+                .collect::<Vec<DataFrame>>()
+        });
+        concat(&dfs, self.how)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::{concat::ConcatHow, DataFrame},
+    lazy_dataframe::{expr::col, frame::concat, lit::lit, logical_plan::LogicalPlan},
+    series::Series,
+};
+
+#[test]
+fn test_lazy_vertical_concat_with_filter() {
+    let lf = || {
+        let first = DataFrame::new(vec![
+            Series::from_vec("name", &vec!["a", "b"]),
+            Series::from_vec("points", &vec![1, 5]),
+        ]);
+        let second = DataFrame::new(vec![
+            Series::from_vec("name", &vec!["c", "d"]),
+            Series::from_vec("points", &vec![7, 2]),
+        ]);
+        concat(vec![first.lazy(), second.lazy()], ConcatHow::Vertical)
+            .filter(col("points").gt(lit(3)))
+    };
+
+    // The filter runs in every input
+    match lf().get_optimized_plan() {
+        LogicalPlan::Union { inputs, .. } => assert!(inputs.iter().all(|input| matches!(
+            input,
+            LogicalPlan::DataFrameScan {
+                selection: Some(_),
+                ..
+            }
+        ))),
+        plan => panic!("expected the union at the top of the plan, got {plan:?}"),
+    }
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "c"]),
+        Series::from_vec("points", &vec![5, 7]),
+    ]);
+    assert_eq!(&lf().collect(), &expected_df);
+}
+
+#[test]
+fn test_lazy_diagonal_concat() {
+    let first = DataFrame::new(vec![Series::from_vec("name", &vec!["a"])]);
+    let second = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b"]),
+        Series::from_vec("points", &vec![2]),
+    ]);
+
+    let computed_df = concat(
+        vec![
+            first.lazy(),
+            second.lazy().select(vec![col("name"), col("points")]),
+        ],
+        ConcatHow::Diagonal,
+    )
+    .filter(col("name").neq(lit("c")))
+    .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b"]),
+        Series::from_slice_options("points", &vec![None, Some(2)]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
@@ -0,0 +1,24 @@
+use crate::{dataframe::DataFrame, lazy_dataframe::logical_plan::DistinctOptions};
+
+use super::Executor;
+
+pub struct DistinctExec {
+    pub input: Box<dyn Executor>,
+    pub options: DistinctOptions,
+}
+
+impl Executor for DistinctExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        let subset = self
+            .options
+            .subset
+            .as_ref()
+            .map(|subset| subset.iter().map(|s| s.as_str()).collect::<Vec<&str>>());
+        df.unique(
+            subset.as_deref(),
+            self.options.keep,
+            self.options.maintain_order,
+        )
+    }
+}
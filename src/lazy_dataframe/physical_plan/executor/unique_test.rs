@@ -0,0 +1,60 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::{unique::UniqueKeepStrategy, DataFrame},
+    lazy_dataframe::{expr::col, lit::lit, logical_plan::LogicalPlan},
+    series::Series,
+};
+
+#[cfg(test)]
+fn example_df() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "b", "a", "c", "b"]),
+        Series::from_vec("points", &vec![1, 2, 3, 4, 5]),
+    ])
+}
+
+#[test]
+fn test_lazy_unique() {
+    let computed_df = example_df()
+        .lazy()
+        .unique(Some(&["name"]), UniqueKeepStrategy::Last, true)
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["a", "c", "b"]),
+        Series::from_vec("points", &vec![3, 4, 5]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_unique_predicate_pushdown() {
+    // Only predicates on the subset give the same rows when they are applied first
+    let lf = || {
+        example_df()
+            .lazy()
+            .unique(Some(&["name"]), UniqueKeepStrategy::First, true)
+            .filter(col("name").neq(lit("c")))
+            .filter(col("points").gt(lit(1)))
+    };
+
+    match lf().get_optimized_plan() {
+        LogicalPlan::Selection { input, .. } => match *input {
+            LogicalPlan::Distinct { input, .. } => assert!(matches!(
+                *input,
+                LogicalPlan::DataFrameScan {
+                    selection: Some(_),
+                    ..
+                }
+            )),
+            plan => panic!("expected the distinct below the filter, got {plan:?}"),
+        },
+        plan => panic!("expected the filter at the top of the plan, got {plan:?}"),
+    }
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b"]),
+        Series::from_vec("points", &vec![2]),
+    ]);
+    assert_eq!(&lf().collect(), &expected_df);
+}
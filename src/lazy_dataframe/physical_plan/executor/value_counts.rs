@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::DataFrame,
+    lazy_dataframe::{
+        logical_plan::GroupByOptions,
+        physical_plan::{physical_expr::PhysicalExpr, state::ExecutionState},
+    },
+    series::Series,
+};
+
+use super::Executor;
+
+pub struct ValueCountsExec {
+    pub input: Box<dyn Executor>,
+    pub keys: Vec<Arc<dyn PhysicalExpr>>,
+    pub values: Arc<dyn PhysicalExpr>,
+    pub sort: bool,
+    pub normalize: bool,
+    pub options: GroupByOptions,
+}
+
+impl Executor for ValueCountsExec {
+    fn execute(&mut self) -> DataFrame {
+        let df = self.input.execute();
+        let state = ExecutionState::new();
+        let values = self.values.evaluate(&df, &state);
+        if self.keys.is_empty() {
+            return values.value_counts(self.sort, self.normalize);
+        }
+        if values.len() != df.rows_count() {
+            panic!("cannot count the values of an aggregation within groups");
+        }
+
+        let by = self
+            .keys
+            .iter()
+            .map(|expr| expr.evaluate(&df, &state))
+            .collect::<Vec<Series>>();
+        // The counts of a group, next to as many copies of its keys
+        let keys_count = by.len();
+        let count_group = |group: DataFrame| {
+            let counts = group.columns[keys_count].value_counts(self.sort, self.normalize);
+            let firsts = vec![0; counts.rows_count()];
+            let mut columns = group.columns[..keys_count]
+                .iter()
+                .map(|key| key.take_indices(&firsts))
+                .collect::<Vec<Series>>();
+            columns.extend(counts.columns);
+            DataFrame::new_no_checks(columns)
+        };
+        let mut keys_and_values = by.clone();
+        keys_and_values.push(values);
+        let keys_and_values = DataFrame::new_no_checks(keys_and_values);
+        if df.rows_count() == 0 {
+            return count_group(keys_and_values);
+        }
+
+        let mut groups = df.compute_group_proxy(by.clone(), self.options.maintain_order);
+        if self.options.sort {
+            groups.sort_by_keys(&by);
+        }
+        keys_and_values.apply_groups(&groups, &count_group)
+    }
+}
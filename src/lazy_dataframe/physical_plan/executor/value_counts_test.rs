@@ -0,0 +1,124 @@
+use crate::{
+    chunked_array::builder::NewFrom,
+    dataframe::DataFrame,
+    lazy_dataframe::{expr::col, lit::lit, logical_plan::LogicalPlan},
+    series::Series,
+};
+
+#[cfg(test)]
+fn example_df() -> DataFrame {
+    DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "a", "b", "c", "b", "a"]),
+        Series::from_vec("points", &vec![1, 2, 3, 4, 5, 6]),
+    ])
+}
+
+#[test]
+fn test_select_value_counts() {
+    let computed_df = example_df()
+        .lazy()
+        .select(vec![col("name").value_counts(true, false)])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "a", "c"]),
+        Series::from_vec("count", &vec![3, 2, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_value_counts_of_aliased_expression() {
+    let computed_df = example_df()
+        .lazy()
+        .select(vec![col("points")
+            .gt(lit(2))
+            .value_counts(false, true)
+            .alias("high")])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("high", &vec![false, true]),
+        Series::from_vec("proportion", &vec![2.0 / 6.0, 4.0 / 6.0]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_value_counts() {
+    let computed_df = example_df()
+        .lazy()
+        .with_columns(vec![col("points").gt(lit(2)).alias("high")])
+        .groupby(vec![col("high")])
+        .maintain_order(true)
+        .agg(vec![col("name").value_counts(true, false)])
+        .collect();
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("high", &vec![false, false, true, true, true]),
+        Series::from_vec("name", &vec!["b", "a", "b", "c", "a"]),
+        Series::from_vec("count", &vec![1, 1, 2, 1, 1]),
+    ]);
+    assert_eq!(&computed_df, &expected_df);
+}
+
+#[test]
+fn test_groupby_value_counts_of_empty_frame() {
+    let computed_df = example_df()
+        .lazy()
+        .filter(col("points").gt(lit(10)))
+        .groupby(vec![col("name")])
+        .agg(vec![col("points").value_counts(false, false)])
+        .collect();
+
+    assert_eq!(computed_df.dimensions(), (0, 3));
+    assert_eq!(computed_df.column("count").len(), 0);
+}
+
+#[test]
+fn test_value_counts_predicate_pushdown() {
+    // The counts depend on all the rows, so the filter stays above them
+    let lf = || {
+        example_df()
+            .lazy()
+            .select(vec![col("name").value_counts(false, false)])
+            .filter(col("count").gt(lit(1)))
+    };
+
+    match lf().get_optimized_plan() {
+        LogicalPlan::Selection { input, .. } => {
+            assert!(matches!(*input, LogicalPlan::ValueCounts { .. }))
+        }
+        plan => panic!("expected the filter at the top of the plan, got {plan:?}"),
+    }
+
+    let expected_df = DataFrame::new(vec![
+        Series::from_vec("name", &vec!["b", "a"]),
+        Series::from_vec("count", &vec![3, 2]),
+    ]);
+    assert_eq!(&lf().collect(), &expected_df);
+}
+
+#[test]
+#[should_panic(expected = "value_counts must be the only expression")]
+fn test_value_counts_next_to_other_expressions() {
+    example_df()
+        .lazy()
+        .select(vec![col("points"), col("name").value_counts(true, false)]);
+}
+
+#[test]
+#[should_panic(expected = "value_counts must be the only expression")]
+fn test_value_counts_in_window() {
+    example_df().lazy().select(vec![col("points")
+        .value_counts(true, false)
+        .over(vec![col("name")])]);
+}
+
+#[test]
+#[should_panic(expected = "cannot count the values into a column named \"count\"")]
+fn test_value_counts_into_taken_column() {
+    let df = DataFrame::new(vec![Series::from_vec("count", &vec![1, 1, 2])]);
+    df.lazy()
+        .select(vec![col("count").value_counts(true, false)]);
+}
@@ -1,2 +1,3 @@
 pub mod executor;
 pub mod physical_expr;
+pub mod state;
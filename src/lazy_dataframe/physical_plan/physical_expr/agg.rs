@@ -1,29 +1,124 @@
 use std::sync::Arc;
 
 use crate::{
+    chunked_array::{aggregate::QuantileInterpolOptions, types::I32Chunked},
     dataframe::{groupby::GroupsProxy, DataFrame},
-    series::Series,
+    lazy_dataframe::physical_plan::state::ExecutionState,
+    series::{constructor::IntoSeries, Series},
 };
 
-use super::PhysicalExpr;
+use super::{AggState, AggregationContext, PhysicalExpr};
 
 pub enum AggregationExpr {
     Min(Arc<dyn PhysicalExpr>),
+    Max(Arc<dyn PhysicalExpr>),
+    Sum(Arc<dyn PhysicalExpr>),
+    Mean(Arc<dyn PhysicalExpr>),
+    Count(Arc<dyn PhysicalExpr>),
+    Len(Arc<dyn PhysicalExpr>),
+    NUnique(Arc<dyn PhysicalExpr>),
+    First(Arc<dyn PhysicalExpr>),
+    Last(Arc<dyn PhysicalExpr>),
+    Median(Arc<dyn PhysicalExpr>),
+    Quantile {
+        input: Arc<dyn PhysicalExpr>,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
+    Std(Arc<dyn PhysicalExpr>, u8),
+    Var(Arc<dyn PhysicalExpr>, u8),
 }
 
-impl PhysicalExpr for AggregationExpr {
-    fn evaluate(&self, df: &DataFrame) -> Series {
-        todo!()
+impl AggregationExpr {
+    fn input(&self) -> &Arc<dyn PhysicalExpr> {
+        match self {
+            AggregationExpr::Min(input)
+            | AggregationExpr::Max(input)
+            | AggregationExpr::Sum(input)
+            | AggregationExpr::Mean(input)
+            | AggregationExpr::Count(input)
+            | AggregationExpr::Len(input)
+            | AggregationExpr::NUnique(input)
+            | AggregationExpr::First(input)
+            | AggregationExpr::Last(input)
+            | AggregationExpr::Median(input)
+            | AggregationExpr::Quantile { input, .. }
+            | AggregationExpr::Std(input, _)
+            | AggregationExpr::Var(input, _) => input,
+        }
     }
+}
 
-    fn evaluate_for_groups(&self, df: &DataFrame, group_proxy: &GroupsProxy) -> Series {
+impl PhysicalExpr for AggregationExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        let series = self.input().evaluate(df, state);
+        // The whole series is a single group
+        let groups = GroupsProxy::Slice {
+            offset: vec![0],
+            len: vec![series.len() as u32],
+        };
         match self {
-            AggregationExpr::Min(agg) => {
-                // TODO: This should be evaluate_groups.
-                // But for the MVP, let's just only support col(...).agg(...)
-                let series = agg.evaluate(df);
-                series.agg_min(group_proxy)
+            AggregationExpr::Min(_) => series.min_as_series(),
+            AggregationExpr::Max(_) => series.max_as_series(),
+            AggregationExpr::Sum(_) => series.sum_as_series(),
+            AggregationExpr::Mean(_) => series.mean_as_series(),
+            AggregationExpr::Count(_) => {
+                let count = (series.len() - series.null_count()) as i32;
+                I32Chunked::from_iter_values(series.name(), std::iter::once(count)).into_series()
             }
+            AggregationExpr::Len(_) => {
+                let len = series.len() as i32;
+                I32Chunked::from_iter_values(series.name(), std::iter::once(len)).into_series()
+            }
+            AggregationExpr::NUnique(_) => series.agg_n_unique(&groups),
+            // A null if the series is empty
+            AggregationExpr::First(_) => series.agg_first(&groups),
+            AggregationExpr::Last(_) => series.agg_last(&groups),
+            AggregationExpr::Median(_) => series.median_as_series(),
+            AggregationExpr::Quantile {
+                quantile, interpol, ..
+            } => series.quantile_as_series(*quantile, *interpol),
+            AggregationExpr::Std(_, ddof) => series.std_as_series(*ddof),
+            AggregationExpr::Var(_, ddof) => series.var_as_series(*ddof),
         }
     }
+
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let ctx = self
+            .input()
+            .evaluate_for_groups(df, groups, state)
+            .expand_literal();
+        assert!(
+            !ctx.is_aggregated(),
+            "cannot aggregate an expression that is already aggregated"
+        );
+        let series = ctx.series();
+        let groups = ctx.groups();
+        let aggregated = match self {
+            AggregationExpr::Min(_) => series.agg_min(groups),
+            AggregationExpr::Max(_) => series.agg_max(groups),
+            AggregationExpr::Sum(_) => series.agg_sum(groups),
+            AggregationExpr::Mean(_) => series.agg_mean(groups),
+            AggregationExpr::Count(_) => series.agg_count(groups),
+            AggregationExpr::Len(_) => {
+                let lengths = groups.group_lengths().into_iter().map(|len| len as i32);
+                I32Chunked::from_iter_values(series.name(), lengths).into_series()
+            }
+            AggregationExpr::NUnique(_) => series.agg_n_unique(groups),
+            AggregationExpr::First(_) => series.agg_first(groups),
+            AggregationExpr::Last(_) => series.agg_last(groups),
+            AggregationExpr::Median(_) => series.agg_median(groups),
+            AggregationExpr::Quantile {
+                quantile, interpol, ..
+            } => series.agg_quantile(groups, *quantile, *interpol),
+            AggregationExpr::Std(_, ddof) => series.agg_std(groups, *ddof),
+            AggregationExpr::Var(_, ddof) => series.agg_var(groups, *ddof),
+        };
+        AggregationContext::new(AggState::Aggregated(aggregated), ctx.groups)
+    }
 }
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::physical_plan::state::ExecutionState,
+    series::Series,
+};
+
+use super::{AggState, AggregationContext, PhysicalExpr};
+
+pub struct AliasExpr {
+    pub input: Arc<dyn PhysicalExpr>,
+    pub name: Arc<str>,
+}
+
+impl AliasExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, name: Arc<str>) -> Self {
+        AliasExpr { input, name }
+    }
+
+    fn rename(&self, mut series: Series) -> Series {
+        series.rename(&self.name);
+        series
+    }
+}
+
+impl PhysicalExpr for AliasExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        self.rename(self.input.evaluate(df, state))
+    }
+
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let ctx = self.input.evaluate_for_groups(df, groups, state);
+        let state = match ctx.state {
+            AggState::Aggregated(series) => AggState::Aggregated(self.rename(series)),
+            AggState::NotAggregated(series) => AggState::NotAggregated(self.rename(series)),
+            AggState::Literal(series) => AggState::Literal(self.rename(series)),
+        };
+        AggregationContext::new(state, ctx.groups)
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    core::POOL,
+    dataframe::{groupby::GroupsProxy, utils::accumulate_series_vertical, DataFrame},
+    lazy_dataframe::{function_expr::FunctionExpr, physical_plan::state::ExecutionState},
+    series::Series,
+};
+
+use super::{AggState, AggregationContext, PhysicalExpr};
+
+pub struct ApplyExpr {
+    pub input: Arc<dyn PhysicalExpr>,
+    pub function: FunctionExpr,
+}
+
+impl ApplyExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, function: FunctionExpr) -> Self {
+        ApplyExpr { input, function }
+    }
+}
+
+impl PhysicalExpr for ApplyExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        self.function.call(&self.input.evaluate(df, state))
+    }
+
+    // Calls the function on the values of every group and puts the outputs back at the
+    // positions of the values, so the groups stay valid
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let ctx = self
+            .input
+            .evaluate_for_groups(df, groups, state)
+            .expand_literal();
+        assert!(
+            !ctx.is_aggregated(),
+            "cannot apply {:?} to an aggregated expression",
+            self.function
+        );
+        let series = ctx.series();
+        let all = ctx.groups().all_indices();
+        let mut outputs = POOL.install(|| {
+            all.par_iter()
+                .map(|idx| {
+                    let idx = idx.iter().map(|idx| *idx as usize).collect::<Vec<usize>>();
+                    self.function.call(&series.take_indices(&idx))
+                })
+                .collect::<Vec<Series>>()
+        });
+        // Keeps the output dtype when there are no groups
+        outputs.push(self.function.call(&series.slice(0, 0)));
+
+        let mut positions = vec![None; series.len()];
+        all.iter()
+            .flatten()
+            .enumerate()
+            .for_each(|(output_idx, idx)| positions[*idx as usize] = Some(output_idx));
+        let output = accumulate_series_vertical(outputs).take_opt_indices(&positions);
+        AggregationContext::new(AggState::NotAggregated(output), ctx.groups)
+    }
+}
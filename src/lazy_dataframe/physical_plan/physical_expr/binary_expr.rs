@@ -3,11 +3,11 @@ use std::sync::Arc;
 use crate::{
     chunked_array::chunk_compare::ChunkCompare,
     dataframe::{groupby::GroupsProxy, DataFrame},
-    lazy_dataframe::expr::Operator,
+    lazy_dataframe::{expr::Operator, physical_plan::state::ExecutionState},
     series::{constructor::IntoSeries, Series},
 };
 
-use super::PhysicalExpr;
+use super::{combine_contexts, AggregationContext, PhysicalExpr};
 
 pub struct BinaryExpr {
     pub left: Arc<dyn PhysicalExpr>,
@@ -21,21 +21,42 @@ impl BinaryExpr {
     }
 }
 
+pub fn apply_operator(left: &Series, op: Operator, right: &Series) -> Series {
+    match op {
+        Operator::And => (left.bool() & right.bool()).into_series(),
+        Operator::Or => (left.bool() | right.bool()).into_series(),
+        Operator::Eq => left.equal(right).into_series(),
+        Operator::NotEq => left.not_equal(right).into_series(),
+        Operator::Gt => left.gt(right).into_series(),
+        Operator::GtEq => left.gt_eq(right).into_series(),
+        Operator::Lt => left.lt(right).into_series(),
+        Operator::LtEq => left.lt_eq(right).into_series(),
+        Operator::Plus => left + right,
+        Operator::Minus => left - right,
+        Operator::Multiply => left * right,
+        Operator::Divide => left / right,
+    }
+}
+
 impl PhysicalExpr for BinaryExpr {
-    fn evaluate(&self, df: &DataFrame) -> Series {
-        let left = self.left.evaluate(df);
-        let right = self.right.evaluate(df);
-        match self.op {
-            Operator::And => todo!(),
-            Operator::Or => todo!(),
-            Operator::Eq => {
-                let boolean_chunk = left.equal(&right);
-                boolean_chunk.into_series()
-            }
-        }
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        let left = self.left.evaluate(df, state);
+        let right = self.right.evaluate(df, state);
+        apply_operator(&left, self.op, &right)
     }
 
-    fn evaluate_for_groups(&self, df: &DataFrame, group_proxy: &GroupsProxy) -> Series {
-        todo!()
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let contexts = vec![
+            self.left.evaluate_for_groups(df, groups, state),
+            self.right.evaluate_for_groups(df, groups, state),
+        ];
+        combine_contexts(contexts, |inputs| {
+            apply_operator(&inputs[0], self.op, &inputs[1])
+        })
     }
 }
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::physical_plan::state::ExecutionState,
+    series::{nulls::coalesce, Series},
+};
+
+use super::{combine_contexts, AggregationContext, PhysicalExpr};
+
+pub struct CoalesceExpr {
+    pub inputs: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl CoalesceExpr {
+    pub fn new(inputs: Vec<Arc<dyn PhysicalExpr>>) -> Self {
+        CoalesceExpr { inputs }
+    }
+}
+
+impl PhysicalExpr for CoalesceExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.evaluate(df, state))
+            .collect::<Vec<Series>>();
+        coalesce(&inputs)
+    }
+
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let contexts = self
+            .inputs
+            .iter()
+            .map(|input| input.evaluate_for_groups(df, groups, state))
+            .collect();
+        combine_contexts(contexts, coalesce)
+    }
+}
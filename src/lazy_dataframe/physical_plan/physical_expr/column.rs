@@ -1,11 +1,12 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use crate::{
     dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::physical_plan::state::ExecutionState,
     series::Series,
 };
 
-use super::PhysicalExpr;
+use super::{AggState, AggregationContext, PhysicalExpr};
 
 pub struct ColumnExpr {
     pub col_name: Arc<str>,
@@ -18,11 +19,17 @@ impl ColumnExpr {
 }
 
 impl PhysicalExpr for ColumnExpr {
-    fn evaluate(&self, df: &DataFrame) -> Series {
+    fn evaluate(&self, df: &DataFrame, _state: &ExecutionState) -> Series {
         df.column(&self.col_name)
     }
 
-    fn evaluate_for_groups(&self, df: &DataFrame, group_proxy: &GroupsProxy) -> Series {
-        todo!()
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let series = self.evaluate(df, state);
+        AggregationContext::new(AggState::NotAggregated(series), Cow::Borrowed(groups))
     }
 }
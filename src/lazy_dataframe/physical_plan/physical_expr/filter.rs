@@ -0,0 +1,80 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::physical_plan::state::ExecutionState,
+    series::Series,
+};
+
+use super::{align_contexts, AggState, AggregationContext, PhysicalExpr};
+
+pub struct FilterExpr {
+    pub input: Arc<dyn PhysicalExpr>,
+    pub by: Arc<dyn PhysicalExpr>,
+}
+
+impl FilterExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, by: Arc<dyn PhysicalExpr>) -> Self {
+        FilterExpr { input, by }
+    }
+}
+
+impl PhysicalExpr for FilterExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        let series = self.input.evaluate(df, state);
+        let predicate = self.by.evaluate(df, state);
+        series.filter(predicate.bool())
+    }
+
+    // Filters the values of every group. The groups are rewritten to index into the filtered
+    // values, so groups can become empty.
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let input = self
+            .input
+            .evaluate_for_groups(df, groups, state)
+            .expand_literal();
+        assert!(
+            !input.is_aggregated(),
+            "cannot filter an aggregated expression"
+        );
+        let by = self.by.evaluate_for_groups(df, groups, state);
+        let (inputs, _, groups) = align_contexts(vec![input, by]);
+        let predicate = inputs[1].bool();
+
+        // Position of every kept row in the filtered values
+        let mut new_idx = Vec::with_capacity(predicate.length);
+        let mut kept = 0u32;
+        predicate.into_iter().for_each(|keep| {
+            if keep == Some(true) {
+                new_idx.push(Some(kept));
+                kept += 1;
+            } else {
+                new_idx.push(None);
+            }
+        });
+
+        let all = groups
+            .all_indices()
+            .iter()
+            .map(|idx| {
+                idx.iter()
+                    .filter_map(|row| new_idx[*row as usize])
+                    .collect::<Vec<u32>>()
+            })
+            .collect::<Vec<Vec<u32>>>();
+        let first = all
+            .iter()
+            .map(|idx| idx.first().copied().unwrap_or(0))
+            .collect::<Vec<u32>>();
+        let series = inputs[0].filter(predicate);
+        AggregationContext::new(
+            AggState::NotAggregated(series),
+            Cow::Owned(GroupsProxy::Idx { first, all }),
+        )
+    }
+}
@@ -1,14 +1,16 @@
+use std::borrow::Cow;
+
 use crate::{
     chunked_array::{
         chunk_full::ChunkFull,
         types::{BooleanChunked, I32Chunked, Utf8Chunked},
     },
     dataframe::{groupby::GroupsProxy, DataFrame},
-    lazy_dataframe::lit::LiteralValue,
+    lazy_dataframe::{lit::LiteralValue, physical_plan::state::ExecutionState},
     series::{constructor::IntoSeries, Series},
 };
 
-use super::PhysicalExpr;
+use super::{AggState, AggregationContext, PhysicalExpr};
 
 pub struct LiteralExpr(pub LiteralValue);
 
@@ -16,25 +18,32 @@ impl LiteralExpr {
     pub fn new(lit: LiteralValue) -> Self {
         LiteralExpr(lit)
     }
+
+    fn to_series(&self, length: usize) -> Series {
+        let series_name = "LITERAL";
+        match &self.0 {
+            LiteralValue::Boolean(v) => BooleanChunked::full(series_name, *v, length).into_series(),
+            LiteralValue::Int32(v) => I32Chunked::full(series_name, *v, length).into_series(),
+            LiteralValue::Utf8(v) => Utf8Chunked::full(series_name, v, length).into_series(),
+        }
+    }
 }
 
 impl PhysicalExpr for LiteralExpr {
-    fn evaluate(&self, df: &DataFrame) -> Series {
-        let rows_count = df.rows_count();
-        let series_name = "LITERAL";
+    fn evaluate(&self, df: &DataFrame, _state: &ExecutionState) -> Series {
         // TODO: We should create a Series of size 1. But
         // currently our algorithms doesn't work when Series size
         // is 1.
-        match &self.0 {
-            LiteralValue::Boolean(v) => {
-                BooleanChunked::full(series_name, *v, rows_count).into_series()
-            }
-            LiteralValue::Int32(v) => I32Chunked::full(series_name, *v, rows_count).into_series(),
-            LiteralValue::Utf8(v) => Utf8Chunked::full(series_name, v, rows_count).into_series(),
-        }
+        self.to_series(df.rows_count())
     }
 
-    fn evaluate_for_groups(&self, df: &DataFrame, group_proxy: &GroupsProxy) -> Series {
-        todo!()
+    // A literal has the same value for every row of every group
+    fn evaluate_for_groups<'a>(
+        &self,
+        _df: &DataFrame,
+        groups: &'a GroupsProxy,
+        _state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        AggregationContext::new(AggState::Literal(self.to_series(1)), Cow::Borrowed(groups))
     }
 }
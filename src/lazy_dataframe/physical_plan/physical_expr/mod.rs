@@ -1,15 +1,228 @@
+use std::borrow::Cow;
+
 use crate::{
     dataframe::{groupby::GroupsProxy, DataFrame},
     series::Series,
 };
 
+use super::state::ExecutionState;
+
 pub mod agg;
+pub mod alias;
+pub mod apply;
 pub mod binary_expr;
+pub mod coalesce;
 pub mod column;
+pub mod filter;
 pub mod literal;
+pub mod ternary;
+pub mod window;
 
 pub trait PhysicalExpr: Send + Sync {
-    fn evaluate(&self, df: &DataFrame) -> Series;
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series;
+
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a>;
+}
+
+// Based on the AggregationContext of Polars:
+// https://github.com/pola-rs/polars/blob/f566963f526a11585805088c96e579045a0a2b79/polars/polars-lazy/src/physical_plan/expressions/mod.rs#L47
+pub enum AggState {
+    // One value per group
+    Aggregated(Series),
+    // Values that still have to be aggregated. The groups index into this Series.
+    NotAggregated(Series),
+    // A single value that is the same for every row of every group
+    Literal(Series),
+}
+
+// The result of evaluating an expression on groups. Expressions like filter change the
+// groups, so the groups are passed along with the values.
+pub struct AggregationContext<'a> {
+    pub state: AggState,
+    pub groups: Cow<'a, GroupsProxy>,
+}
+
+impl<'a> AggregationContext<'a> {
+    pub fn new(state: AggState, groups: Cow<'a, GroupsProxy>) -> Self {
+        AggregationContext { state, groups }
+    }
+
+    pub fn series(&self) -> &Series {
+        match &self.state {
+            AggState::Aggregated(series)
+            | AggState::NotAggregated(series)
+            | AggState::Literal(series) => series,
+        }
+    }
+
+    pub fn is_aggregated(&self) -> bool {
+        matches!(self.state, AggState::Aggregated(_))
+    }
+
+    pub fn groups(&self) -> &GroupsProxy {
+        self.groups.as_ref()
+    }
+
+    // Repeats a literal for every row of every group, so it can be aggregated like a column
+    pub fn expand_literal(self) -> Self {
+        match self.state {
+            AggState::Literal(series) => {
+                let lengths = self.groups.group_lengths();
+                let len = lengths.iter().sum::<u32>() as usize;
+                AggregationContext::new(
+                    AggState::NotAggregated(repeat(&series, len)),
+                    Cow::Owned(consecutive_groups(&lengths)),
+                )
+            }
+            state => AggregationContext::new(state, self.groups),
+        }
+    }
+
+    // Returns the values of the groups one group after another, where group `g` has
+    // `lengths[g]` values. Aggregated values are repeated for every value of their group.
+    fn flat_values(&self, lengths: &[u32]) -> Series {
+        match &self.state {
+            AggState::NotAggregated(series) => {
+                let all = self.groups.all_indices();
+                let idx = all
+                    .iter()
+                    .flatten()
+                    .map(|idx| *idx as usize)
+                    .collect::<Vec<_>>();
+                series.take_indices(&idx)
+            }
+            AggState::Aggregated(series) => {
+                let idx = lengths
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(group, len)| std::iter::repeat_n(group, *len as usize))
+                    .collect::<Vec<usize>>();
+                series.take_indices(&idx)
+            }
+            AggState::Literal(series) => repeat(series, lengths.iter().sum::<u32>() as usize),
+        }
+    }
+
+    // Returns one value per group. Only aggregated expressions can be the output of a groupby.
+    pub fn aggregated(self) -> Series {
+        match self.state {
+            AggState::Aggregated(series) => series,
+            AggState::Literal(series) => repeat(&series, self.groups.len()),
+            AggState::NotAggregated(series) => panic!(
+                "expression on {} must be aggregated in a groupby context",
+                series.name()
+            ),
+        }
+    }
+}
+
+// Repeats the single value of a literal
+pub fn repeat(series: &Series, len: usize) -> Series {
+    series.take_indices(&vec![0; len])
+}
+
+// Groups of the given lengths that follow each other
+fn consecutive_groups(lengths: &[u32]) -> GroupsProxy {
+    let offset = lengths
+        .iter()
+        .scan(0, |offset, len| {
+            *offset += len;
+            Some(*offset - len)
+        })
+        .collect();
+    GroupsProxy::Slice {
+        offset,
+        len: lengths.to_vec(),
+    }
+}
+
+// The aligned values, with the state constructor and the groups of the combined values
+type AlignedContexts<'a> = (Vec<Series>, fn(Series) -> AggState, Cow<'a, GroupsProxy>);
+
+// Brings the values of several contexts to a common layout, so they can be combined value by
+// value. Groups can overlap or skip rows, so values that are not aggregated only stay in
+// place when all of them are indexed by the same groups. Otherwise the values of every group
+// are laid out one group after another.
+pub fn align_contexts<'a>(mut contexts: Vec<AggregationContext<'a>>) -> AlignedContexts<'a> {
+    let is_not_aggregated =
+        |ctx: &AggregationContext| matches!(ctx.state, AggState::NotAggregated(_));
+    let Some(first) = contexts.iter().position(is_not_aggregated) else {
+        return match contexts.iter().position(|ctx| ctx.is_aggregated()) {
+            Some(position) => {
+                let len = contexts[position].series().len();
+                let inputs = contexts
+                    .iter()
+                    .map(|ctx| match &ctx.state {
+                        AggState::Literal(series) => repeat(series, len),
+                        _ => ctx.series().clone(),
+                    })
+                    .collect();
+                (
+                    inputs,
+                    AggState::Aggregated,
+                    contexts.swap_remove(position).groups,
+                )
+            }
+            None => {
+                let inputs = contexts.iter().map(|ctx| ctx.series().clone()).collect();
+                (inputs, AggState::Literal, contexts.swap_remove(0).groups)
+            }
+        };
+    };
+
+    let groups = contexts[first].groups();
+    let in_place = contexts.iter().all(|ctx| match &ctx.state {
+        AggState::NotAggregated(_) => std::ptr::eq(ctx.groups(), groups) || ctx.groups() == groups,
+        AggState::Aggregated(_) => false,
+        AggState::Literal(_) => true,
+    });
+    if in_place {
+        let len = contexts[first].series().len();
+        let inputs = contexts
+            .iter()
+            .map(|ctx| match &ctx.state {
+                AggState::Literal(series) => repeat(series, len),
+                _ => ctx.series().clone(),
+            })
+            .collect();
+        return (
+            inputs,
+            AggState::NotAggregated,
+            contexts.swap_remove(first).groups,
+        );
+    }
+
+    let lengths = groups.group_lengths();
+    if contexts
+        .iter()
+        .any(|ctx| is_not_aggregated(ctx) && ctx.groups().group_lengths() != lengths)
+    {
+        panic!("the combined expressions must have the same number of values in every group");
+    }
+    let inputs = contexts
+        .iter()
+        .map(|ctx| ctx.flat_values(&lengths))
+        .collect();
+    (
+        inputs,
+        AggState::NotAggregated,
+        Cow::Owned(consecutive_groups(&lengths)),
+    )
+}
 
-    fn evaluate_for_groups(&self, df: &DataFrame, group_proxy: &GroupsProxy) -> Series;
+// Combines the values of several expressions value by value with `f`
+pub fn combine_contexts<'a, F>(
+    contexts: Vec<AggregationContext<'a>>,
+    f: F,
+) -> AggregationContext<'a>
+where
+    F: Fn(&[Series]) -> Series,
+{
+    let (inputs, state, groups) = align_contexts(contexts);
+    AggregationContext::new(state(f(&inputs)), groups)
 }
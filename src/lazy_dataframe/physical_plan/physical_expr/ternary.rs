@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::physical_plan::state::ExecutionState,
+    series::Series,
+};
+
+use super::{combine_contexts, AggregationContext, PhysicalExpr};
+
+pub struct TernaryExpr {
+    pub predicate: Arc<dyn PhysicalExpr>,
+    pub truthy: Arc<dyn PhysicalExpr>,
+    pub falsy: Arc<dyn PhysicalExpr>,
+}
+
+impl TernaryExpr {
+    pub fn new(
+        predicate: Arc<dyn PhysicalExpr>,
+        truthy: Arc<dyn PhysicalExpr>,
+        falsy: Arc<dyn PhysicalExpr>,
+    ) -> Self {
+        TernaryExpr {
+            predicate,
+            truthy,
+            falsy,
+        }
+    }
+}
+
+fn zip(inputs: &[Series]) -> Series {
+    inputs[1].zip_with(inputs[0].bool(), &inputs[2])
+}
+
+impl PhysicalExpr for TernaryExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        let predicate = self.predicate.evaluate(df, state);
+        let truthy = self.truthy.evaluate(df, state);
+        let falsy = self.falsy.evaluate(df, state);
+        truthy.zip_with(predicate.bool(), &falsy)
+    }
+
+    fn evaluate_for_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        let contexts = [&self.predicate, &self.truthy, &self.falsy]
+            .iter()
+            .map(|input| input.evaluate_for_groups(df, groups, state))
+            .collect();
+        combine_contexts(contexts, zip)
+    }
+}
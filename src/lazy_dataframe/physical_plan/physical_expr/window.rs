@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::{
+    dataframe::{groupby::GroupsProxy, DataFrame},
+    lazy_dataframe::physical_plan::state::ExecutionState,
+    series::Series,
+};
+
+use super::{repeat, AggState, AggregationContext, PhysicalExpr};
+
+pub struct WindowExpr {
+    pub function: Arc<dyn PhysicalExpr>,
+    pub partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    pub order_by: Option<Arc<dyn PhysicalExpr>>,
+    // Identifies the groups in the cache of the execution state
+    pub cache_key: String,
+}
+
+impl PhysicalExpr for WindowExpr {
+    // Evaluates the function on the groups of the partition keys and maps the results back to
+    // the rows
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Series {
+        let groups = state.groups_or_insert_with(&self.cache_key, || {
+            let by = self
+                .partition_by
+                .iter()
+                .map(|expr| expr.evaluate(df, state))
+                .collect::<Vec<Series>>();
+            let groups = df.compute_group_proxy(by, false);
+            match &self.order_by {
+                Some(order_by) => groups.sort_rows_by(&order_by.evaluate(df, state)),
+                None => groups,
+            }
+        });
+        let ctx = self.function.evaluate_for_groups(df, &groups, state);
+        map_to_rows(&ctx, &groups, df.rows_count())
+    }
+
+    fn evaluate_for_groups<'a>(
+        &self,
+        _df: &DataFrame,
+        _groups: &'a GroupsProxy,
+        _state: &ExecutionState,
+    ) -> AggregationContext<'a> {
+        panic!("window expressions are not supported in a groupby context")
+    }
+}
+
+// Puts the values of every group at the rows of the group. The groups of the context have to
+// have a value for every row of the partition groups, unless they are aggregated.
+fn map_to_rows(ctx: &AggregationContext, groups: &GroupsProxy, rows: usize) -> Series {
+    let all = groups.all_indices();
+    let mut positions = vec![None; rows];
+    match &ctx.state {
+        AggState::NotAggregated(series) if series.len() == rows && ctx.groups() == groups => {
+            return series.clone();
+        }
+        AggState::NotAggregated(series) => {
+            let values = ctx.groups().all_indices();
+            all.iter().zip(values.iter()).for_each(|(rows, values)| {
+                assert_eq!(
+                    rows.len(),
+                    values.len(),
+                    "the window function on {} must return a value for every row",
+                    series.name()
+                );
+                rows.iter()
+                    .zip(values)
+                    .for_each(|(row, value)| positions[*row as usize] = Some(*value as usize));
+            });
+        }
+        AggState::Aggregated(_) => all.iter().enumerate().for_each(|(group, rows)| {
+            rows.iter()
+                .for_each(|row| positions[*row as usize] = Some(group))
+        }),
+        AggState::Literal(series) => return repeat(series, rows),
+    }
+    ctx.series().take_opt_indices(&positions)
+}
@@ -0,0 +1,37 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::dataframe::groupby::GroupsProxy;
+
+// State shared by the expressions of a plan node while they are evaluated on the same frame
+#[derive(Default)]
+pub struct ExecutionState {
+    // Groups of the window expressions, by their partition and order keys
+    group_tuples: Mutex<HashMap<String, Arc<GroupsProxy>>>,
+}
+
+impl ExecutionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the cached groups of `key`, computing them on the first call. The lock isn't
+    // held while computing, since the keys can contain window expressions themselves.
+    pub fn groups_or_insert_with<F>(&self, key: &str, compute: F) -> Arc<GroupsProxy>
+    where
+        F: FnOnce() -> GroupsProxy,
+    {
+        if let Some(groups) = self.group_tuples.lock().unwrap().get(key) {
+            return groups.clone();
+        }
+        let groups = Arc::new(compute());
+        self.group_tuples
+            .lock()
+            .unwrap()
+            .entry(key.to_string())
+            .or_insert(groups)
+            .clone()
+    }
+}
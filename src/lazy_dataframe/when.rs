@@ -0,0 +1,49 @@
+use super::expr::Expr;
+
+// Builds a conditional expression. `when(a).then(x).when(b).then(y).otherwise(z)` is `x` where
+// `a` is true, else `y` where `b` is true, else `z`. Null predicates count as false.
+pub fn when(predicate: Expr) -> When {
+    When {
+        branches: vec![],
+        predicate,
+    }
+}
+
+pub struct When {
+    branches: Vec<(Expr, Expr)>,
+    predicate: Expr,
+}
+
+pub struct Then {
+    branches: Vec<(Expr, Expr)>,
+}
+
+impl When {
+    pub fn then(mut self, expr: Expr) -> Then {
+        self.branches.push((self.predicate, expr));
+        Then {
+            branches: self.branches,
+        }
+    }
+}
+
+impl Then {
+    pub fn when(self, predicate: Expr) -> When {
+        When {
+            branches: self.branches,
+            predicate,
+        }
+    }
+
+    // The branches become nested ternaries, the first one outermost
+    pub fn otherwise(self, expr: Expr) -> Expr {
+        self.branches
+            .into_iter()
+            .rev()
+            .fold(expr, |falsy, (predicate, truthy)| Expr::Ternary {
+                predicate: Box::new(predicate),
+                truthy: Box::new(truthy),
+                falsy: Box::new(falsy),
+            })
+    }
+}
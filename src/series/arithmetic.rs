@@ -0,0 +1,30 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::types::DataType;
+
+use super::{cast::numeric_supertype, constructor::IntoSeries, Series};
+
+// Arithmetic between two Series. Both sides are cast to their common numeric type first.
+macro_rules! impl_series_arithmetic {
+    ($trait: ident, $method: ident) => {
+        impl $trait<&Series> for &Series {
+            type Output = Series;
+
+            fn $method(self, rhs: &Series) -> Series {
+                let dtype = numeric_supertype(&self.dtype(), &rhs.dtype());
+                let lhs = self.cast(&dtype);
+                let rhs = rhs.cast(&dtype);
+                match dtype {
+                    DataType::Int32 => lhs.i32().$method(rhs.i32()).into_series(),
+                    DataType::Float64 => lhs.f64().$method(rhs.f64()).into_series(),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+impl_series_arithmetic!(Add, add);
+impl_series_arithmetic!(Sub, sub);
+impl_series_arithmetic!(Mul, mul);
+impl_series_arithmetic!(Div, div);
@@ -0,0 +1,19 @@
+use crate::{chunked_array::builder::NewFrom, series::Series};
+
+#[test]
+fn test_series_arithmetic() {
+    let a = Series::from_vec("a", &vec![1, 2, 3]);
+    let b = Series::from_vec("b", &vec![4, 5, 6]);
+    assert_eq!(&a * &b, Series::from_vec("a", &vec![4, 10, 18]));
+    assert_eq!(&b - &a, Series::from_vec("b", &vec![3, 3, 3]));
+}
+
+#[test]
+fn test_series_arithmetic_casts_to_float() {
+    let a = Series::from_slice_options("a", &vec![Some(1), None, Some(3)]);
+    let b = Series::from_vec("b", &vec![0.5, 1.0, 1.5]);
+    assert_eq!(
+        &a + &b,
+        Series::from_slice_options("a", &vec![Some(1.5), None, Some(4.5)])
+    );
+}
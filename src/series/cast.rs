@@ -0,0 +1,64 @@
+use arrow2::{
+    array::new_null_array,
+    compute::cast::{cast, CastOptions},
+};
+
+use crate::{
+    chunked_array::types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+    little_arrow::types::ArrayRef,
+    types::DataType,
+};
+
+use super::{constructor::IntoSeries, Series};
+
+impl Series {
+    pub fn from_chunks_and_dtype(name: &str, chunks: Vec<ArrayRef>, dtype: &DataType) -> Series {
+        match dtype {
+            DataType::Int32 => I32Chunked::from_chunks(name, chunks).into_series(),
+            DataType::Float64 => F64Chunked::from_chunks(name, chunks).into_series(),
+            DataType::Utf8 => Utf8Chunked::from_chunks(name, chunks).into_series(),
+            DataType::Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+        }
+    }
+
+    pub fn full_null(name: &str, length: usize, dtype: &DataType) -> Series {
+        let chunks = vec![new_null_array(dtype.to_array_type(), length)];
+        Series::from_chunks_and_dtype(name, chunks, dtype)
+    }
+
+    // Casts the values with the arrow2 cast kernel. Values that can't be cast become null.
+    pub fn cast(&self, dtype: &DataType) -> Series {
+        if &self.dtype() == dtype {
+            return self.clone();
+        }
+        let chunks = self
+            .chunks()
+            .iter()
+            .map(|arr| cast(arr.as_ref(), &dtype.to_array_type(), CastOptions::default()).unwrap())
+            .collect::<Vec<ArrayRef>>();
+        Series::from_chunks_and_dtype(self.name(), chunks, dtype)
+    }
+}
+
+// Returns the type both sides of an arithmetic operation are cast to
+pub fn numeric_supertype(left: &DataType, right: &DataType) -> DataType {
+    match (left, right) {
+        (DataType::Int32, DataType::Int32) => DataType::Int32,
+        (DataType::Int32 | DataType::Float64, DataType::Int32 | DataType::Float64) => {
+            DataType::Float64
+        }
+        _ => panic!("Arithmetic is not supported between {left:?} and {right:?}"),
+    }
+}
+
+// Returns the type values of both types are cast to when they end up in the same column, if
+// there is one
+pub fn get_supertype(left: &DataType, right: &DataType) -> Option<DataType> {
+    match (left, right) {
+        (left, right) if left == right => Some(left.clone()),
+        (DataType::Int32 | DataType::Float64, DataType::Int32 | DataType::Float64) => {
+            Some(DataType::Float64)
+        }
+        _ => None,
+    }
+}
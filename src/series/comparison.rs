@@ -3,15 +3,48 @@ use crate::{
     types::DataType,
 };
 
-use super::Series;
+use super::{cast::numeric_supertype, Series};
+
+// Numeric sides are cast to their supertype, other types must match
+macro_rules! compare_series {
+    ($lhs: ident, $rhs: ident, $method: ident) => {{
+        let (lhs, rhs) = if $lhs.dtype() == $rhs.dtype() {
+            ($lhs.clone(), $rhs.clone())
+        } else {
+            let dtype = numeric_supertype(&$lhs.dtype(), &$rhs.dtype());
+            ($lhs.cast(&dtype), $rhs.cast(&dtype))
+        };
+        match lhs.dtype() {
+            DataType::Int32 => lhs.i32().$method(rhs.i32()),
+            DataType::Float64 => lhs.f64().$method(rhs.f64()),
+            DataType::Utf8 => lhs.utf8().$method(rhs.utf8()),
+            DataType::Boolean => lhs.bool().$method(rhs.bool()),
+        }
+    }};
+}
 
 impl ChunkCompare<&Series> for Series {
     fn equal(&self, rhs: &Series) -> BooleanChunked {
-        assert_eq!(self.dtype(), rhs.dtype());
-        match self.dtype() {
-            DataType::Int32 => self.i32().equal(rhs.i32()),
-            DataType::Utf8 => self.utf8().equal(rhs.utf8()),
-            DataType::Boolean => self.bool().equal(rhs.bool()),
-        }
+        compare_series!(self, rhs, equal)
+    }
+
+    fn not_equal(&self, rhs: &Series) -> BooleanChunked {
+        compare_series!(self, rhs, not_equal)
+    }
+
+    fn gt(&self, rhs: &Series) -> BooleanChunked {
+        compare_series!(self, rhs, gt)
+    }
+
+    fn gt_eq(&self, rhs: &Series) -> BooleanChunked {
+        compare_series!(self, rhs, gt_eq)
+    }
+
+    fn lt(&self, rhs: &Series) -> BooleanChunked {
+        compare_series!(self, rhs, lt)
+    }
+
+    fn lt_eq(&self, rhs: &Series) -> BooleanChunked {
+        compare_series!(self, rhs, lt_eq)
     }
 }
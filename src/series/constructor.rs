@@ -5,10 +5,13 @@ use arrow2::array::{MutableArray, MutableUtf8Array};
 use crate::{
     chunked_array::{
         builder::NewFrom,
-        types::{BooleanType, I32Chunked, I32Type, Utf8Chunked, Utf8Type},
+        types::{
+            AnyValue, BooleanChunked, BooleanType, F64Chunked, Float64Type, I32Chunked, I32Type,
+            Utf8Chunked, Utf8Type,
+        },
         ChunkedArray,
     },
-    types::LittleDataType,
+    types::{DataType, LittleDataType},
 };
 
 use super::{series_trait::SeriesTrait, Series, SeriesWrap};
@@ -38,6 +41,7 @@ macro_rules! impl_new_from {
 
 impl_new_from!(bool, BooleanType);
 impl_new_from!(i32, I32Type);
+impl_new_from!(f64, Float64Type);
 impl_new_from!(&str, Utf8Type);
 
 pub trait IntoSeries {
@@ -58,3 +62,52 @@ impl IntoSeries for Series {
         self
     }
 }
+
+impl Series {
+    // Builds a Series of `dtype` from values that must match it, except for Int32 values
+    // which are accepted in Float64 Series
+    pub fn from_any_values<'a>(
+        name: &str,
+        dtype: &DataType,
+        values: impl Iterator<Item = Option<AnyValue<'a>>>,
+    ) -> Series {
+        let mismatch = |value: AnyValue| -> ! {
+            panic!("cannot put {value:?} in column {name:?} of {dtype:?}")
+        };
+        match dtype {
+            DataType::Int32 => I32Chunked::from_iter_options(
+                name,
+                values.map(|value| match value? {
+                    AnyValue::Int32(value) => Some(value),
+                    value => mismatch(value),
+                }),
+            )
+            .into_series(),
+            DataType::Float64 => F64Chunked::from_iter_options(
+                name,
+                values.map(|value| match value? {
+                    AnyValue::Float64(value) => Some(value),
+                    AnyValue::Int32(value) => Some(value as f64),
+                    value => mismatch(value),
+                }),
+            )
+            .into_series(),
+            DataType::Utf8 => Utf8Chunked::from_iter_options(
+                name,
+                values.map(|value| match value? {
+                    AnyValue::Utf8(value) => Some(value),
+                    value => mismatch(value),
+                }),
+            )
+            .into_series(),
+            DataType::Boolean => BooleanChunked::from_iter_options(
+                name,
+                values.map(|value| match value? {
+                    AnyValue::Boolean(value) => Some(value),
+                    value => mismatch(value),
+                }),
+            )
+            .into_series(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use arrow2::array::{Array, BooleanArray};
 
 use crate::{
-    chunked_array::types::{BooleanChunked, I32Chunked, Utf8Chunked},
+    chunked_array::types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
     series::series_trait::SeriesTrait,
     types::DataType,
 };
@@ -23,6 +23,13 @@ impl Series {
         unsafe { &*(self.0.as_ref() as *const dyn SeriesTrait as *const I32Chunked) }
     }
 
+    pub fn f64(&self) -> &F64Chunked {
+        if self.dtype() != DataType::Float64 {
+            panic!("Expected Series to contain F64 type")
+        }
+        unsafe { &*(self.0.as_ref() as *const dyn SeriesTrait as *const F64Chunked) }
+    }
+
     pub fn utf8(&self) -> &Utf8Chunked {
         if self.dtype() != DataType::Utf8 {
             panic!("Expected Series to contain Utf8 type")
@@ -1,16 +1,23 @@
-use std::{cmp::min, collections::hash_map::RandomState, sync::Arc};
+use std::{collections::hash_map::RandomState, sync::Arc};
 
 use crate::{
     chunked_array::{
+        aggregate::QuantileInterpolOptions,
         builder::NewFrom,
         chunk_equal::ChunkEqualElement,
         chunk_get::ChunkGet,
         filter::ChunkedArrayFilter,
-        types::{AnyValue, BooleanChunked, I32Chunked, Utf8Chunked},
+        rank::RankOptions,
+        rolling_window::RollingOptions,
+        sort::{ChunkedArgSort, SortOptions},
+        top_k::ChunkedArgTopK,
+        types::{AnyValue, BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+        IsSorted,
     },
     core::field::Field,
     dataframe::groupby::GroupsProxy,
     hashing::VecHash,
+    little_arrow::types::ArrayRef,
     types::DataType,
 };
 
@@ -29,6 +36,10 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         &self.0.name
     }
 
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.0.chunks
+    }
+
     fn vec_hash(&self, hasher: RandomState, buf: &mut Vec<u64>) {
         self.0.vec_hash(hasher, buf)
     }
@@ -37,8 +48,8 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         self.0.vec_hash_combine(hasher, buf)
     }
 
-    fn rechunk(&self) -> super::Series {
-        todo!()
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_series()
     }
 
     fn slice(&self, offset: usize, length: usize) -> Series {
@@ -50,6 +61,10 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         self.0.get_value(idx)
     }
 
+    fn iter_any_values(&self) -> Box<dyn Iterator<Item = Option<AnyValue<'_>>> + '_> {
+        Box::new(self.0.into_iter().map(|value| value.map(AnyValue::Boolean)))
+    }
+
     unsafe fn equal_element(
         &self,
         idx_self: usize,
@@ -69,6 +84,23 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
                     AnyValue::Boolean(v) => v,
                     AnyValue::Utf8(_) => unreachable!(),
                     AnyValue::Int32(_) => unreachable!(),
+                    AnyValue::Float64(_) => unreachable!(),
+                })
+            })
+            .collect::<Vec<Option<bool>>>();
+        Series::from_slice_options(self.name(), &value)
+    }
+
+    fn take_opt_indices(&self, indices: &[Option<usize>]) -> Series {
+        let value = indices
+            .iter()
+            .map(|idx| {
+                let v = idx.and_then(|idx| self.get(idx));
+                v.map(|v| match v {
+                    AnyValue::Boolean(v) => v,
+                    AnyValue::Utf8(_) => unreachable!(),
+                    AnyValue::Int32(_) => unreachable!(),
+                    AnyValue::Float64(_) => unreachable!(),
                 })
             })
             .collect::<Vec<Option<bool>>>();
@@ -86,8 +118,88 @@ impl SeriesTrait for SeriesWrap<BooleanChunked> {
         }
     }
 
+    fn is_sorted_flag(&self) -> IsSorted {
+        self.0.is_sorted_flag()
+    }
+
+    fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        self.0.set_sorted_flag(sorted)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.name = name.into()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> Vec<usize> {
+        self.0.arg_sort(options)
+    }
+
+    fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize> {
+        self.0.arg_top_k(k, options)
+    }
+
+    fn rank(&self, options: RankOptions) -> Series {
+        self.0.rank(options)
+    }
+
+    fn percent_rank(&self, descending: bool) -> Series {
+        self.0.percent_rank(descending)
+    }
+
+    fn ntile(&self, n: u32, descending: bool) -> Series {
+        self.0.ntile(n, descending)
+    }
+
+    fn cum_count(&self) -> Series {
+        self.0.cum_count().into_series()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(self.0.clone()))
+    }
+
     fn agg_min(&self, groups: &GroupsProxy) -> Series {
-        todo!()
+        self.0.agg_min(groups)
+    }
+
+    fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_max(groups)
+    }
+
+    fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_sum(groups)
+    }
+
+    fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_mean(groups)
+    }
+
+    fn agg_count(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_count(groups)
+    }
+
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_n_unique(groups)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn min_as_series(&self) -> Series {
+        BooleanChunked::from_iter_options(self.name(), std::iter::once(self.0.min())).into_series()
+    }
+
+    fn max_as_series(&self) -> Series {
+        BooleanChunked::from_iter_options(self.name(), std::iter::once(self.0.max())).into_series()
+    }
+
+    fn sum_as_series(&self) -> Series {
+        I32Chunked::from_iter_values(self.name(), std::iter::once(self.0.sum())).into_series()
+    }
+
+    fn mean_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.mean())).into_series()
     }
 }
 
@@ -104,6 +216,10 @@ impl SeriesTrait for SeriesWrap<I32Chunked> {
         &self.0.name
     }
 
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.0.chunks
+    }
+
     fn vec_hash(&self, hasher: RandomState, buf: &mut Vec<u64>) {
         self.0.vec_hash(hasher, buf)
     }
@@ -112,11 +228,11 @@ impl SeriesTrait for SeriesWrap<I32Chunked> {
         self.0.vec_hash_combine(hasher, buf)
     }
 
-    fn rechunk(&self) -> super::Series {
-        todo!()
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_series()
     }
 
-    fn slice(&self, offset: usize, length: usize) -> super::Series {
+    fn slice(&self, offset: usize, length: usize) -> Series {
         let chunked = self.0.slice(offset, length);
         chunked.into_series()
     }
@@ -125,6 +241,10 @@ impl SeriesTrait for SeriesWrap<I32Chunked> {
         self.0.get_value(idx)
     }
 
+    fn iter_any_values(&self) -> Box<dyn Iterator<Item = Option<AnyValue<'_>>> + '_> {
+        Box::new(self.0.into_iter().map(|value| value.map(AnyValue::Int32)))
+    }
+
     unsafe fn equal_element(
         &self,
         idx_self: usize,
@@ -144,6 +264,23 @@ impl SeriesTrait for SeriesWrap<I32Chunked> {
                     AnyValue::Boolean(_) => unreachable!(),
                     AnyValue::Utf8(_) => unreachable!(),
                     AnyValue::Int32(v) => v,
+                    AnyValue::Float64(_) => unreachable!(),
+                })
+            })
+            .collect::<Vec<Option<i32>>>();
+        Series::from_slice_options(self.name(), &value)
+    }
+
+    fn take_opt_indices(&self, indices: &[Option<usize>]) -> Series {
+        let value = indices
+            .iter()
+            .map(|idx| {
+                let v = idx.and_then(|idx| self.get(idx));
+                v.map(|v| match v {
+                    AnyValue::Boolean(_) => unreachable!(),
+                    AnyValue::Utf8(_) => unreachable!(),
+                    AnyValue::Int32(v) => v,
+                    AnyValue::Float64(_) => unreachable!(),
                 })
             })
             .collect::<Vec<Option<i32>>>();
@@ -161,23 +298,486 @@ impl SeriesTrait for SeriesWrap<I32Chunked> {
         }
     }
 
+    fn is_sorted_flag(&self) -> IsSorted {
+        self.0.is_sorted_flag()
+    }
+
+    fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        self.0.set_sorted_flag(sorted)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.name = name.into()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> Vec<usize> {
+        self.0.arg_sort(options)
+    }
+
+    fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize> {
+        self.0.arg_top_k(k, options)
+    }
+
+    fn rank(&self, options: RankOptions) -> Series {
+        self.0.rank(options)
+    }
+
+    fn percent_rank(&self, descending: bool) -> Series {
+        self.0.percent_rank(descending)
+    }
+
+    fn ntile(&self, n: u32, descending: bool) -> Series {
+        self.0.ntile(n, descending)
+    }
+
+    fn cum_count(&self) -> Series {
+        self.0.cum_count().into_series()
+    }
+
+    fn cum_sum(&self) -> Series {
+        self.0.cum_sum().into_series()
+    }
+
+    fn cum_prod(&self) -> Series {
+        self.0.cum_prod().into_series()
+    }
+
+    fn cum_min(&self) -> Series {
+        self.0.cum_min().into_series()
+    }
+
+    fn cum_max(&self) -> Series {
+        self.0.cum_max().into_series()
+    }
+
+    fn diff(&self, periods: i64) -> Series {
+        self.0.diff(periods).into_series()
+    }
+
+    fn pct_change(&self, periods: i64) -> Series {
+        self.0.pct_change(periods).into_series()
+    }
+
+    fn rolling_sum(&self, options: &RollingOptions) -> Series {
+        let output = self.0.rolling_sum(options).into_series();
+        // Without weights the values stay integers
+        match options.weights {
+            Some(_) => output,
+            None => output.cast(&DataType::Int32),
+        }
+    }
+
+    fn rolling_mean(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_mean(options).into_series()
+    }
+
+    fn rolling_min(&self, options: &RollingOptions) -> Series {
+        let output = self.0.rolling_min(options).into_series();
+        // Without weights the values stay integers
+        match options.weights {
+            Some(_) => output,
+            None => output.cast(&DataType::Int32),
+        }
+    }
+
+    fn rolling_max(&self, options: &RollingOptions) -> Series {
+        let output = self.0.rolling_max(options).into_series();
+        // Without weights the values stay integers
+        match options.weights {
+            Some(_) => output,
+            None => output.cast(&DataType::Int32),
+        }
+    }
+
+    fn rolling_std(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_std(options).into_series()
+    }
+
+    fn rolling_quantile(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+        options: &RollingOptions,
+    ) -> Series {
+        self.0
+            .rolling_quantile(quantile, interpol, options)
+            .into_series()
+    }
+
+    fn shift(&self, periods: i64, fill_value: Option<AnyValue>) -> Series {
+        let fill_value = fill_value.map(|value| match value {
+            AnyValue::Int32(v) => v,
+            value => panic!("cannot fill an Int32 series with {value:?}"),
+        });
+        self.0.shift(periods, fill_value).into_series()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(self.0.clone()))
+    }
+
     fn agg_min(&self, groups: &GroupsProxy) -> Series {
-        let arr = self.0.iter_primitive().next().unwrap();
+        self.0.agg_min(groups)
+    }
+
+    fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_max(groups)
+    }
+
+    fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_sum(groups)
+    }
+
+    fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_mean(groups)
+    }
+
+    fn agg_count(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_count(groups)
+    }
 
-        let values: Vec<i32> = groups
-            .all
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_n_unique(groups)
+    }
+
+    fn agg_quantile(
+        &self,
+        groups: &GroupsProxy,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Series {
+        self.0.agg_quantile(groups, quantile, interpol)
+    }
+
+    fn agg_median(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_median(groups)
+    }
+
+    fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.0.agg_var(groups, ddof)
+    }
+
+    fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.0.agg_std(groups, ddof)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn min_as_series(&self) -> Series {
+        I32Chunked::from_iter_options(self.name(), std::iter::once(self.0.min())).into_series()
+    }
+
+    fn max_as_series(&self) -> Series {
+        I32Chunked::from_iter_options(self.name(), std::iter::once(self.0.max())).into_series()
+    }
+
+    fn sum_as_series(&self) -> Series {
+        I32Chunked::from_iter_values(self.name(), std::iter::once(self.0.sum())).into_series()
+    }
+
+    fn mean_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.mean())).into_series()
+    }
+
+    fn quantile_as_series(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Series {
+        let value = self.0.quantile(quantile, interpol);
+        F64Chunked::from_iter_options(self.name(), std::iter::once(value)).into_series()
+    }
+
+    fn median_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.median())).into_series()
+    }
+
+    fn var_as_series(&self, ddof: u8) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.var(ddof))).into_series()
+    }
+
+    fn std_as_series(&self, ddof: u8) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.std(ddof))).into_series()
+    }
+}
+
+impl SeriesTrait for SeriesWrap<F64Chunked> {
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn len(&self) -> usize {
+        self.0.length
+    }
+
+    fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.0.chunks
+    }
+
+    fn vec_hash(&self, hasher: RandomState, buf: &mut Vec<u64>) {
+        self.0.vec_hash(hasher, buf)
+    }
+
+    fn vec_hash_combine(&self, hasher: RandomState, buf: &mut Vec<u64>) {
+        self.0.vec_hash_combine(hasher, buf)
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_series()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Series {
+        let chunked = self.0.slice(offset, length);
+        chunked.into_series()
+    }
+
+    fn get(&self, idx: usize) -> Option<AnyValue> {
+        self.0.get_value(idx)
+    }
+
+    fn iter_any_values(&self) -> Box<dyn Iterator<Item = Option<AnyValue<'_>>> + '_> {
+        Box::new(self.0.into_iter().map(|value| value.map(AnyValue::Float64)))
+    }
+
+    unsafe fn equal_element(
+        &self,
+        idx_self: usize,
+        other_series: &Series,
+        idx_other: usize,
+    ) -> bool {
+        self.0.equal_element(idx_self, other_series, idx_other)
+    }
+
+    fn take_indices(&self, indices: &[usize]) -> Series {
+        // TODO: Optimizations since performing get is expensive
+        let value = indices
             .iter()
-            .enumerate()
-            .map(|(_, indices)| {
-                // TODO: Parallelize
-                let min = indices.iter().fold(i32::MAX, |acc, &idx| {
-                    let v = arr.get(idx as usize).unwrap();
-                    min(acc, v)
-                });
-                min
+            .map(|idx| {
+                let v = self.get(*idx);
+                v.map(|v| match v {
+                    AnyValue::Boolean(_) => unreachable!(),
+                    AnyValue::Utf8(_) => unreachable!(),
+                    AnyValue::Int32(_) => unreachable!(),
+                    AnyValue::Float64(v) => v,
+                })
             })
-            .collect();
-        Series::new(self.name(), &values)
+            .collect::<Vec<Option<f64>>>();
+        Series::from_slice_options(self.name(), &value)
+    }
+
+    fn take_opt_indices(&self, indices: &[Option<usize>]) -> Series {
+        let value = indices
+            .iter()
+            .map(|idx| {
+                let v = idx.and_then(|idx| self.get(idx));
+                v.map(|v| match v {
+                    AnyValue::Boolean(_) => unreachable!(),
+                    AnyValue::Utf8(_) => unreachable!(),
+                    AnyValue::Int32(_) => unreachable!(),
+                    AnyValue::Float64(v) => v,
+                })
+            })
+            .collect::<Vec<Option<f64>>>();
+        Series::from_slice_options(self.name(), &value)
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Series {
+        self.0.filter(filter).into_series()
+    }
+
+    fn field(&self) -> Field {
+        Field {
+            name: self.name().into(),
+            dtype: self.dtype(),
+        }
+    }
+
+    fn is_sorted_flag(&self) -> IsSorted {
+        self.0.is_sorted_flag()
+    }
+
+    fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        self.0.set_sorted_flag(sorted)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.name = name.into()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> Vec<usize> {
+        self.0.arg_sort(options)
+    }
+
+    fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize> {
+        self.0.arg_top_k(k, options)
+    }
+
+    fn rank(&self, options: RankOptions) -> Series {
+        self.0.rank(options)
+    }
+
+    fn percent_rank(&self, descending: bool) -> Series {
+        self.0.percent_rank(descending)
+    }
+
+    fn ntile(&self, n: u32, descending: bool) -> Series {
+        self.0.ntile(n, descending)
+    }
+
+    fn cum_count(&self) -> Series {
+        self.0.cum_count().into_series()
+    }
+
+    fn cum_sum(&self) -> Series {
+        self.0.cum_sum().into_series()
+    }
+
+    fn cum_prod(&self) -> Series {
+        self.0.cum_prod().into_series()
+    }
+
+    fn cum_min(&self) -> Series {
+        self.0.cum_min().into_series()
+    }
+
+    fn cum_max(&self) -> Series {
+        self.0.cum_max().into_series()
+    }
+
+    fn diff(&self, periods: i64) -> Series {
+        self.0.diff(periods).into_series()
+    }
+
+    fn pct_change(&self, periods: i64) -> Series {
+        self.0.pct_change(periods).into_series()
+    }
+
+    fn rolling_sum(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_sum(options).into_series()
+    }
+
+    fn rolling_mean(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_mean(options).into_series()
+    }
+
+    fn rolling_min(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_min(options).into_series()
+    }
+
+    fn rolling_max(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_max(options).into_series()
+    }
+
+    fn rolling_std(&self, options: &RollingOptions) -> Series {
+        self.0.rolling_std(options).into_series()
+    }
+
+    fn rolling_quantile(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+        options: &RollingOptions,
+    ) -> Series {
+        self.0
+            .rolling_quantile(quantile, interpol, options)
+            .into_series()
+    }
+
+    fn shift(&self, periods: i64, fill_value: Option<AnyValue>) -> Series {
+        let fill_value = fill_value.map(|value| match value {
+            AnyValue::Int32(v) => v as f64,
+            AnyValue::Float64(v) => v,
+            value => panic!("cannot fill a Float64 series with {value:?}"),
+        });
+        self.0.shift(periods, fill_value).into_series()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(self.0.clone()))
+    }
+
+    fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_min(groups)
+    }
+
+    fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_max(groups)
+    }
+
+    fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_sum(groups)
+    }
+
+    fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_mean(groups)
+    }
+
+    fn agg_count(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_count(groups)
+    }
+
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_n_unique(groups)
+    }
+
+    fn agg_quantile(
+        &self,
+        groups: &GroupsProxy,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Series {
+        self.0.agg_quantile(groups, quantile, interpol)
+    }
+
+    fn agg_median(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_median(groups)
+    }
+
+    fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.0.agg_var(groups, ddof)
+    }
+
+    fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.0.agg_std(groups, ddof)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn min_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.min())).into_series()
+    }
+
+    fn max_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.max())).into_series()
+    }
+
+    fn sum_as_series(&self) -> Series {
+        F64Chunked::from_iter_values(self.name(), std::iter::once(self.0.sum())).into_series()
+    }
+
+    fn mean_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.mean())).into_series()
+    }
+
+    fn quantile_as_series(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Series {
+        let value = self.0.quantile(quantile, interpol);
+        F64Chunked::from_iter_options(self.name(), std::iter::once(value)).into_series()
+    }
+
+    fn median_as_series(&self) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.median())).into_series()
+    }
+
+    fn var_as_series(&self, ddof: u8) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.var(ddof))).into_series()
+    }
+
+    fn std_as_series(&self, ddof: u8) -> Series {
+        F64Chunked::from_iter_options(self.name(), std::iter::once(self.0.std(ddof))).into_series()
     }
 }
 
@@ -194,6 +794,10 @@ impl SeriesTrait for SeriesWrap<Utf8Chunked> {
         &self.0.name
     }
 
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.0.chunks
+    }
+
     fn vec_hash(&self, hasher: RandomState, buf: &mut Vec<u64>) {
         self.0.vec_hash(hasher, buf)
     }
@@ -202,11 +806,11 @@ impl SeriesTrait for SeriesWrap<Utf8Chunked> {
         self.0.vec_hash_combine(hasher, buf)
     }
 
-    fn rechunk(&self) -> super::Series {
-        todo!()
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_series()
     }
 
-    fn slice(&self, offset: usize, length: usize) -> super::Series {
+    fn slice(&self, offset: usize, length: usize) -> Series {
         let chunked = self.0.slice(offset, length);
         chunked.into_series()
     }
@@ -215,6 +819,10 @@ impl SeriesTrait for SeriesWrap<Utf8Chunked> {
         self.0.get_value(idx)
     }
 
+    fn iter_any_values(&self) -> Box<dyn Iterator<Item = Option<AnyValue<'_>>> + '_> {
+        Box::new(self.0.into_iter().map(|value| value.map(AnyValue::Utf8)))
+    }
+
     unsafe fn equal_element(
         &self,
         idx_self: usize,
@@ -233,7 +841,24 @@ impl SeriesTrait for SeriesWrap<Utf8Chunked> {
                 v.map(|v| match v {
                     AnyValue::Boolean(_) => unreachable!(),
                     AnyValue::Utf8(v) => v,
-                    AnyValue::Int32(v) => unreachable!(),
+                    AnyValue::Int32(_) => unreachable!(),
+                    AnyValue::Float64(_) => unreachable!(),
+                })
+            })
+            .collect::<Vec<Option<&str>>>();
+        Series::from_slice_options(self.name(), &value)
+    }
+
+    fn take_opt_indices(&self, indices: &[Option<usize>]) -> Series {
+        let value = indices
+            .iter()
+            .map(|idx| {
+                let v = idx.and_then(|idx| self.get(idx));
+                v.map(|v| match v {
+                    AnyValue::Boolean(_) => unreachable!(),
+                    AnyValue::Utf8(v) => v,
+                    AnyValue::Int32(_) => unreachable!(),
+                    AnyValue::Float64(_) => unreachable!(),
                 })
             })
             .collect::<Vec<Option<&str>>>();
@@ -251,7 +876,87 @@ impl SeriesTrait for SeriesWrap<Utf8Chunked> {
         }
     }
 
+    fn is_sorted_flag(&self) -> IsSorted {
+        self.0.is_sorted_flag()
+    }
+
+    fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        self.0.set_sorted_flag(sorted)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.name = name.into()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> Vec<usize> {
+        self.0.arg_sort(options)
+    }
+
+    fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize> {
+        self.0.arg_top_k(k, options)
+    }
+
+    fn rank(&self, options: RankOptions) -> Series {
+        self.0.rank(options)
+    }
+
+    fn percent_rank(&self, descending: bool) -> Series {
+        self.0.percent_rank(descending)
+    }
+
+    fn ntile(&self, n: u32, descending: bool) -> Series {
+        self.0.ntile(n, descending)
+    }
+
+    fn cum_count(&self) -> Series {
+        self.0.cum_count().into_series()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(self.0.clone()))
+    }
+
     fn agg_min(&self, groups: &GroupsProxy) -> Series {
-        todo!()
+        self.0.agg_min(groups)
+    }
+
+    fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_max(groups)
+    }
+
+    fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_sum(groups)
+    }
+
+    fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_mean(groups)
+    }
+
+    fn agg_count(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_count(groups)
+    }
+
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Series {
+        self.0.agg_n_unique(groups)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn min_as_series(&self) -> Series {
+        Utf8Chunked::from_iter_options(self.name(), std::iter::once(self.0.min())).into_series()
+    }
+
+    fn max_as_series(&self) -> Series {
+        Utf8Chunked::from_iter_options(self.name(), std::iter::once(self.0.max())).into_series()
+    }
+
+    fn sum_as_series(&self) -> Series {
+        Utf8Chunked::full_null(self.name(), 1).into_series()
+    }
+
+    fn mean_as_series(&self) -> Series {
+        F64Chunked::full_null(self.name(), 1).into_series()
     }
 }
@@ -1,18 +1,33 @@
 use std::{fmt::Debug, ops::Deref, sync::Arc};
 
 use crate::{
-    chunked_array::types::{BooleanChunked, I32Chunked, Utf8Chunked},
+    chunked_array::{
+        sort::{sorted_flag, SortOptions},
+        types::{BooleanChunked, F64Chunked, I32Chunked, Utf8Chunked},
+        IsSorted,
+    },
+    dataframe::{unique::UniqueKeepStrategy, DataFrame},
     types::DataType,
 };
 
+use self::constructor::IntoSeries;
+
 use self::series_trait::SeriesTrait;
 
+pub mod arithmetic;
+mod arithmetic_test;
+pub mod cast;
 pub mod comparison;
 pub mod constructor;
 pub mod constructor_test;
 pub mod downcast;
 pub mod implementations;
+pub mod nulls;
+mod nulls_test;
+mod mod_test;
 pub mod series_trait;
+pub mod zip_with;
+mod zip_with_test;
 
 #[derive(Clone)]
 pub struct Series(pub Arc<dyn SeriesTrait>);
@@ -27,6 +42,102 @@ impl Deref for Series {
     }
 }
 
+impl Series {
+    pub fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        if Arc::get_mut(&mut self.0).is_none() {
+            self.0 = self.0.clone_inner();
+        }
+        Arc::get_mut(&mut self.0).unwrap().set_sorted_flag(sorted)
+    }
+
+    // The `k` largest values if `descending`, otherwise the `k` smallest, in sorted order.
+    // Nulls only fill the output when there are fewer than `k` values.
+    pub fn top_k(&self, k: usize, descending: bool) -> Series {
+        let options = SortOptions {
+            descending,
+            nulls_last: true,
+        };
+        let mut output = self.take_indices(&self.arg_top_k(k, options));
+        if output.null_count() == 0 {
+            output.set_sorted_flag(sorted_flag(descending));
+        }
+        output
+    }
+
+    // The distinct values, in the order of their first occurrence
+    pub fn unique(&self) -> Series {
+        let df = DataFrame::new_no_checks(vec![self.clone()]);
+        df.unique(None, UniqueKeepStrategy::First, true).get(0)
+    }
+
+    // Number of distinct values, counting null as a value
+    pub fn n_unique(&self) -> usize {
+        if self.len() == 0 {
+            return 0;
+        }
+        let df = DataFrame::new_no_checks(vec![self.clone()]);
+        df.compute_group_proxy(vec![self.clone()], false).len()
+    }
+
+    // Marks the values that appear more than once
+    pub fn is_duplicated(&self) -> Series {
+        let df = DataFrame::new_no_checks(vec![self.clone()]);
+        let mut is_duplicated = df.is_duplicated(None).into_series();
+        is_duplicated.rename(self.name());
+        is_duplicated
+    }
+
+    // Marks the values that appear only once
+    pub fn is_unique(&self) -> Series {
+        let df = DataFrame::new_no_checks(vec![self.clone()]);
+        let mut is_unique = df.is_unique(None).into_series();
+        is_unique.rename(self.name());
+        is_unique
+    }
+
+    // The distinct values and how many times they appear, in a column named "count". With
+    // `normalize`, the counts are divided by the length, in a column named "proportion". With
+    // `sort`, the most frequent values come first, otherwise the values are in the order of
+    // their first occurrence.
+    pub fn value_counts(&self, sort: bool, normalize: bool) -> DataFrame {
+        let counts_name = if normalize { "proportion" } else { "count" };
+        if self.name() == counts_name {
+            panic!("cannot count the values of {counts_name:?} into a column of the same name");
+        }
+        let df = DataFrame::new_no_checks(vec![self.clone()]);
+        let (first, mut counts) = if self.len() == 0 {
+            (vec![], vec![])
+        } else {
+            let groups = df.compute_group_proxy(vec![self.clone()], true);
+            let first = groups.first_indices().into_iter().flatten().collect();
+            (first, groups.group_lengths())
+        };
+        let mut order = (0..counts.len()).collect::<Vec<usize>>();
+        if sort {
+            // Stable, so that equal counts stay in the order of their values
+            order.sort_by(|a, b| counts[*b].cmp(&counts[*a]));
+            counts = order.iter().map(|idx| counts[*idx]).collect();
+        }
+        let values = self.take_indices(&order.iter().map(|idx| first[*idx]).collect::<Vec<_>>());
+        let counts = if normalize {
+            let len = self.len() as f64;
+            let proportions = counts.into_iter().map(|count| count as f64 / len);
+            F64Chunked::from_iter_values(counts_name, proportions).into_series()
+        } else {
+            let counts = counts.into_iter().map(|count| count as i32);
+            I32Chunked::from_iter_values(counts_name, counts).into_series()
+        };
+        DataFrame::new_no_checks(vec![values, counts])
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        if Arc::get_mut(&mut self.0).is_none() {
+            self.0 = self.0.clone_inner();
+        }
+        Arc::get_mut(&mut self.0).unwrap().rename(name)
+    }
+}
+
 impl Debug for Series {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let chunked_arr = self.0.as_ref();
@@ -36,6 +147,11 @@ impl Debug for Series {
                 let chunked_arr = unsafe { &*chunked_arr_ref };
                 write!(f, "{:?}", chunked_arr).unwrap();
             }
+            DataType::Float64 => {
+                let chunked_arr_ref = chunked_arr as *const dyn SeriesTrait as *const F64Chunked;
+                let chunked_arr = unsafe { &*chunked_arr_ref };
+                write!(f, "{:?}", chunked_arr).unwrap();
+            }
             DataType::Utf8 => {
                 let chunked_arr_ref = chunked_arr as *const dyn SeriesTrait as *const Utf8Chunked;
                 let chunked_arr = unsafe { &*chunked_arr_ref };
@@ -0,0 +1,46 @@
+use crate::{chunked_array::builder::NewFrom, dataframe::DataFrame, series::Series};
+
+#[test]
+fn test_value_counts() {
+    let series = Series::from_slice_options(
+        "fruit",
+        &vec![
+            Some("pear"),
+            None,
+            Some("apple"),
+            Some("apple"),
+            None,
+            Some("apple"),
+        ],
+    );
+
+    let counts = series.value_counts(false, false);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("fruit", &vec![Some("pear"), None, Some("apple")]),
+        Series::from_vec("count", &vec![1, 2, 3]),
+    ]);
+    assert_eq!(counts, expected);
+
+    let counts = series.value_counts(true, true);
+    let expected = DataFrame::new(vec![
+        Series::from_slice_options("fruit", &vec![Some("apple"), None, Some("pear")]),
+        Series::from_vec("proportion", &vec![0.5, 2.0 / 6.0, 1.0 / 6.0]),
+    ]);
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn test_value_counts_of_empty_series() {
+    let series = Series::from_vec("a", &Vec::<i32>::new());
+
+    let counts = series.value_counts(true, false);
+    assert_eq!(counts.dimensions(), (0, 2));
+    assert_eq!(counts.column("count").len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "into a column of the same name")]
+fn test_value_counts_of_proportion_column() {
+    let series = Series::from_vec("proportion", &vec![0.5, 0.5]);
+    series.value_counts(false, true);
+}
@@ -0,0 +1,149 @@
+use arrow2::compute::boolean;
+
+use crate::{
+    chunked_array::{
+        nulls::null_mask,
+        types::{AnyValue, BooleanChunked},
+    },
+    dataframe::utils::accumulate_series_vertical,
+    types::DataType,
+};
+
+use super::{cast::get_supertype, Series};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillNullStrategy {
+    // The last value before the null
+    Forward,
+    // The first value after the null
+    Backward,
+    Min,
+    Max,
+    Mean,
+    Zero,
+}
+
+impl Series {
+    pub fn is_null(&self) -> BooleanChunked {
+        null_mask(self.name(), self.chunks(), boolean::is_null)
+    }
+
+    pub fn is_not_null(&self) -> BooleanChunked {
+        null_mask(self.name(), self.chunks(), boolean::is_not_null)
+    }
+
+    // The nulls with nothing to fill them with, like leading nulls with Forward, stay null.
+    // The mean of integers is truncated to keep the dtype.
+    pub fn fill_null(&self, strategy: FillNullStrategy) -> Series {
+        if self.null_count() == 0 {
+            return self.clone();
+        }
+        let dtype = self.dtype();
+        if matches!(strategy, FillNullStrategy::Mean | FillNullStrategy::Zero)
+            && !matches!(dtype, DataType::Int32 | DataType::Float64)
+        {
+            panic!("cannot fill the nulls of a {dtype:?} series with {strategy:?}");
+        }
+        let fill = match strategy {
+            FillNullStrategy::Forward => {
+                return self.take_opt_indices(&fill_indices(self.is_valid(), false))
+            }
+            FillNullStrategy::Backward => {
+                return self.take_opt_indices(&fill_indices(self.is_valid(), true))
+            }
+            FillNullStrategy::Min => self.min_as_series(),
+            FillNullStrategy::Max => self.max_as_series(),
+            FillNullStrategy::Mean => self.mean_as_series().cast(&dtype),
+            FillNullStrategy::Zero => {
+                Series::from_any_values(self.name(), &dtype, [Some(AnyValue::Int32(0))].into_iter())
+            }
+        };
+        coalesce(&[self.clone(), fill])
+    }
+
+    pub fn fill_null_with_value(&self, value: AnyValue) -> Series {
+        let fill = Series::from_any_values(self.name(), &self.dtype(), [Some(value)].into_iter());
+        coalesce(&[self.clone(), fill])
+    }
+
+    fn is_valid(&self) -> Vec<bool> {
+        self.is_not_null()
+            .into_iter()
+            .map(|valid| valid.unwrap_or(false))
+            .collect()
+    }
+}
+
+// For every row, the position of the closest valid row at or before it, or after it with
+// `backward`
+fn fill_indices(valid: Vec<bool>, backward: bool) -> Vec<Option<usize>> {
+    let mut last = None;
+    let fill = |idx: usize| {
+        if valid[idx] {
+            last = Some(idx);
+        }
+        last
+    };
+    if backward {
+        let mut indices = (0..valid.len()).rev().map(fill).collect::<Vec<_>>();
+        indices.reverse();
+        indices
+    } else {
+        (0..valid.len()).map(fill).collect()
+    }
+}
+
+// For every row, the value of the first series that isn't null there. Series of a single value
+// are repeated for every row, unless all the series have a single value. The output is named after
+// the first series.
+pub fn coalesce(series: &[Series]) -> Series {
+    let first = series.first().expect("coalesce needs at least one series");
+    let dtype = series
+        .iter()
+        .map(|s| s.dtype())
+        .reduce(|left, right| coalesce_supertype(&left, &right))
+        .unwrap();
+    if first.len() == 0 {
+        return first.cast(&dtype);
+    }
+    let len = series
+        .iter()
+        .map(|s| s.len())
+        .find(|len| *len != 1)
+        .unwrap_or(1);
+    if let Some(s) = series.iter().find(|s| s.len() != len && s.len() != 1) {
+        panic!(
+            "cannot coalesce {} values of {} with {len} values",
+            s.len(),
+            s.name()
+        );
+    }
+    if first.len() == len && first.null_count() == 0 {
+        return first.cast(&dtype);
+    }
+
+    let valid = series.iter().map(|s| s.is_valid()).collect::<Vec<_>>();
+    let offsets = series
+        .iter()
+        .scan(0, |offset, s| {
+            *offset += s.len();
+            Some(*offset - s.len())
+        })
+        .collect::<Vec<usize>>();
+    let indices = (0..len)
+        .map(|row| {
+            series.iter().enumerate().find_map(|(idx, s)| {
+                let row = if s.len() == 1 { 0 } else { row };
+                valid[idx][row].then_some(offsets[idx] + row)
+            })
+        })
+        .collect::<Vec<_>>();
+    let stacked = accumulate_series_vertical(series.iter().map(|s| s.cast(&dtype)).collect());
+    let mut output = stacked.take_opt_indices(&indices);
+    output.rename(first.name());
+    output
+}
+
+pub fn coalesce_supertype(left: &DataType, right: &DataType) -> DataType {
+    get_supertype(left, right).unwrap_or_else(|| panic!("cannot coalesce {left:?} with {right:?}"))
+}
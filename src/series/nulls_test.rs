@@ -0,0 +1,105 @@
+use crate::{
+    chunked_array::{builder::NewFrom, types::AnyValue},
+    series::{
+        nulls::{coalesce, FillNullStrategy},
+        Series,
+    },
+};
+
+#[test]
+fn test_is_null() {
+    let series = Series::from_slice_options("a", &vec![Some(1.5), None]);
+    assert_eq!(
+        series.is_null().into_iter().collect::<Vec<_>>(),
+        vec![Some(false), Some(true)]
+    );
+    assert_eq!(
+        series.is_not_null().into_iter().collect::<Vec<_>>(),
+        vec![Some(true), Some(false)]
+    );
+}
+
+#[test]
+fn test_fill_null() {
+    let series = Series::from_slice_options("a", &vec![None, Some(1), None, None, Some(4), None]);
+    let cases = [
+        (
+            FillNullStrategy::Forward,
+            vec![None, Some(1), Some(1), Some(1), Some(4), Some(4)],
+        ),
+        (
+            FillNullStrategy::Backward,
+            vec![Some(1), Some(1), Some(4), Some(4), Some(4), None],
+        ),
+        (
+            FillNullStrategy::Min,
+            vec![Some(1), Some(1), Some(1), Some(1), Some(4), Some(1)],
+        ),
+        (
+            FillNullStrategy::Max,
+            vec![Some(4), Some(1), Some(4), Some(4), Some(4), Some(4)],
+        ),
+        (
+            FillNullStrategy::Mean,
+            vec![Some(2), Some(1), Some(2), Some(2), Some(4), Some(2)],
+        ),
+        (
+            FillNullStrategy::Zero,
+            vec![Some(0), Some(1), Some(0), Some(0), Some(4), Some(0)],
+        ),
+    ];
+    for (strategy, expected) in cases {
+        assert_eq!(
+            series.fill_null(strategy),
+            Series::from_slice_options("a", &expected),
+            "{strategy:?}"
+        );
+    }
+
+    let series = Series::from_slice_options("a", &vec![Some("x"), None]);
+    assert_eq!(
+        series.fill_null(FillNullStrategy::Forward),
+        Series::from_vec("a", &vec!["x", "x"])
+    );
+    assert_eq!(
+        series.fill_null_with_value(AnyValue::Utf8("y")),
+        Series::from_vec("a", &vec!["x", "y"])
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot fill the nulls")]
+fn test_fill_null_mean_of_strings() {
+    let series = Series::from_slice_options("a", &vec![Some("x"), None]);
+    series.fill_null(FillNullStrategy::Mean);
+}
+
+#[test]
+fn test_coalesce() {
+    let a = Series::from_slice_options("a", &vec![Some(1), None, None]);
+    let b = Series::from_slice_options("b", &vec![Some(5.0), Some(6.0), None]);
+    let c = Series::from_vec("c", &vec![0]);
+    assert_eq!(
+        coalesce(&[a, b, c]),
+        Series::from_vec("a", &vec![1.0, 6.0, 0.0])
+    );
+}
+
+#[test]
+fn test_fill_null_of_empty_series() {
+    let series = Series::from_slice_options("a", &Vec::<Option<i32>>::new());
+    assert_eq!(series.fill_null_with_value(AnyValue::Int32(0)).len(), 0);
+    assert_eq!(series.fill_null(FillNullStrategy::Zero).len(), 0);
+}
+
+#[test]
+fn test_coalesce_broadcasts_single_values() {
+    // A first series of a single value is repeated too
+    let a = Series::from_slice_options("a", &vec![None::<i32>]);
+    let b = Series::from_vec("b", &vec![1, 2, 3]);
+    assert_eq!(coalesce(&[a, b]), Series::from_vec("a", &vec![1, 2, 3]));
+
+    let a = Series::from_vec("a", &Vec::<i32>::new());
+    let b = Series::from_vec("b", &vec![0]);
+    assert_eq!(coalesce(&[a, b]).len(), 0);
+}
@@ -1,13 +1,22 @@
-use std::collections::hash_map::RandomState;
+use std::{collections::hash_map::RandomState, sync::Arc};
 
 use crate::{
-    chunked_array::types::{AnyValue, BooleanChunked},
+    chunked_array::{
+        aggregate::QuantileInterpolOptions,
+        rank::RankOptions,
+        rolling_window::RollingOptions,
+        shift::shifted_indices,
+        sort::SortOptions,
+        types::{AnyValue, BooleanChunked, F64Chunked},
+        IsSorted,
+    },
     core::field::Field,
     dataframe::groupby::GroupsProxy,
+    little_arrow::types::ArrayRef,
     types::{DataType, LittleDataType},
 };
 
-use super::Series;
+use super::{constructor::IntoSeries, Series};
 
 pub trait SeriesTrait: Send + Sync {
     fn dtype(&self) -> DataType;
@@ -16,6 +25,8 @@ pub trait SeriesTrait: Send + Sync {
 
     fn name(&self) -> &str;
 
+    fn chunks(&self) -> &Vec<ArrayRef>;
+
     fn vec_hash(&self, _hasher: RandomState, buf: &mut Vec<u64>);
 
     fn vec_hash_combine(&self, _hasher: RandomState, buf: &mut Vec<u64>);
@@ -27,6 +38,9 @@ pub trait SeriesTrait: Send + Sync {
 
     fn get(&self, idx: usize) -> Option<AnyValue>;
 
+    // The values in order, walking the chunks instead of looking up every position
+    fn iter_any_values(&self) -> Box<dyn Iterator<Item = Option<AnyValue<'_>>> + '_>;
+
     unsafe fn equal_element(
         &self,
         idx_self: usize,
@@ -36,9 +50,176 @@ pub trait SeriesTrait: Send + Sync {
 
     fn take_indices(&self, indices: &[usize]) -> Series;
 
+    // Like take_indices, but a None index gives a null
+    fn take_opt_indices(&self, indices: &[Option<usize>]) -> Series;
+
     fn filter(&self, _filter: &BooleanChunked) -> Series;
 
     fn field(&self) -> Field;
 
+    fn is_sorted_flag(&self) -> IsSorted;
+
+    fn set_sorted_flag(&mut self, sorted: IsSorted);
+
+    fn rename(&mut self, name: &str);
+
+    // Returns the positions of the values in sorted order. Equal values keep their order.
+    fn arg_sort(&self, options: SortOptions) -> Vec<usize>;
+
+    // The first `k` positions of arg_sort, found without sorting all the values
+    fn arg_top_k(&self, k: usize, options: SortOptions) -> Vec<usize>;
+
+    // Ranks the values starting from 1. Nulls have a null rank.
+    fn rank(&self, options: RankOptions) -> Series;
+
+    fn percent_rank(&self, descending: bool) -> Series;
+
+    fn ntile(&self, n: u32, descending: bool) -> Series;
+
+    // Number of non-null values up to and including every row
+    fn cum_count(&self) -> Series;
+
+    // The other sequence operations are only defined for numeric types
+    fn cum_sum(&self) -> Series {
+        panic!("cum_sum is not supported for {:?}", self.dtype())
+    }
+
+    fn cum_prod(&self) -> Series {
+        panic!("cum_prod is not supported for {:?}", self.dtype())
+    }
+
+    fn cum_min(&self) -> Series {
+        panic!("cum_min is not supported for {:?}", self.dtype())
+    }
+
+    fn cum_max(&self) -> Series {
+        panic!("cum_max is not supported for {:?}", self.dtype())
+    }
+
+    fn diff(&self, _periods: i64) -> Series {
+        panic!("diff is not supported for {:?}", self.dtype())
+    }
+
+    fn pct_change(&self, _periods: i64) -> Series {
+        panic!("pct_change is not supported for {:?}", self.dtype())
+    }
+
+    // Aggregations over a window around every row, only defined for numeric types
+    fn rolling_sum(&self, _options: &RollingOptions) -> Series {
+        panic!("rolling_sum is not supported for {:?}", self.dtype())
+    }
+
+    fn rolling_mean(&self, _options: &RollingOptions) -> Series {
+        panic!("rolling_mean is not supported for {:?}", self.dtype())
+    }
+
+    fn rolling_min(&self, _options: &RollingOptions) -> Series {
+        panic!("rolling_min is not supported for {:?}", self.dtype())
+    }
+
+    fn rolling_max(&self, _options: &RollingOptions) -> Series {
+        panic!("rolling_max is not supported for {:?}", self.dtype())
+    }
+
+    fn rolling_std(&self, _options: &RollingOptions) -> Series {
+        panic!("rolling_std is not supported for {:?}", self.dtype())
+    }
+
+    fn rolling_quantile(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+        _options: &RollingOptions,
+    ) -> Series {
+        panic!("rolling_quantile is not supported for {:?}", self.dtype())
+    }
+
+    // Moves the values down by `periods` rows, or up if it is negative. Non-numeric types can
+    // only fill the emptied rows with nulls.
+    fn shift(&self, periods: i64, fill_value: Option<AnyValue>) -> Series {
+        if let Some(fill_value) = fill_value {
+            panic!(
+                "cannot fill a {:?} series with {fill_value:?}",
+                self.dtype()
+            )
+        }
+        let indices = shifted_indices(self.len(), periods).collect::<Vec<_>>();
+        self.take_opt_indices(&indices)
+    }
+
+    // Deep copies the underlying ChunkedArray into a new Arc. The arrow buffers are shared.
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait>;
+
+    fn null_count(&self) -> usize;
+
+    // Reductions of the whole Series into a Series of length 1
+    fn min_as_series(&self) -> Series;
+
+    fn max_as_series(&self) -> Series;
+
+    fn sum_as_series(&self) -> Series;
+
+    fn mean_as_series(&self) -> Series;
+
+    // Like their group counterparts, the statistical reductions return null for non-numeric types.
+    fn quantile_as_series(&self, _quantile: f64, _interpol: QuantileInterpolOptions) -> Series {
+        F64Chunked::full_null(self.name(), 1).into_series()
+    }
+
+    fn median_as_series(&self) -> Series {
+        F64Chunked::full_null(self.name(), 1).into_series()
+    }
+
+    fn var_as_series(&self, _ddof: u8) -> Series {
+        F64Chunked::full_null(self.name(), 1).into_series()
+    }
+
+    fn std_as_series(&self, _ddof: u8) -> Series {
+        F64Chunked::full_null(self.name(), 1).into_series()
+    }
+
     fn agg_min(&self, groups: &GroupsProxy) -> Series;
+
+    fn agg_max(&self, groups: &GroupsProxy) -> Series;
+
+    fn agg_sum(&self, groups: &GroupsProxy) -> Series;
+
+    fn agg_mean(&self, groups: &GroupsProxy) -> Series;
+
+    // Number of non-null values in every group
+    fn agg_count(&self, groups: &GroupsProxy) -> Series;
+
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Series;
+
+    // The statistical aggregations are only defined for numeric types.
+    // Other types return nulls, like in Polars.
+    fn agg_quantile(
+        &self,
+        groups: &GroupsProxy,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Series {
+        F64Chunked::full_null(self.name(), groups.len()).into_series()
+    }
+
+    fn agg_median(&self, groups: &GroupsProxy) -> Series {
+        F64Chunked::full_null(self.name(), groups.len()).into_series()
+    }
+
+    fn agg_var(&self, groups: &GroupsProxy, _ddof: u8) -> Series {
+        F64Chunked::full_null(self.name(), groups.len()).into_series()
+    }
+
+    fn agg_std(&self, groups: &GroupsProxy, _ddof: u8) -> Series {
+        F64Chunked::full_null(self.name(), groups.len()).into_series()
+    }
+
+    // Groups can be empty after a filter, in which case first and last are null
+    fn agg_first(&self, groups: &GroupsProxy) -> Series {
+        self.take_opt_indices(&groups.first_indices())
+    }
+
+    fn agg_last(&self, groups: &GroupsProxy) -> Series {
+        self.take_opt_indices(&groups.last_indices())
+    }
 }
@@ -0,0 +1,44 @@
+use arrow2::compute::if_then_else::if_then_else;
+
+use crate::chunked_array::types::BooleanChunked;
+
+use super::{cast::get_supertype, constructor::IntoSeries, Series};
+
+impl Series {
+    // The values of `self` where the mask is true, and of `other` where it is false or null.
+    // Int32 and Float64 values give Float64, and Series of a single value are repeated, so the
+    // length is the one of the mask unless it has a single value.
+    pub fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> Series {
+        let (left, right) = (self.dtype(), other.dtype());
+        let dtype = get_supertype(&left, &right)
+            .unwrap_or_else(|| panic!("cannot combine {left:?} and {right:?} values in a column"));
+        let len = [mask.length, self.len(), other.len()]
+            .into_iter()
+            .find(|len| *len != 1)
+            .unwrap_or(1);
+
+        // The kernel gives a null for a null in the mask, and needs aligned single chunks
+        let mask = mask & &mask.is_not_null();
+        let mask = broadcast(&mask.into_series(), len).rechunk();
+        let truthy = broadcast(&self.cast(&dtype), len).rechunk();
+        let falsy = broadcast(&other.cast(&dtype), len).rechunk();
+        let chunk = if_then_else(
+            mask.bool().iter_primitive().next().unwrap(),
+            truthy.chunks()[0].as_ref(),
+            falsy.chunks()[0].as_ref(),
+        )
+        .unwrap();
+        Series::from_chunks_and_dtype(self.name(), vec![chunk], &dtype)
+    }
+}
+
+fn broadcast(series: &Series, len: usize) -> Series {
+    match series.len() {
+        length if length == len => series.clone(),
+        1 => series.take_indices(&vec![0; len]),
+        length => panic!(
+            "cannot combine {length} values of {} with {len} values",
+            series.name()
+        ),
+    }
+}
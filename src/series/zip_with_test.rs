@@ -0,0 +1,38 @@
+use crate::{
+    chunked_array::{builder::NewFrom, types::BooleanChunked},
+    series::Series,
+};
+
+#[test]
+fn test_zip_with() {
+    let mask = BooleanChunked::from_slice_options("mask", &vec![Some(true), None, Some(false)]);
+    let truthy = Series::from_vec("a", &vec![1, 2, 3]);
+    let falsy = Series::from_slice_options("b", &vec![Some(0.5), Some(1.5), None]);
+    assert_eq!(
+        truthy.zip_with(&mask, &falsy),
+        Series::from_slice_options("a", &vec![Some(1.0), Some(1.5), None])
+    );
+
+    // Single values are repeated for every row
+    let falsy = Series::from_vec("b", &vec![0]);
+    assert_eq!(
+        truthy.zip_with(&mask, &falsy),
+        Series::from_vec("a", &vec![1, 0, 0])
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot combine")]
+fn test_zip_with_different_types() {
+    let mask = BooleanChunked::from_slice_options("mask", &vec![Some(true)]);
+    let truthy = Series::from_vec("a", &vec![1]);
+    truthy.zip_with(&mask, &Series::from_vec("b", &vec!["x"]));
+}
+
+#[test]
+fn test_zip_with_empty_mask() {
+    let mask = BooleanChunked::from_slice_options("mask", &Vec::<Option<bool>>::new());
+    let truthy = Series::from_vec("a", &vec![1]);
+    let falsy = Series::from_vec("b", &vec![0]);
+    assert_eq!(truthy.zip_with(&mask, &falsy).len(), 0);
+}
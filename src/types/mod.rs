@@ -1,8 +1,17 @@
-use arrow2::datatypes::DataType as ArrowDataType;
+use std::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use arrow2::{datatypes::DataType as ArrowDataType, types::NativeType};
+
+use crate::chunked_array::sort::TotalOrd;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum DataType {
     Int32,
+    Float64,
     Utf8,
     Boolean,
 }
@@ -11,10 +20,84 @@ pub trait LittleDataType {
     fn get_dtype() -> DataType;
 }
 
+// Data types that are backed by an arrow PrimitiveArray. This allows the numeric
+// kernels to be written once instead of once per type.
+pub trait LittleNumericType: LittleDataType + Send + Sync + 'static {
+    type Native: NumericNative;
+}
+
+pub trait NumericNative:
+    NativeType
+    + PartialOrd
+    + TotalOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Sum
+    + Debug
+{
+    fn to_f64(self) -> f64;
+
+    fn from_f64(v: f64) -> Self;
+
+    // Integer sums, differences and products wrap around on overflow instead of panicking
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+impl NumericNative for i32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(v: f64) -> Self {
+        v as i32
+    }
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        i32::wrapping_add(self, rhs)
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        i32::wrapping_sub(self, rhs)
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        i32::wrapping_mul(self, rhs)
+    }
+}
+
+impl NumericNative for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+}
+
 impl DataType {
     pub fn to_array_type(&self) -> ArrowDataType {
         match self {
             DataType::Int32 => ArrowDataType::Int32,
+            DataType::Float64 => ArrowDataType::Float64,
             DataType::Utf8 => ArrowDataType::LargeUtf8,
             DataType::Boolean => ArrowDataType::Boolean,
         }